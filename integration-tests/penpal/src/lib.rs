@@ -624,6 +624,9 @@ parameter_types! {
 	pub PolimecParaId: ParaId = 3344u32.into();
 	pub GenesisMoment: BlockNumber = 0u32;
 	pub const MaxMigrations: u128 = 100000;
+	// Metadata of the project CT used in the integration tests
+	pub const TokenDecimals: u8 = 18;
+	pub TokenSymbol: Vec<u8> = b"CTEST".to_vec();
 }
 impl polimec_receiver::Config for Runtime {
 	type Balance = Balance;
@@ -632,9 +635,14 @@ impl polimec_receiver::Config for Runtime {
 	type MaxMigrations = MaxMigrations;
 	type MigrationInfoToPerBlockBalance = DivideBalanceByBlocks;
 	type PolimecParaId = PolimecParaId;
+	type PolimecReportCallIndices = polimec_receiver::config_preludes::PolimecReportCallIndices;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeOrigin = RuntimeOrigin;
+	type TokenDecimals = TokenDecimals;
+	type TokenSymbol = TokenSymbol;
 	type Vesting = Vesting;
+	type WeightInfo = polimec_receiver::weights::SubstrateWeight<Runtime>;
+	type XcmSender = xcm_config::XcmRouter;
 }
 
 impl polkadot_runtime_parachains::origin::Config for Runtime {}
@@ -693,6 +701,7 @@ mod benches {
 		[pallet_timestamp, Timestamp]
 		[pallet_collator_selection, CollatorSelection]
 		[cumulus_pallet_xcmp_queue, XcmpQueue]
+		[polimec_receiver, PolimecReceiver]
 	);
 }
 
@@ -52,9 +52,15 @@ fn mock_hrmp_establishment(project_id: u32) {
 }
 
 fn assert_migration_is_ready(project_id: u32) {
+	// The token metadata check is sent once the receiver was found
+	PolitestNet::execute_with(|| {});
+	PenNet::execute_with(|| {});
+
 	PolitestNet::execute_with(|| {
 		let project_details = pallet_funding::ProjectsDetails::<PolitestRuntime>::get(project_id).unwrap();
-		assert!(project_details.migration_readiness_check.unwrap().is_ready())
+		assert!(project_details.migration_readiness_check.unwrap().is_ready());
+		let readiness_report = pallet_funding::MigrationReadinessReports::<PolitestRuntime>::get(project_id).unwrap();
+		assert!(readiness_report.token_metadata_matches(), "{:?}", readiness_report.failures);
 	});
 }
 
@@ -196,3 +202,36 @@ fn full_migration_test() {
 
 	migrations_are_vested(project_id, participants.clone());
 }
+
+#[test]
+fn batch_migration_test() {
+	let (project_id, participants) = create_settled_project();
+
+	mock_hrmp_establishment(project_id);
+
+	assert_migration_is_ready(project_id);
+
+	// All participants fit in a single message
+	PolitestNet::execute_with(|| {
+		assert_ok!(Funding::migrate_participants_batch(PolitestOrigin::signed(ISSUER.into()), project_id));
+		let sent_statuses = participants
+			.iter()
+			.map(|participant| {
+				pallet_funding::UserMigrations::<PolitestRuntime>::get(project_id, participant.clone()).unwrap().0
+			})
+			.collect::<Vec<_>>();
+		let MigrationStatus::Sent(query_id) = sent_statuses[0] else { panic!("Migration should have been sent") };
+		assert!(sent_statuses.iter().all(|status| status == &MigrationStatus::Sent(query_id)));
+		let mut batch = pallet_funding::ActiveMigrationQueue::<PolitestRuntime>::get(query_id).unwrap().1.into_inner();
+		batch.sort();
+		assert_eq!(batch, participants);
+	});
+
+	migrations_are_executed(project_id, participants.clone());
+
+	migrations_are_confirmed(project_id, participants.clone());
+
+	vest_migrations(project_id, participants.clone());
+
+	migrations_are_vested(project_id, participants.clone());
+}
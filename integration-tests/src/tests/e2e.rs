@@ -45,7 +45,8 @@ generate_accounts!(
 
 pub fn excel_project() -> ProjectMetadataOf<PolitestRuntime> {
 	let bounded_name = BoundedVec::try_from("Polimec".as_bytes().to_vec()).unwrap();
-	let bounded_symbol = BoundedVec::try_from("PLMC".as_bytes().to_vec()).unwrap();
+	// Penpal's receiver checks the symbol against the one of its token
+	let bounded_symbol = BoundedVec::try_from("CTEST".as_bytes().to_vec()).unwrap();
 	let metadata_hash = ipfs_hash();
 	ProjectMetadata {
 		token_information: CurrencyMetadata { name: bounded_name, symbol: bounded_symbol, decimals: CT_DECIMALS },
@@ -75,6 +76,9 @@ pub fn excel_project() -> ProjectMetadataOf<PolitestRuntime> {
 		participation_currencies: vec![AcceptedFundingAsset::USDT].try_into().unwrap(),
 		funding_destination_account: ISSUER.into(),
 		policy_ipfs_cid: Some(metadata_hash),
+		phase_durations: None,
+		scheduled_starts: Default::default(),
+		funding_caps: None,
 	}
 }
 
@@ -504,10 +508,16 @@ fn ct_migrated() {
 		dbg!(PenNet::events());
 	});
 
+	// The token metadata check is sent once the receiver was found
+	PolitestNet::execute_with(|| {});
+	PenNet::execute_with(|| {});
+
 	// Migration is ready
 	PolitestNet::execute_with(|| {
 		let project_details = pallet_funding::ProjectsDetails::<PolitestRuntime>::get(project_id).unwrap();
-		assert!(project_details.migration_readiness_check.unwrap().is_ready())
+		assert!(project_details.migration_readiness_check.unwrap().is_ready());
+		let readiness_report = pallet_funding::MigrationReadinessReports::<PolitestRuntime>::get(project_id).unwrap();
+		assert!(readiness_report.token_metadata_matches());
 	});
 
 	excel_ct_amounts().iter().map(|tup| tup.0.clone()).unique().for_each(|account| {
@@ -348,6 +348,9 @@ mod testing_helpers {
 			participation_currencies: vec![AcceptedFundingAsset::USDT].try_into().unwrap(),
 			funding_destination_account: issuer,
 			policy_ipfs_cid: Some(ipfs_hash()),
+			phase_durations: None,
+			scheduled_starts: Default::default(),
+			funding_caps: None,
 		}
 	}
 	pub fn default_evaluations() -> Vec<UserToUSDBalance<politest_runtime::Runtime>> {
@@ -92,6 +92,14 @@ pub struct Cli {
 	#[arg(long)]
 	pub no_hardware_benchmarks: bool,
 
+	/// Replay recorded exchange responses in the oracle offchain worker instead of querying the exchanges.
+	///
+	/// The file is a JSON object of responses keyed by exchange and asset, e.g.
+	/// `{"kraken": {"DOT": <response>}}`. Meant for testing only. The responses are only replayed until the node
+	/// is restarted without this flag.
+	#[arg(long, value_name = "PATH")]
+	pub oracle_fixtures: Option<PathBuf>,
+
 	/// Relay chain arguments
 	#[arg(raw = true)]
 	pub relay_chain_args: Vec<String>,
@@ -348,6 +348,7 @@ pub fn run() -> Result<()> {
 					collator_options,
 					id,
 					hwbench,
+					cli.oracle_fixtures.clone(),
 				)
 				.await
 				.map(|r| r.0)
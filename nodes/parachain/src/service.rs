@@ -17,10 +17,19 @@
 //! Service and ServiceFactory implementation. Specialized wrapper over substrate service.
 
 // std
-use std::{sync::Arc, time::Duration};
+use std::{
+	path::{Path, PathBuf},
+	sync::Arc,
+	time::Duration,
+};
 
 use cumulus_client_cli::CollatorOptions;
 // Local Runtime Types
+use pallet_oracle_ocw::{
+	fixture_key,
+	types::{AssetName, Exchange},
+	FIXTURE_KEYS_KEY,
+};
 use polimec_runtime::{
 	opaque::{Block, Hash},
 	RuntimeApi,
@@ -50,6 +59,7 @@ use sc_network_sync::SyncingService;
 use sc_service::{Configuration, PartialComponents, TFullBackend, TFullClient, TaskManager};
 use sc_telemetry::{Telemetry, TelemetryHandle, TelemetryWorker, TelemetryWorkerHandle};
 use sc_transaction_pool_api::OffchainTransactionPoolFactory;
+use sp_core::offchain::OffchainStorage;
 use sp_keystore::KeystorePtr;
 use substrate_prometheus_endpoint::Registry;
 
@@ -152,6 +162,7 @@ async fn start_node_impl(
 	collator_options: CollatorOptions,
 	para_id: ParaId,
 	hwbench: Option<sc_sysinfo::HwBench>,
+	oracle_fixtures: Option<PathBuf>,
 ) -> sc_service::error::Result<(TaskManager, Arc<ParachainClient>)> {
 	let parachain_config = prepare_node_config(parachain_config);
 
@@ -163,6 +174,13 @@ async fn start_node_impl(
 	let backend = params.backend.clone();
 	let mut task_manager = params.task_manager;
 
+	match backend.offchain_storage() {
+		Some(mut offchain_storage) => load_oracle_fixtures(oracle_fixtures.as_deref(), &mut offchain_storage)?,
+		None if oracle_fixtures.is_some() =>
+			return Err("Oracle fixtures need the offchain storage to be enabled".into()),
+		None => {},
+	}
+
 	let (relay_chain_interface, collator_key) = build_relay_chain_interface(
 		polkadot_config,
 		&parachain_config,
@@ -412,6 +430,45 @@ pub async fn start_parachain_node(
 	collator_options: CollatorOptions,
 	para_id: ParaId,
 	hwbench: Option<sc_sysinfo::HwBench>,
+	oracle_fixtures: Option<PathBuf>,
 ) -> sc_service::error::Result<(TaskManager, Arc<ParachainClient>)> {
-	start_node_impl(parachain_config, polkadot_config, collator_options, para_id, hwbench).await
+	start_node_impl(parachain_config, polkadot_config, collator_options, para_id, hwbench, oracle_fixtures).await
+}
+
+/// Write the recorded exchange responses of the `path` JSON file to the offchain local storage, where the
+/// oracle offchain worker replays them from. Fixtures only apply to the start they were passed to, so the ones of
+/// the previous start are removed first.
+fn load_oracle_fixtures(
+	path: Option<&Path>,
+	offchain_storage: &mut impl OffchainStorage,
+) -> sc_service::error::Result<()> {
+	if let Some(previous_keys) = offchain_storage.get(sp_offchain::STORAGE_PREFIX, FIXTURE_KEYS_KEY) {
+		let previous_keys: Vec<Vec<u8>> = serde_json::from_slice(&previous_keys).unwrap_or_default();
+		for key in previous_keys {
+			offchain_storage.remove(sp_offchain::STORAGE_PREFIX, &key);
+		}
+		offchain_storage.remove(sp_offchain::STORAGE_PREFIX, FIXTURE_KEYS_KEY);
+		log::info!("Removed the oracle fixtures of the previous start");
+	}
+	let Some(path) = path else { return Ok(()) };
+
+	let file = std::fs::read(path).map_err(|e| format!("Could not read the oracle fixtures: {e}"))?;
+	let fixtures: std::collections::BTreeMap<String, std::collections::BTreeMap<String, serde_json::Value>> =
+		serde_json::from_slice(&file).map_err(|e| format!("Could not parse the oracle fixtures: {e}"))?;
+
+	let mut keys = Vec::new();
+	for (exchange, responses) in fixtures {
+		let exchange: Exchange = exchange.parse().map_err(|_| format!("Unknown oracle fixture exchange {exchange}"))?;
+		for (asset, response) in responses {
+			let asset: AssetName = asset.parse().map_err(|_| format!("Unknown oracle fixture asset {asset}"))?;
+			let body = serde_json::to_vec(&response).map_err(|e| format!("Invalid oracle fixture: {e}"))?;
+			let key = fixture_key(exchange, asset);
+			offchain_storage.set(sp_offchain::STORAGE_PREFIX, &key, &body);
+			keys.push(key);
+			log::info!("Replaying oracle fixture for {:?} from {:?}", asset, exchange);
+		}
+	}
+	let keys = serde_json::to_vec(&keys).map_err(|e| format!("Could not record the oracle fixtures: {e}"))?;
+	offchain_storage.set(sp_offchain::STORAGE_PREFIX, FIXTURE_KEYS_KEY, &keys);
+	Ok(())
 }
@@ -24,12 +24,28 @@ use frame_benchmarking::v2::*;
 use frame_support::traits::{EnsureOrigin, Get};
 use frame_system::RawOrigin;
 use polimec_common_test_utils::{generate_did_from_account, get_mock_jwt};
-use sp_runtime::traits::One;
+use sp_runtime::traits::{One, Zero};
+use sp_std::vec;
 
 fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
 
+fn campaign_info<T: Config>() -> CampaignInfoOf<T> {
+	CampaignInfo {
+		amount: T::InitialDispenseAmount::get(),
+		free_amount: T::FreeDispenseAmount::get(),
+		investor_types: BoundedVec::truncate_from(vec![
+			InvestorType::Retail,
+			InvestorType::Professional,
+			InvestorType::Institutional,
+		]),
+		lock_period: T::LockPeriod::get(),
+		vest_period: T::VestPeriod::get(),
+		end: frame_system::Pallet::<T>::block_number() + One::one(),
+	}
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -55,6 +71,35 @@ mod benchmarks {
 		);
 	}
 
+	#[benchmark]
+	fn dispense_faucet() {
+		let caller: T::AccountId = whitelisted_caller();
+		let did = generate_did_from_account(1);
+		let amount = T::InitialDispenseAmount::get();
+		let settings = FaucetSettings {
+			amount,
+			cooldown: Zero::zero(),
+			block_budget: amount + amount,
+			daily_budget: amount + amount,
+		};
+		Mode::<T>::put(DispenseMode::Faucet(settings));
+		CurrencyOf::<T>::deposit_creating(&Dispenser::<T>::dispense_account(), amount);
+
+		// Worst case: the DID and the faucet were already refilled in this block, so every refill record is updated
+		let now = frame_system::Pallet::<T>::block_number();
+		LastRefill::<T>::insert(did.clone(), now);
+		BlockRefilled::<T>::put((now, amount));
+		DayRefilled::<T>::put((now - now % T::BlocksPerDay::get().max(One::one()), amount));
+
+		let jwt = get_mock_jwt(caller.clone(), InvestorType::Retail, did.clone());
+		#[extrinsic_call]
+		dispense(RawOrigin::Signed(caller.clone()), jwt);
+
+		assert_eq!(LastRefill::<T>::get(did.clone()), Some(now));
+		assert_eq!(BlockRefilled::<T>::get(), (now, amount + amount));
+		assert_last_event::<T>(Event::<T>::Dispensed { dispensed_to_did: did, dispensed_to: caller, amount }.into());
+	}
+
 	#[benchmark]
 	fn set_dispense_amount() -> Result<(), BenchmarkError> {
 		let origin = T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
@@ -69,5 +114,121 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_dispense_mode() -> Result<(), BenchmarkError> {
+		let origin = T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		assert_eq!(Mode::<T>::get(), DispenseMode::OneTime);
+
+		let amount = T::FreeDispenseAmount::get();
+		let mode = DispenseMode::Faucet(FaucetSettings {
+			amount,
+			cooldown: One::one(),
+			block_budget: amount,
+			daily_budget: amount,
+		});
+		#[extrinsic_call]
+		set_dispense_mode(origin as T::RuntimeOrigin, mode);
+
+		assert_eq!(Mode::<T>::get(), mode);
+		assert_last_event::<T>(Event::<T>::DispenseModeChanged(mode).into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn create_campaign() -> Result<(), BenchmarkError> {
+		let origin = T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let info = campaign_info::<T>();
+
+		#[extrinsic_call]
+		create_campaign(origin as T::RuntimeOrigin, info.clone());
+
+		assert_eq!(Campaigns::<T>::get(0), Some(info));
+		assert_last_event::<T>(
+			Event::<T>::CampaignCreated { campaign_id: 0, account: Dispenser::<T>::campaign_account(0) }.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn dispense_campaign() {
+		let caller: T::AccountId = whitelisted_caller();
+		let did = generate_did_from_account(1);
+		let info = campaign_info::<T>();
+		Campaigns::<T>::insert(0, info.clone());
+		CurrencyOf::<T>::deposit_creating(&Dispenser::<T>::campaign_account(0), info.amount);
+
+		let jwt = get_mock_jwt(caller.clone(), InvestorType::Retail, did.clone());
+		#[extrinsic_call]
+		dispense_campaign(RawOrigin::Signed(caller.clone()), 0, jwt);
+
+		assert_eq!(CampaignDispensed::<T>::get(0, did.clone()), Some(()));
+		assert_last_event::<T>(
+			Event::<T>::CampaignDispensed {
+				campaign_id: 0,
+				dispensed_to_did: did,
+				dispensed_to: caller,
+				amount: info.amount,
+			}
+			.into(),
+		);
+	}
+
+	#[benchmark]
+	fn end_campaign(x: Linear<0, 500>) -> Result<(), BenchmarkError> {
+		let origin = T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let info = campaign_info::<T>();
+		Campaigns::<T>::insert(0, info.clone());
+		NextCampaignId::<T>::put(1);
+		CurrencyOf::<T>::deposit_creating(&Dispenser::<T>::campaign_account(0), info.amount);
+		for i in 0..x {
+			CampaignDispensed::<T>::insert(0, generate_did_from_account(i), ());
+		}
+
+		#[extrinsic_call]
+		end_campaign(origin as T::RuntimeOrigin, 0);
+
+		let max = T::MaxCampaignCleanup::get();
+		assert_eq!(Campaigns::<T>::get(0), None);
+		assert_eq!(CampaignDispensed::<T>::iter_key_prefix(0).count() as u32, x.saturating_sub(max));
+		assert_last_event::<T>(
+			Event::<T>::CampaignCleanedUp { campaign_id: 0, removed: x.min(max), complete: x <= max }.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn fund_campaign() -> Result<(), BenchmarkError> {
+		let origin = T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let info = campaign_info::<T>();
+		Campaigns::<T>::insert(0, info.clone());
+		NextCampaignId::<T>::put(1);
+		CurrencyOf::<T>::deposit_creating(&Dispenser::<T>::dispense_account(), info.amount);
+
+		#[extrinsic_call]
+		fund_campaign(origin as T::RuntimeOrigin, 0, info.amount);
+
+		assert_eq!(CurrencyOf::<T>::free_balance(&Dispenser::<T>::campaign_account(0)), info.amount);
+		assert_last_event::<T>(Event::<T>::CampaignFunded { campaign_id: 0, amount: info.amount }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn clean_up_campaign(x: Linear<1, 500>) {
+		let caller: T::AccountId = whitelisted_caller();
+		NextCampaignId::<T>::put(1);
+		for i in 0..x {
+			CampaignDispensed::<T>::insert(0, generate_did_from_account(i), ());
+		}
+
+		#[extrinsic_call]
+		clean_up_campaign(RawOrigin::Signed(caller), 0);
+
+		let max = T::MaxCampaignCleanup::get();
+		assert_eq!(CampaignDispensed::<T>::iter_key_prefix(0).count() as u32, x.saturating_sub(max));
+		assert_last_event::<T>(
+			Event::<T>::CampaignCleanedUp { campaign_id: 0, removed: x.min(max), complete: x <= max }.into(),
+		);
+	}
+
 	impl_benchmark_test_suite!(Dispenser, crate::mock::ExtBuilder::default().build(), crate::mock::Test);
 }
@@ -85,7 +85,7 @@ where
 		let mut account = frame_system::Account::<T>::get(who);
 		if account.providers.is_zero() && account.sufficients.is_zero() {
 			match call.is_sub_type() {
-				Some(call) if matches!(call, &Call::<T>::dispense { .. }) => {},
+				Some(call) if matches!(call, &Call::<T>::dispense { .. } | &Call::<T>::dispense_campaign { .. }) => {},
 				_ => return Err(InvalidTransaction::Payment.into()),
 			}
 		}
@@ -109,7 +109,7 @@ where
 		let account = frame_system::Account::<T>::get(who);
 		if account.providers.is_zero() && account.sufficients.is_zero() {
 			match call.is_sub_type() {
-				Some(call) if matches!(call, &Call::<T>::dispense { .. }) => {},
+				Some(call) if matches!(call, &Call::<T>::dispense { .. } | &Call::<T>::dispense_campaign { .. }) => {},
 				_ => return Err(InvalidTransaction::Payment.into()),
 			}
 		}
@@ -20,10 +20,12 @@
 pub use pallet::*;
 
 pub use crate::weights::WeightInfo;
+use frame_support::pallet_prelude::{BoundedVec, ConstU32, Decode, Encode, MaxEncodedLen, RuntimeDebug, TypeInfo};
 pub use frame_support::traits::{
 	tokens::{currency::VestingSchedule, Balance},
 	Currency, ExistenceRequirement,
 };
+use frame_system::pallet_prelude::BlockNumberFor;
 pub use polimec_common::credentials::{Cid, Did, EnsureOriginWithCredentials, InvestorType, UntrustedToken};
 pub use sp_runtime::traits::Convert;
 
@@ -43,6 +45,51 @@ mod benchmarking;
 pub type BalanceOf<T> = <CurrencyOf<T> as Currency<AccountIdOf<T>>>::Balance;
 pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 type CurrencyOf<T> = <<T as Config>::VestingSchedule as VestingSchedule<AccountIdOf<T>>>::Currency;
+pub type DispenseModeOf<T> = DispenseMode<BalanceOf<T>, BlockNumberFor<T>>;
+pub type FaucetSettingsOf<T> = FaucetSettings<BalanceOf<T>, BlockNumberFor<T>>;
+pub type CampaignInfoOf<T> = CampaignInfo<BalanceOf<T>, BlockNumberFor<T>>;
+pub type CampaignId = u32;
+
+/// How the dispenser hands out tokens to a DID.
+#[derive(Clone, Copy, Default, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum DispenseMode<Balance, BlockNumber> {
+	/// Each DID receives `DispenseAmount` once, vested over `VestPeriod` after `LockPeriod`.
+	#[default]
+	OneTime,
+	/// Each DID can be refilled periodically with free tokens, within a global budget.
+	Faucet(FaucetSettings<Balance, BlockNumber>),
+}
+
+/// Refill parameters of the faucet mode.
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct FaucetSettings<Balance, BlockNumber> {
+	/// The amount of free tokens given in each refill.
+	pub amount: Balance,
+	/// The number of blocks a DID has to wait between two refills.
+	pub cooldown: BlockNumber,
+	/// The maximum amount of tokens refilled in a single block.
+	pub block_budget: Balance,
+	/// The maximum amount of tokens refilled in a day of `BlocksPerDay` blocks.
+	pub daily_budget: Balance,
+}
+
+/// Parameters of a dispense campaign, paid out from its own sub-account of the dispenser.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CampaignInfo<Balance, BlockNumber> {
+	/// The amount of tokens each eligible DID receives.
+	pub amount: Balance,
+	/// The part of `amount` that is free from the start. The rest is vested.
+	pub free_amount: Balance,
+	/// The investor types whose credentials are eligible.
+	pub investor_types: BoundedVec<InvestorType, ConstU32<3>>,
+	/// The period of time that the vested part is locked before its vesting schedule starts.
+	pub lock_period: BlockNumber,
+	/// The period of time over which the vested part is released.
+	pub vest_period: BlockNumber,
+	/// The last block in which the campaign dispenses.
+	pub end: BlockNumber,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -53,7 +100,7 @@ pub mod pallet {
 	};
 	use frame_system::pallet_prelude::*;
 	use sp_runtime::{
-		traits::{AccountIdConversion, CheckedDiv},
+		traits::{AccountIdConversion, CheckedDiv, One, Zero},
 		Saturating,
 	};
 
@@ -66,6 +113,10 @@ pub mod pallet {
 		/// Block to balance converter.
 		type BlockNumberToBalance: Convert<BlockNumberFor<Self>, BalanceOf<Self>>;
 
+		/// The number of blocks in a day, used for the daily budget of the faucet mode.
+		#[pallet::constant]
+		type BlocksPerDay: Get<BlockNumberFor<Self>>;
+
 		/// The amount of dispensed tokens that are free, so they could be used to pay for
 		/// future transaction fees.
 		#[pallet::constant]
@@ -86,6 +137,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type LockPeriod: Get<BlockNumberFor<Self>>;
 
+		/// The maximum number of DIDs whose receipt of an ended campaign is removed in a single call.
+		#[pallet::constant]
+		type MaxCampaignCleanup: Get<u32>;
+
 		/// The dispenser's pallet id, used for deriving its sovereign account ID.
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
@@ -118,11 +173,48 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type Dispensed<T> = StorageMap<_, Blake2_128Concat, Did, ()>;
 
+	/// The mode in which `dispense` hands out tokens.
+	#[pallet::storage]
+	pub type Mode<T> = StorageValue<_, DispenseModeOf<T>, ValueQuery>;
+
+	/// The block in which each DID was last refilled in the faucet mode.
+	#[pallet::storage]
+	pub type LastRefill<T> = StorageMap<_, Blake2_128Concat, Did, BlockNumberFor<T>>;
+
+	/// The amount refilled in the faucet mode during the stored block.
+	#[pallet::storage]
+	pub type BlockRefilled<T> = StorageValue<_, (BlockNumberFor<T>, BalanceOf<T>), ValueQuery>;
+
+	/// The amount refilled in the faucet mode during the day starting at the stored block.
+	#[pallet::storage]
+	pub type DayRefilled<T> = StorageValue<_, (BlockNumberFor<T>, BalanceOf<T>), ValueQuery>;
+
+	#[pallet::storage]
+	pub type NextCampaignId<T> = StorageValue<_, CampaignId, ValueQuery>;
+
+	#[pallet::storage]
+	pub type Campaigns<T> = StorageMap<_, Blake2_128Concat, CampaignId, CampaignInfoOf<T>>;
+
+	/// The DIDs that already received tokens from each campaign.
+	#[pallet::storage]
+	pub type CampaignDispensed<T> = StorageDoubleMap<_, Blake2_128Concat, CampaignId, Blake2_128Concat, Did, ()>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		Dispensed { dispensed_to_did: Did, dispensed_to: T::AccountId, amount: BalanceOf<T> },
 		DispenseAmountChanged(BalanceOf<T>),
+		DispenseModeChanged(DispenseModeOf<T>),
+		CampaignCreated { campaign_id: CampaignId, account: T::AccountId },
+		CampaignDispensed {
+			campaign_id: CampaignId,
+			dispensed_to_did: Did,
+			dispensed_to: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		CampaignEnded { campaign_id: CampaignId, returned: BalanceOf<T> },
+		CampaignFunded { campaign_id: CampaignId, amount: BalanceOf<T> },
+		CampaignCleanedUp { campaign_id: CampaignId, removed: u32, complete: bool },
 	}
 
 	#[pallet::error]
@@ -133,69 +225,328 @@ pub mod pallet {
 		DispenserDepleted,
 		/// The dispense amount is too low. It must be greater than the free dispense amount.
 		DispenseAmountTooLow,
+		/// The DID was refilled too recently. It has to wait for the faucet cooldown to pass.
+		RefillCooldownNotOver,
+		/// The faucet already gave out its budget for the current block or day.
+		FaucetBudgetExhausted,
+		/// The faucet settings are invalid. The refill amount must be non-zero and fit in both budgets.
+		InvalidFaucetSettings,
+		/// The campaign does not exist or was ended by the admin.
+		CampaignNotFound,
+		/// The campaign is over. Its end block has passed.
+		CampaignOver,
+		/// The investor type of the credential is not eligible for the campaign.
+		InvestorTypeNotEligible,
+		/// The campaign parameters are invalid. The amount must be non-zero and cover the free amount, at least
+		/// one investor type must be eligible and the end block cannot be in the past.
+		InvalidCampaign,
+		/// The campaign has not been ended yet, so its receipts cannot be removed.
+		CampaignNotEnded,
+		/// The receipts of the campaign were already all removed.
+		CampaignAlreadyCleanedUp,
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		#[pallet::feeless_if( | origin: &OriginFor<T>, jwt: &UntrustedToken | -> bool {
             if let Ok((_, did, _, _)) = T::InvestorOrigin::ensure_origin(origin.clone(), jwt, T::VerifierPublicKey::get()) {
-                return Dispensed::<T>::get(did).is_none()
+                return Pallet::<T>::is_eligible(&did)
             } else {
                 return false
             }
         })]
 		#[pallet::call_index(0)]
-		#[pallet::weight(T::WeightInfo::dispense())]
+		#[pallet::weight(T::WeightInfo::dispense().max(T::WeightInfo::dispense_faucet()))]
 		pub fn dispense(origin: OriginFor<T>, jwt: UntrustedToken) -> DispatchResultWithPostInfo {
 			let (who, did, _investor_type, _) =
 				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
-			ensure!(Dispensed::<T>::get(&did).is_none(), Error::<T>::DispensedAlreadyToDid);
+			match Mode::<T>::get() {
+				DispenseMode::OneTime => Self::do_dispense_once(who, did),
+				DispenseMode::Faucet(settings) => Self::do_refill(who, did, settings),
+			}
+		}
 
-			let amount = DispenseAmount::<T>::get();
-			ensure!(CurrencyOf::<T>::free_balance(&Self::dispense_account()) >= amount, Error::<T>::DispenserDepleted);
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::set_dispense_amount())]
+		pub fn set_dispense_amount(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResultWithPostInfo {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(amount > T::FreeDispenseAmount::get(), Error::<T>::DispenseAmountTooLow);
+			DispenseAmount::<T>::put(amount);
+			Self::deposit_event(Event::DispenseAmountChanged(amount));
+			Ok(Pays::No.into())
+		}
 
-			let current_block = <frame_system::Pallet<T>>::block_number();
-			let length_as_balance = T::BlockNumberToBalance::convert(T::VestPeriod::get());
-			let locked_amount = amount.saturating_sub(T::FreeDispenseAmount::get());
-			let per_block = locked_amount
-				.checked_div(&length_as_balance.max(sp_runtime::traits::One::one()))
-				.ok_or(DispatchError::Arithmetic(sp_runtime::ArithmeticError::Underflow))?;
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::set_dispense_mode())]
+		pub fn set_dispense_mode(origin: OriginFor<T>, mode: DispenseModeOf<T>) -> DispatchResultWithPostInfo {
+			T::AdminOrigin::ensure_origin(origin)?;
+			if let DispenseMode::Faucet(settings) = mode {
+				ensure!(
+					!settings.amount.is_zero() &&
+						settings.amount <= settings.block_budget &&
+						settings.amount <= settings.daily_budget,
+					Error::<T>::InvalidFaucetSettings
+				);
+			}
+			Mode::<T>::put(mode);
+			Self::deposit_event(Event::DispenseModeChanged(mode));
+			Ok(Pays::No.into())
+		}
 
-			T::VestingSchedule::can_add_vesting_schedule(
-				&who,
-				locked_amount,
-				per_block,
-				current_block + T::LockPeriod::get(),
-			)?;
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::create_campaign())]
+		pub fn create_campaign(origin: OriginFor<T>, info: CampaignInfoOf<T>) -> DispatchResultWithPostInfo {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(
+				!info.amount.is_zero() &&
+					info.free_amount <= info.amount &&
+					!info.investor_types.is_empty() &&
+					info.end >= <frame_system::Pallet<T>>::block_number(),
+				Error::<T>::InvalidCampaign
+			);
+
+			let campaign_id = NextCampaignId::<T>::mutate(|next_id| {
+				let campaign_id = *next_id;
+				*next_id = next_id.saturating_add(1);
+				campaign_id
+			});
+			Campaigns::<T>::insert(campaign_id, info);
+			Self::deposit_event(Event::CampaignCreated { campaign_id, account: Self::campaign_account(campaign_id) });
+			Ok(Pays::No.into())
+		}
 
-			<CurrencyOf<T>>::transfer(&Self::dispense_account(), &who, amount, ExistenceRequirement::AllowDeath)?;
-			T::VestingSchedule::add_vesting_schedule(
+		#[pallet::feeless_if( | origin: &OriginFor<T>, campaign_id: &CampaignId, jwt: &UntrustedToken | -> bool {
+            if let Ok((_, did, investor_type, _)) = T::InvestorOrigin::ensure_origin(origin.clone(), jwt, T::VerifierPublicKey::get()) {
+                return Pallet::<T>::is_eligible_for_campaign(*campaign_id, &did, &investor_type)
+            } else {
+                return false
+            }
+        })]
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::dispense_campaign())]
+		pub fn dispense_campaign(
+			origin: OriginFor<T>,
+			campaign_id: CampaignId,
+			jwt: UntrustedToken,
+		) -> DispatchResultWithPostInfo {
+			let (who, did, investor_type, _) =
+				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
+			let info = Campaigns::<T>::get(campaign_id).ok_or(Error::<T>::CampaignNotFound)?;
+			ensure!(<frame_system::Pallet<T>>::block_number() <= info.end, Error::<T>::CampaignOver);
+			ensure!(info.investor_types.contains(&investor_type), Error::<T>::InvestorTypeNotEligible);
+			ensure!(!CampaignDispensed::<T>::contains_key(campaign_id, &did), Error::<T>::DispensedAlreadyToDid);
+
+			let campaign_account = Self::campaign_account(campaign_id);
+			ensure!(CurrencyOf::<T>::free_balance(&campaign_account) >= info.amount, Error::<T>::DispenserDepleted);
+			Self::transfer_vested(
+				&campaign_account,
 				&who,
-				locked_amount,
-				per_block,
-				current_block + T::LockPeriod::get(),
+				info.amount,
+				info.free_amount,
+				info.lock_period,
+				info.vest_period,
 			)?;
 
-			Dispensed::<T>::insert(did.clone(), ());
-			Self::deposit_event(Event::Dispensed { dispensed_to_did: did, dispensed_to: who, amount });
+			CampaignDispensed::<T>::insert(campaign_id, did.clone(), ());
+			Self::deposit_event(Event::CampaignDispensed {
+				campaign_id,
+				dispensed_to_did: did,
+				dispensed_to: who,
+				amount: info.amount,
+			});
 
 			Ok(Pays::No.into())
 		}
 
-		#[pallet::call_index(1)]
-		#[pallet::weight(T::WeightInfo::set_dispense_amount())]
-		pub fn set_dispense_amount(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResultWithPostInfo {
+		/// End a campaign before or after its end block, and return its remaining funds to the dispenser.
+		/// Up to `MaxCampaignCleanup` receipts of the campaign are removed, the rest with `clean_up_campaign`.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::end_campaign(T::MaxCampaignCleanup::get()))]
+		pub fn end_campaign(origin: OriginFor<T>, campaign_id: CampaignId) -> DispatchResultWithPostInfo {
 			T::AdminOrigin::ensure_origin(origin)?;
-			ensure!(amount > T::FreeDispenseAmount::get(), Error::<T>::DispenseAmountTooLow);
-			DispenseAmount::<T>::put(amount);
-			Self::deposit_event(Event::DispenseAmountChanged(amount));
+			ensure!(Campaigns::<T>::contains_key(campaign_id), Error::<T>::CampaignNotFound);
+
+			let campaign_account = Self::campaign_account(campaign_id);
+			let returned = CurrencyOf::<T>::free_balance(&campaign_account);
+			<CurrencyOf<T>>::transfer(
+				&campaign_account,
+				&Self::dispense_account(),
+				returned,
+				ExistenceRequirement::AllowDeath,
+			)?;
+			Campaigns::<T>::remove(campaign_id);
+			let (removed, complete) = Self::remove_campaign_receipts(campaign_id);
+			Self::deposit_event(Event::CampaignEnded { campaign_id, returned });
+			Self::deposit_event(Event::CampaignCleanedUp { campaign_id, removed, complete });
+			Ok((Some(T::WeightInfo::end_campaign(removed)), Pays::No).into())
+		}
+
+		/// Move `amount` from the dispenser to the account of a campaign, to be dispensed by it.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::fund_campaign())]
+		pub fn fund_campaign(
+			origin: OriginFor<T>,
+			campaign_id: CampaignId,
+			amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(Campaigns::<T>::contains_key(campaign_id), Error::<T>::CampaignNotFound);
+
+			let dispense_account = Self::dispense_account();
+			ensure!(CurrencyOf::<T>::free_balance(&dispense_account) >= amount, Error::<T>::DispenserDepleted);
+			<CurrencyOf<T>>::transfer(
+				&dispense_account,
+				&Self::campaign_account(campaign_id),
+				amount,
+				ExistenceRequirement::AllowDeath,
+			)?;
+			Self::deposit_event(Event::CampaignFunded { campaign_id, amount });
 			Ok(Pays::No.into())
 		}
+
+		/// Remove up to `MaxCampaignCleanup` more receipts of an ended campaign. Can be called by anyone.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::clean_up_campaign(T::MaxCampaignCleanup::get()))]
+		pub fn clean_up_campaign(origin: OriginFor<T>, campaign_id: CampaignId) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			ensure!(
+				campaign_id < NextCampaignId::<T>::get() && !Campaigns::<T>::contains_key(campaign_id),
+				Error::<T>::CampaignNotEnded
+			);
+			ensure!(
+				CampaignDispensed::<T>::iter_key_prefix(campaign_id).next().is_some(),
+				Error::<T>::CampaignAlreadyCleanedUp
+			);
+
+			let (removed, complete) = Self::remove_campaign_receipts(campaign_id);
+			Self::deposit_event(Event::CampaignCleanedUp { campaign_id, removed, complete });
+			Ok(Some(T::WeightInfo::clean_up_campaign(removed)).into())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
 		pub fn dispense_account() -> T::AccountId {
 			T::PalletId::get().into_account_truncating()
 		}
+
+		/// The sub-account of the dispenser that funds the campaign `campaign_id`.
+		pub fn campaign_account(campaign_id: CampaignId) -> T::AccountId {
+			// Offset by one, so the first campaign does not share the account of the dispenser.
+			T::PalletId::get().into_sub_account_truncating(campaign_id.saturating_add(1))
+		}
+
+		/// Remove up to `MaxCampaignCleanup` receipts of the campaign `campaign_id`. Returns how many were removed,
+		/// and whether none are left.
+		fn remove_campaign_receipts(campaign_id: CampaignId) -> (u32, bool) {
+			let result = CampaignDispensed::<T>::clear_prefix(campaign_id, T::MaxCampaignCleanup::get(), None);
+			(result.unique, result.maybe_cursor.is_none())
+		}
+
+		/// Whether `did` can currently receive tokens from the campaign `campaign_id`.
+		pub fn is_eligible_for_campaign(campaign_id: CampaignId, did: &Did, investor_type: &InvestorType) -> bool {
+			Campaigns::<T>::get(campaign_id).is_some_and(|info| {
+				<frame_system::Pallet<T>>::block_number() <= info.end &&
+					info.investor_types.contains(investor_type) &&
+					!CampaignDispensed::<T>::contains_key(campaign_id, did)
+			})
+		}
+
+		/// Whether `did` can currently receive tokens in the active mode.
+		pub fn is_eligible(did: &Did) -> bool {
+			match Mode::<T>::get() {
+				DispenseMode::OneTime => Dispensed::<T>::get(did).is_none(),
+				DispenseMode::Faucet(settings) => {
+					let now = <frame_system::Pallet<T>>::block_number();
+					Self::refill_cooldown_over(did, &settings, now) && Self::refilled_with(&settings, now).is_some()
+				},
+			}
+		}
+
+		fn do_dispense_once(who: AccountIdOf<T>, did: Did) -> DispatchResultWithPostInfo {
+			ensure!(Dispensed::<T>::get(&did).is_none(), Error::<T>::DispensedAlreadyToDid);
+
+			let amount = DispenseAmount::<T>::get();
+			ensure!(CurrencyOf::<T>::free_balance(&Self::dispense_account()) >= amount, Error::<T>::DispenserDepleted);
+			Self::transfer_vested(
+				&Self::dispense_account(),
+				&who,
+				amount,
+				T::FreeDispenseAmount::get(),
+				T::LockPeriod::get(),
+				T::VestPeriod::get(),
+			)?;
+
+			Dispensed::<T>::insert(did.clone(), ());
+			Self::deposit_event(Event::Dispensed { dispensed_to_did: did, dispensed_to: who, amount });
+
+			Ok(Pays::No.into())
+		}
+
+		fn do_refill(who: AccountIdOf<T>, did: Did, settings: FaucetSettingsOf<T>) -> DispatchResultWithPostInfo {
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(Self::refill_cooldown_over(&did, &settings, now), Error::<T>::RefillCooldownNotOver);
+			let (block_refilled, day_refilled) =
+				Self::refilled_with(&settings, now).ok_or(Error::<T>::FaucetBudgetExhausted)?;
+
+			let amount = settings.amount;
+			ensure!(CurrencyOf::<T>::free_balance(&Self::dispense_account()) >= amount, Error::<T>::DispenserDepleted);
+			<CurrencyOf<T>>::transfer(&Self::dispense_account(), &who, amount, ExistenceRequirement::AllowDeath)?;
+
+			BlockRefilled::<T>::put((now, block_refilled));
+			DayRefilled::<T>::put((Self::day_start(now), day_refilled));
+			LastRefill::<T>::insert(did.clone(), now);
+			Self::deposit_event(Event::Dispensed { dispensed_to_did: did, dispensed_to: who, amount });
+
+			Ok(Pays::No.into())
+		}
+
+		/// Transfer `amount` from `from` to `who`, keeping all but `free_amount` locked for `lock_period` and then
+		/// vested over `vest_period`.
+		fn transfer_vested(
+			from: &AccountIdOf<T>,
+			who: &AccountIdOf<T>,
+			amount: BalanceOf<T>,
+			free_amount: BalanceOf<T>,
+			lock_period: BlockNumberFor<T>,
+			vest_period: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let current_block = <frame_system::Pallet<T>>::block_number();
+			let length_as_balance = T::BlockNumberToBalance::convert(vest_period);
+			let locked_amount = amount.saturating_sub(free_amount);
+			let per_block = locked_amount
+				.checked_div(&length_as_balance.max(sp_runtime::traits::One::one()))
+				.ok_or(DispatchError::Arithmetic(sp_runtime::ArithmeticError::Underflow))?;
+
+			T::VestingSchedule::can_add_vesting_schedule(who, locked_amount, per_block, current_block + lock_period)?;
+
+			<CurrencyOf<T>>::transfer(from, who, amount, ExistenceRequirement::AllowDeath)?;
+			T::VestingSchedule::add_vesting_schedule(who, locked_amount, per_block, current_block + lock_period)
+		}
+
+		fn refill_cooldown_over(did: &Did, settings: &FaucetSettingsOf<T>, now: BlockNumberFor<T>) -> bool {
+			LastRefill::<T>::get(did).map_or(true, |last_refill| now >= last_refill.saturating_add(settings.cooldown))
+		}
+
+		/// The amounts refilled in the current block and day after one more refill, or `None` if that refill
+		/// would go over either budget.
+		fn refilled_with(
+			settings: &FaucetSettingsOf<T>,
+			now: BlockNumberFor<T>,
+		) -> Option<(BalanceOf<T>, BalanceOf<T>)> {
+			let (block, block_refilled) = BlockRefilled::<T>::get();
+			let block_refilled = if block == now { block_refilled } else { Zero::zero() };
+			let (day_start, day_refilled) = DayRefilled::<T>::get();
+			let day_refilled = if day_start == Self::day_start(now) { day_refilled } else { Zero::zero() };
+
+			let block_refilled = block_refilled.saturating_add(settings.amount);
+			let day_refilled = day_refilled.saturating_add(settings.amount);
+			(block_refilled <= settings.block_budget && day_refilled <= settings.daily_budget)
+				.then_some((block_refilled, day_refilled))
+		}
+
+		fn day_start(block: BlockNumberFor<T>) -> BlockNumberFor<T> {
+			let blocks_per_day = T::BlocksPerDay::get().max(One::one());
+			block.saturating_sub(block % blocks_per_day)
+		}
 	}
 }
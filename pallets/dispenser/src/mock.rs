@@ -70,9 +70,11 @@ impl pallet_vesting::Config for Test {
 }
 
 parameter_types! {
+	pub const BlocksPerDay: u64 = 20;
 	pub const InitialDispenseAmount: u64 = 100;
 	pub const FreeDispenseAmount: u64 = 5;
 	pub const LockPeriod: u64 = 10;
+	pub const MaxCampaignCleanup: u32 = 2;
 	pub const DispenserPalletId: PalletId = PalletId(*b"plmc/fct");
 	pub const VestPeriod: u64 = 10;
 	pub VerifierPublicKey: [u8; 32] = [
@@ -88,10 +90,12 @@ ord_parameter_types! {
 impl crate::Config for Test {
 	type AdminOrigin = EnsureSignedBy<Admin, AccountId>;
 	type BlockNumberToBalance = ConvertInto;
+	type BlocksPerDay = BlocksPerDay;
 	type FreeDispenseAmount = FreeDispenseAmount;
 	type InitialDispenseAmount = InitialDispenseAmount;
 	type InvestorOrigin = EnsureInvestor<Test>;
 	type LockPeriod = LockPeriod;
+	type MaxCampaignCleanup = MaxCampaignCleanup;
 	type PalletId = DispenserPalletId;
 	type RuntimeEvent = RuntimeEvent;
 	type VerifierPublicKey = VerifierPublicKey;
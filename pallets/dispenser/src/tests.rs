@@ -19,7 +19,7 @@
 use super::*;
 use crate as pallet_dispenser;
 use crate::mock::*;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchResultWithPostInfo};
 use polimec_common::credentials::InvestorType;
 use polimec_common_test_utils::{generate_did_from_account, get_mock_jwt};
 use sp_runtime::DispatchError;
@@ -44,6 +44,36 @@ mod admin {
 		});
 	}
 
+	#[test]
+	fn only_admin_can_change_dispense_mode() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_eq!(Mode::<Test>::get(), DispenseMode::OneTime);
+			let mode =
+				DispenseMode::Faucet(FaucetSettings { amount: 10, cooldown: 5, block_budget: 20, daily_budget: 30 });
+			assert_noop!(Dispenser::set_dispense_mode(RuntimeOrigin::signed(1), mode), DispatchError::BadOrigin);
+			assert_ok!(Dispenser::set_dispense_mode(RuntimeOrigin::signed(Admin::get()), mode));
+			assert_eq!(Mode::<Test>::get(), mode);
+			assert_ok!(Dispenser::set_dispense_mode(RuntimeOrigin::signed(Admin::get()), DispenseMode::OneTime));
+			assert_eq!(Mode::<Test>::get(), DispenseMode::OneTime);
+		});
+	}
+
+	#[test]
+	fn faucet_amount_has_to_fit_in_budgets() {
+		ExtBuilder::default().build().execute_with(|| {
+			for settings in [
+				FaucetSettings { amount: 0, cooldown: 5, block_budget: 20, daily_budget: 30 },
+				FaucetSettings { amount: 10, cooldown: 5, block_budget: 5, daily_budget: 30 },
+				FaucetSettings { amount: 10, cooldown: 5, block_budget: 20, daily_budget: 5 },
+			] {
+				assert_noop!(
+					Dispenser::set_dispense_mode(RuntimeOrigin::signed(Admin::get()), DispenseMode::Faucet(settings)),
+					Error::<Test>::InvalidFaucetSettings
+				);
+			}
+		});
+	}
+
 	#[test]
 	fn amount_has_to_be_higher_then_free_amount() {
 		ExtBuilder::default().build().execute_with(|| {
@@ -152,3 +182,348 @@ mod dispense {
 		});
 	}
 }
+
+mod faucet {
+	use super::*;
+
+	fn set_faucet(settings: FaucetSettings<u64, u64>) {
+		assert_ok!(Dispenser::set_dispense_mode(RuntimeOrigin::signed(Admin::get()), DispenseMode::Faucet(settings)));
+	}
+
+	fn refill(i: u64) -> DispatchResultWithPostInfo {
+		let jwt = get_mock_jwt(i, InvestorType::Retail, generate_did_from_account(i));
+		Dispenser::dispense(RuntimeOrigin::signed(i), jwt)
+	}
+
+	#[test]
+	fn did_is_refilled_after_cooldown() {
+		ExtBuilder::default().dispense_account(10).build().execute_with(|| {
+			set_faucet(FaucetSettings { amount: 10, cooldown: 5, block_budget: 100, daily_budget: 1000 });
+			let did = generate_did_from_account(1);
+			assert!(Dispenser::is_eligible(&did));
+			assert_ok!(refill(1));
+
+			// Refills are free and not vested.
+			assert_eq!(Balances::usable_balance(1), 10);
+			assert_eq!(Vesting::vesting_balance(&1), None);
+			assert_eq!(LastRefill::<Test>::get(&did), Some(1));
+			assert_eq!(Dispensed::<Test>::get(&did), None);
+
+			assert!(!Dispenser::is_eligible(&did));
+			System::set_block_number(5);
+			assert_noop!(refill(1), Error::<Test>::RefillCooldownNotOver);
+			System::set_block_number(6);
+			assert!(Dispenser::is_eligible(&did));
+			assert_ok!(refill(1));
+			assert_eq!(Balances::usable_balance(1), 20);
+		});
+	}
+
+	#[test]
+	fn refills_are_capped_per_block() {
+		ExtBuilder::default().dispense_account(10).build().execute_with(|| {
+			set_faucet(FaucetSettings { amount: 10, cooldown: 5, block_budget: 20, daily_budget: 1000 });
+			assert_ok!(refill(1));
+			assert_ok!(refill(2));
+			assert!(!Dispenser::is_eligible(&generate_did_from_account(3)));
+			assert_noop!(refill(3), Error::<Test>::FaucetBudgetExhausted);
+
+			System::set_block_number(2);
+			assert_ok!(refill(3));
+			assert_eq!(BlockRefilled::<Test>::get(), (2, 10));
+		});
+	}
+
+	#[test]
+	fn refills_are_capped_per_day() {
+		ExtBuilder::default().dispense_account(10).build().execute_with(|| {
+			set_faucet(FaucetSettings { amount: 10, cooldown: 5, block_budget: 10, daily_budget: 30 });
+			for i in 1..=3 {
+				System::set_block_number(i);
+				assert_ok!(refill(i));
+			}
+			System::set_block_number(19);
+			assert_noop!(refill(4), Error::<Test>::FaucetBudgetExhausted);
+
+			// A new day starts every `BlocksPerDay` blocks.
+			System::set_block_number(BlocksPerDay::get());
+			assert_ok!(refill(4));
+			assert_eq!(DayRefilled::<Test>::get(), (BlocksPerDay::get(), 10));
+		});
+	}
+
+	#[test]
+	fn one_time_dispense_is_kept_next_to_faucet() {
+		ExtBuilder::default().dispense_account(10).build().execute_with(|| {
+			assert_ok!(refill(1));
+			assert_eq!(Balances::free_balance(1), <Test as pallet_dispenser::Config>::InitialDispenseAmount::get());
+
+			// A DID that already got its one-time dispense can still be refilled by the faucet.
+			set_faucet(FaucetSettings { amount: 10, cooldown: 5, block_budget: 100, daily_budget: 1000 });
+			assert_ok!(refill(1));
+			assert_eq!(
+				Balances::free_balance(1),
+				<Test as pallet_dispenser::Config>::InitialDispenseAmount::get() + 10
+			);
+
+			assert_ok!(Dispenser::set_dispense_mode(RuntimeOrigin::signed(Admin::get()), DispenseMode::OneTime));
+			assert_noop!(refill(1), Error::<Test>::DispensedAlreadyToDid);
+		});
+	}
+}
+
+mod campaigns {
+	use super::*;
+
+	fn campaign(investor_types: Vec<InvestorType>) -> CampaignInfo<u64, u64> {
+		CampaignInfo {
+			amount: 50,
+			free_amount: 10,
+			investor_types: BoundedVec::truncate_from(investor_types),
+			lock_period: 5,
+			vest_period: 20,
+			end: 10,
+		}
+	}
+
+	fn create_funded_campaign(info: CampaignInfo<u64, u64>) -> CampaignId {
+		let campaign_id = NextCampaignId::<Test>::get();
+		assert_ok!(Dispenser::create_campaign(RuntimeOrigin::signed(Admin::get()), info));
+		Balances::make_free_balance_be(&Dispenser::campaign_account(campaign_id), 100);
+		campaign_id
+	}
+
+	fn dispense(campaign_id: CampaignId, i: u64, investor_type: InvestorType) -> DispatchResultWithPostInfo {
+		let jwt = get_mock_jwt(i, investor_type, generate_did_from_account(i));
+		Dispenser::dispense_campaign(RuntimeOrigin::signed(i), campaign_id, jwt)
+	}
+
+	#[test]
+	fn only_admin_can_create_campaigns() {
+		ExtBuilder::default().build().execute_with(|| {
+			let info = campaign(vec![InvestorType::Retail]);
+			assert_noop!(Dispenser::create_campaign(RuntimeOrigin::signed(1), info.clone()), DispatchError::BadOrigin);
+			assert_ok!(Dispenser::create_campaign(RuntimeOrigin::signed(Admin::get()), info.clone()));
+			assert_eq!(Campaigns::<Test>::get(0), Some(info));
+			assert_eq!(NextCampaignId::<Test>::get(), 1);
+			System::assert_last_event(RuntimeEvent::Dispenser(Event::CampaignCreated {
+				campaign_id: 0,
+				account: Dispenser::campaign_account(0),
+			}));
+			assert_ne!(Dispenser::campaign_account(0), Dispenser::dispense_account());
+		});
+	}
+
+	#[test]
+	fn campaign_parameters_are_validated() {
+		ExtBuilder::default().build().execute_with(|| {
+			let info = campaign(vec![InvestorType::Retail]);
+			for invalid in [
+				CampaignInfo { amount: 0, free_amount: 0, ..info.clone() },
+				CampaignInfo { free_amount: 60, ..info.clone() },
+				CampaignInfo { investor_types: BoundedVec::new(), ..info.clone() },
+			] {
+				assert_noop!(
+					Dispenser::create_campaign(RuntimeOrigin::signed(Admin::get()), invalid),
+					Error::<Test>::InvalidCampaign
+				);
+			}
+
+			// The end block cannot be in the past.
+			System::set_block_number(11);
+			assert_noop!(
+				Dispenser::create_campaign(RuntimeOrigin::signed(Admin::get()), info),
+				Error::<Test>::InvalidCampaign
+			);
+		});
+	}
+
+	#[test]
+	fn campaigns_dispense_with_their_own_vesting() {
+		ExtBuilder::default().build().execute_with(|| {
+			let vested = create_funded_campaign(campaign(vec![InvestorType::Retail]));
+			let free = create_funded_campaign(CampaignInfo {
+				amount: 30,
+				free_amount: 30,
+				..campaign(vec![InvestorType::Retail])
+			});
+
+			assert_ok!(dispense(vested, 1, InvestorType::Retail));
+			assert_eq!(Balances::free_balance(1), 50);
+			assert_eq!(Balances::usable_balance(1), 10);
+			assert_eq!(Vesting::vesting_balance(&1), Some(40));
+			System::assert_last_event(RuntimeEvent::Dispenser(Event::CampaignDispensed {
+				campaign_id: vested,
+				dispensed_to_did: generate_did_from_account(1),
+				dispensed_to: 1,
+				amount: 50,
+			}));
+
+			// The same DID can receive tokens once from each campaign.
+			assert_ok!(dispense(free, 1, InvestorType::Retail));
+			assert_eq!(Balances::free_balance(1), 80);
+			assert_eq!(Balances::usable_balance(1), 40);
+			assert_noop!(dispense(vested, 1, InvestorType::Retail), Error::<Test>::DispensedAlreadyToDid);
+
+			assert_eq!(CampaignDispensed::<Test>::get(vested, generate_did_from_account(1)), Some(()));
+			assert_eq!(Dispensed::<Test>::get(generate_did_from_account(1)), None);
+			assert_eq!(Balances::free_balance(Dispenser::campaign_account(vested)), 50);
+			assert_eq!(Balances::free_balance(Dispenser::campaign_account(free)), 70);
+			assert_eq!(
+				Balances::free_balance(Dispenser::dispense_account()),
+				<Test as pallet_dispenser::Config>::InitialDispenseAmount::get()
+			);
+		});
+	}
+
+	#[test]
+	fn campaigns_filter_investor_types() {
+		ExtBuilder::default().build().execute_with(|| {
+			let campaign_id =
+				create_funded_campaign(campaign(vec![InvestorType::Professional, InvestorType::Institutional]));
+			let did = generate_did_from_account(1);
+			assert!(!Dispenser::is_eligible_for_campaign(campaign_id, &did, &InvestorType::Retail));
+			assert_noop!(dispense(campaign_id, 1, InvestorType::Retail), Error::<Test>::InvestorTypeNotEligible);
+
+			assert!(Dispenser::is_eligible_for_campaign(campaign_id, &did, &InvestorType::Professional));
+			assert_ok!(dispense(campaign_id, 1, InvestorType::Professional));
+			assert_ok!(dispense(campaign_id, 2, InvestorType::Institutional));
+		});
+	}
+
+	#[test]
+	fn campaigns_stop_at_their_end() {
+		ExtBuilder::default().build().execute_with(|| {
+			let campaign_id = create_funded_campaign(campaign(vec![InvestorType::Retail]));
+			System::set_block_number(10);
+			assert_ok!(dispense(campaign_id, 1, InvestorType::Retail));
+
+			System::set_block_number(11);
+			let did = generate_did_from_account(2);
+			assert!(!Dispenser::is_eligible_for_campaign(campaign_id, &did, &InvestorType::Retail));
+			assert_noop!(dispense(campaign_id, 2, InvestorType::Retail), Error::<Test>::CampaignOver);
+			assert_noop!(dispense(campaign_id + 1, 2, InvestorType::Retail), Error::<Test>::CampaignNotFound);
+		});
+	}
+
+	#[test]
+	fn campaign_is_limited_by_its_own_budget() {
+		ExtBuilder::default().build().execute_with(|| {
+			let campaign_id = create_funded_campaign(campaign(vec![InvestorType::Retail]));
+			assert_ok!(dispense(campaign_id, 1, InvestorType::Retail));
+			assert_ok!(dispense(campaign_id, 2, InvestorType::Retail));
+			assert_noop!(dispense(campaign_id, 3, InvestorType::Retail), Error::<Test>::DispenserDepleted);
+		});
+	}
+
+	#[test]
+	fn ending_a_campaign_returns_its_funds() {
+		ExtBuilder::default().build().execute_with(|| {
+			let campaign_id = create_funded_campaign(campaign(vec![InvestorType::Retail]));
+			assert_ok!(dispense(campaign_id, 1, InvestorType::Retail));
+
+			assert_noop!(Dispenser::end_campaign(RuntimeOrigin::signed(1), campaign_id), DispatchError::BadOrigin);
+			assert_ok!(Dispenser::end_campaign(RuntimeOrigin::signed(Admin::get()), campaign_id));
+			System::assert_has_event(RuntimeEvent::Dispenser(Event::CampaignEnded { campaign_id, returned: 50 }));
+			System::assert_last_event(RuntimeEvent::Dispenser(Event::CampaignCleanedUp {
+				campaign_id,
+				removed: 1,
+				complete: true,
+			}));
+			assert_eq!(Campaigns::<Test>::get(campaign_id), None);
+			assert_eq!(CampaignDispensed::<Test>::iter_key_prefix(campaign_id).count(), 0);
+			assert_eq!(Balances::free_balance(Dispenser::campaign_account(campaign_id)), 0);
+			assert_eq!(
+				Balances::free_balance(Dispenser::dispense_account()),
+				<Test as pallet_dispenser::Config>::InitialDispenseAmount::get() + 50
+			);
+
+			assert_noop!(dispense(campaign_id, 2, InvestorType::Retail), Error::<Test>::CampaignNotFound);
+			assert_noop!(
+				Dispenser::end_campaign(RuntimeOrigin::signed(Admin::get()), campaign_id),
+				Error::<Test>::CampaignNotFound
+			);
+		});
+	}
+
+	#[test]
+	fn funded_campaign_returns_its_leftovers_when_ended() {
+		ExtBuilder::default().build().execute_with(|| {
+			let admin = RuntimeOrigin::signed(Admin::get());
+			let initial = <Test as pallet_dispenser::Config>::InitialDispenseAmount::get();
+			assert_ok!(Dispenser::create_campaign(admin.clone(), campaign(vec![InvestorType::Retail])));
+			let campaign_id = 0;
+
+			assert_noop!(Dispenser::fund_campaign(RuntimeOrigin::signed(1), campaign_id, 60), DispatchError::BadOrigin);
+			assert_noop!(Dispenser::fund_campaign(admin.clone(), campaign_id + 1, 60), Error::<Test>::CampaignNotFound);
+			assert_noop!(
+				Dispenser::fund_campaign(admin.clone(), campaign_id, initial + 1),
+				Error::<Test>::DispenserDepleted
+			);
+			assert_ok!(Dispenser::fund_campaign(admin.clone(), campaign_id, 60));
+			System::assert_last_event(RuntimeEvent::Dispenser(Event::CampaignFunded { campaign_id, amount: 60 }));
+			assert_eq!(Balances::free_balance(Dispenser::campaign_account(campaign_id)), 60);
+			assert_eq!(Balances::free_balance(Dispenser::dispense_account()), initial - 60);
+
+			assert_ok!(dispense(campaign_id, 1, InvestorType::Retail));
+			assert_noop!(dispense(campaign_id, 2, InvestorType::Retail), Error::<Test>::DispenserDepleted);
+
+			assert_ok!(Dispenser::end_campaign(admin, campaign_id));
+			System::assert_has_event(RuntimeEvent::Dispenser(Event::CampaignEnded { campaign_id, returned: 10 }));
+			assert_eq!(Balances::free_balance(Dispenser::campaign_account(campaign_id)), 0);
+			assert_eq!(Balances::free_balance(Dispenser::dispense_account()), initial - 50);
+			assert_eq!(CampaignDispensed::<Test>::get(campaign_id, generate_did_from_account(1)), None);
+		});
+	}
+
+	#[test]
+	fn ended_campaign_receipts_are_cleaned_up_in_batches() {
+		let mut ext = ExtBuilder::default().build();
+		let campaign_id = ext.execute_with(|| {
+			let campaign_id = create_funded_campaign(campaign(vec![InvestorType::Retail]));
+			Balances::make_free_balance_be(&Dispenser::campaign_account(campaign_id), 250);
+			for i in 1..=5 {
+				assert_ok!(dispense(campaign_id, i, InvestorType::Retail));
+			}
+			assert_noop!(
+				Dispenser::clean_up_campaign(RuntimeOrigin::signed(1), campaign_id),
+				Error::<Test>::CampaignNotEnded
+			);
+			campaign_id
+		});
+		// Uncommitted keys are all removed at once, regardless of the limit.
+		ext.commit_all().unwrap();
+
+		ext.execute_with(|| {
+			// `MaxCampaignCleanup` is 2 in the mock.
+			assert_ok!(Dispenser::end_campaign(RuntimeOrigin::signed(Admin::get()), campaign_id));
+			System::assert_last_event(RuntimeEvent::Dispenser(Event::CampaignCleanedUp {
+				campaign_id,
+				removed: 2,
+				complete: false,
+			}));
+			assert_ok!(Dispenser::clean_up_campaign(RuntimeOrigin::signed(1), campaign_id));
+			System::assert_last_event(RuntimeEvent::Dispenser(Event::CampaignCleanedUp {
+				campaign_id,
+				removed: 2,
+				complete: false,
+			}));
+			assert_ok!(Dispenser::clean_up_campaign(RuntimeOrigin::signed(1), campaign_id));
+			System::assert_last_event(RuntimeEvent::Dispenser(Event::CampaignCleanedUp {
+				campaign_id,
+				removed: 1,
+				complete: true,
+			}));
+			assert_eq!(CampaignDispensed::<Test>::iter_key_prefix(campaign_id).count(), 0);
+
+			assert_noop!(
+				Dispenser::clean_up_campaign(RuntimeOrigin::signed(1), campaign_id),
+				Error::<Test>::CampaignAlreadyCleanedUp
+			);
+			assert_noop!(
+				Dispenser::clean_up_campaign(RuntimeOrigin::signed(1), campaign_id + 1),
+				Error::<Test>::CampaignNotEnded
+			);
+		});
+	}
+}
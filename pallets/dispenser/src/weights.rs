@@ -54,7 +54,14 @@ use core::marker::PhantomData;
 /// Weight functions needed for `pallet_dispenser`.
 pub trait WeightInfo {
 	fn dispense() -> Weight;
+	fn dispense_faucet() -> Weight;
 	fn set_dispense_amount() -> Weight;
+	fn set_dispense_mode() -> Weight;
+	fn create_campaign() -> Weight;
+	fn dispense_campaign() -> Weight;
+	fn end_campaign(x: u32, ) -> Weight;
+	fn fund_campaign() -> Weight;
+	fn clean_up_campaign(x: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_dispenser` using the Substrate node and recommended hardware.
@@ -83,6 +90,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(7_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	/// Placeholder until the weights are regenerated with the benchmark CLI. Reads the timestamp to check the
+	/// credential, the mode, the DID's last refill, the block and day refills and both accounts, and writes all but
+	/// the timestamp and the mode.
+	fn dispense_faucet() -> Weight {
+		Weight::from_parts(100_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
 	/// Storage: `Dispenser::DispenseAmount` (r:0 w:1)
 	/// Proof: `Dispenser::DispenseAmount` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	fn set_dispense_amount() -> Weight {
@@ -93,6 +108,53 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(3_580_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Placeholder until the weights are regenerated with the benchmark CLI. Writes the mode.
+	fn set_dispense_mode() -> Weight {
+		Weight::from_parts(4_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Placeholder until the weights are regenerated with the benchmark CLI. Reads and bumps the next campaign id,
+	/// and writes the campaign.
+	fn create_campaign() -> Weight {
+		Weight::from_parts(10_000_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Placeholder until the weights are regenerated with the benchmark CLI. Reads the campaign, the time and the
+	/// receipt, transfers from the campaign account and adds a vesting schedule.
+	fn dispense_campaign() -> Weight {
+		Weight::from_parts(200_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Placeholder until the weights are regenerated with the benchmark CLI. Removes the campaign, returns its funds
+	/// and removes `x` receipts.
+	/// The range of component `x` is `[0, 500]`.
+	fn end_campaign(x: u32, ) -> Weight {
+		Weight::from_parts(70_000_000, 6196)
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2569).saturating_mul(x.into()))
+	}
+	/// Placeholder until the weights are regenerated with the benchmark CLI. Reads the campaign and transfers from
+	/// the dispenser to the campaign account.
+	fn fund_campaign() -> Weight {
+		Weight::from_parts(70_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Placeholder until the weights are regenerated with the benchmark CLI. Reads the next campaign id and the
+	/// campaign, and removes `x` receipts.
+	/// The range of component `x` is `[1, 500]`.
+	fn clean_up_campaign(x: u32, ) -> Weight {
+		Weight::from_parts(15_000_000, 3559)
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2569).saturating_mul(x.into()))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -120,6 +182,14 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(7_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
+	/// Placeholder until the weights are regenerated with the benchmark CLI. Reads the timestamp to check the
+	/// credential, the mode, the DID's last refill, the block and day refills and both accounts, and writes all but
+	/// the timestamp and the mode.
+	fn dispense_faucet() -> Weight {
+		Weight::from_parts(100_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
 	/// Storage: `Dispenser::DispenseAmount` (r:0 w:1)
 	/// Proof: `Dispenser::DispenseAmount` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	fn set_dispense_amount() -> Weight {
@@ -130,4 +200,51 @@ impl WeightInfo for () {
 		Weight::from_parts(3_580_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Placeholder until the weights are regenerated with the benchmark CLI. Writes the mode.
+	fn set_dispense_mode() -> Weight {
+		Weight::from_parts(4_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Placeholder until the weights are regenerated with the benchmark CLI. Reads and bumps the next campaign id,
+	/// and writes the campaign.
+	fn create_campaign() -> Weight {
+		Weight::from_parts(10_000_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Placeholder until the weights are regenerated with the benchmark CLI. Reads the campaign, the time and the
+	/// receipt, transfers from the campaign account and adds a vesting schedule.
+	fn dispense_campaign() -> Weight {
+		Weight::from_parts(200_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Placeholder until the weights are regenerated with the benchmark CLI. Removes the campaign, returns its funds
+	/// and removes `x` receipts.
+	/// The range of component `x` is `[0, 500]`.
+	fn end_campaign(x: u32, ) -> Weight {
+		Weight::from_parts(70_000_000, 6196)
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2569).saturating_mul(x.into()))
+	}
+	/// Placeholder until the weights are regenerated with the benchmark CLI. Reads the campaign and transfers from
+	/// the dispenser to the campaign account.
+	fn fund_campaign() -> Weight {
+		Weight::from_parts(70_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Placeholder until the weights are regenerated with the benchmark CLI. Reads the next campaign id and the
+	/// campaign, and removes `x` receipts.
+	/// The range of component `x` is `[1, 500]`.
+	fn clean_up_campaign(x: u32, ) -> Weight {
+		Weight::from_parts(15_000_000, 3559)
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2569).saturating_mul(x.into()))
+	}
 }
\ No newline at end of file
@@ -36,7 +36,7 @@ use scale_info::prelude::format;
 use sp_arithmetic::Percent;
 use sp_core::H256;
 use sp_io::hashing::blake2_256;
-use sp_runtime::traits::{Get, Member, TrailingZeroInput, Zero};
+use sp_runtime::traits::{Convert, Get, Member, TrailingZeroInput, Zero};
 
 const IPFS_CID: &str = "QmbvsJBhQtu9uAGVp7x4H77JkwAQxV7TA6xTfdeALuDiYB";
 const CT_DECIMALS: u8 = 17;
@@ -94,6 +94,9 @@ where
 		participation_currencies: vec![AcceptedFundingAsset::USDT].try_into().unwrap(),
 		funding_destination_account: issuer,
 		policy_ipfs_cid: Some(metadata_hash.into()),
+		phase_durations: None,
+		scheduled_starts: Default::default(),
+		funding_caps: None,
 	}
 }
 
@@ -528,6 +531,9 @@ mod benchmarks {
 			participation_currencies: vec![AcceptedFundingAsset::USDT, AcceptedFundingAsset::USDC].try_into().unwrap(),
 			funding_destination_account: issuer_funding.clone().clone(),
 			policy_ipfs_cid: Some(BoundedVec::try_from(IPFS_CID.as_bytes().to_vec()).unwrap()),
+			phase_durations: None,
+			scheduled_starts: Default::default(),
+			funding_caps: None,
 		};
 
 		let jwt = get_mock_jwt_with_cid(
@@ -2686,6 +2692,226 @@ mod benchmarks {
 		assert_eq!(project_details.status, ProjectStatus::FundingFailed);
 	}
 
+	#[benchmark]
+	fn migrate_participants_batch(x: Linear<1, 100>) {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+
+		// real benchmark starts at block 0, and we can't call `events()` at block 0
+		inst.advance_time(1u32.into()).unwrap();
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let project_id = inst.create_finished_project(
+			default_project_metadata::<T>(issuer.clone()),
+			issuer,
+			default_evaluations::<T>(),
+			default_bids::<T>(),
+			default_community_contributions::<T>(),
+			vec![],
+		);
+		run_blocks_to_execute_next_transition(project_id, UpdateType::StartSettlement, &mut inst);
+
+		// Every participant in the batch has one migration
+		let participants = (0..x).map(|i| account::<AccountIdOf<T>>("migrating_participant", i, 0)).collect::<Vec<_>>();
+		inst.execute(|| {
+			ProjectsDetails::<T>::mutate(project_id, |details| {
+				let details = details.as_mut().unwrap();
+				details.parachain_id = Some(ParaId::from(6969u32));
+				details.hrmp_channel_status = HRMPChannelStatus {
+					project_to_polimec: ChannelStatus::Open,
+					polimec_to_project: ChannelStatus::Open,
+				};
+				details.migration_readiness_check = Some(MigrationReadinessCheck {
+					holding_check: (0, CheckOutcome::Passed),
+					pallet_check: (1, CheckOutcome::Passed),
+				});
+				details.receiver_call_indices = Some(ReceiverCallIndices { pallet: 51, call: 0 });
+			});
+			MigrationReadinessReports::<T>::insert(
+				project_id,
+				MigrationReadinessReport { metadata_check: Some((2, CheckOutcome::Passed)), ..Default::default() },
+			);
+
+			let _ = PendingMigrations::<T>::clear_prefix(project_id, u32::MAX, None);
+			for (id, participant) in participants.iter().enumerate() {
+				let origin = MigrationOrigin {
+					user: T::AccountId32Conversion::convert(participant.clone()),
+					id: id as u32,
+					participation_type: ParticipationType::Contribution,
+				};
+				let info = MigrationInfo::from((CT_UNIT, 1_000u64));
+				let migrations = BoundedVec::try_from(vec![Migration::new(origin, info)]).unwrap();
+				UserMigrations::<T>::insert(project_id, participant.clone(), (MigrationStatus::NotStarted, migrations));
+				PendingMigrations::<T>::insert(project_id, participant.clone(), ());
+			}
+		});
+
+		#[block]
+		{
+			Pallet::<T>::do_migrate_participants_batch(project_id).unwrap();
+		}
+
+		// * validity checks *
+		for participant in participants {
+			let (status, _) = UserMigrations::<T>::get(project_id, participant.clone()).unwrap();
+			assert!(matches!(status, MigrationStatus::Sent(_)));
+			assert!(!PendingMigrations::<T>::contains_key(project_id, participant));
+		}
+	}
+
+	#[benchmark]
+	fn start_offchain_migration(x: Linear<1, 1000>) {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+
+		// real benchmark starts at block 0, and we can't call `events()` at block 0
+		inst.advance_time(1u32.into()).unwrap();
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		whitelist_account!(issuer);
+		let project_metadata = default_project_metadata::<T>(issuer.clone());
+		let project_id = inst.create_finished_project(
+			project_metadata.clone(),
+			issuer.clone(),
+			default_evaluations::<T>(),
+			default_bids::<T>(),
+			default_community_contributions::<T>(),
+			vec![],
+		);
+
+		// Every participation is settled, and every participant has one migration
+		inst.execute(|| {
+			ProjectsDetails::<T>::mutate(project_id, |details| {
+				details.as_mut().unwrap().status = ProjectStatus::FundingSuccessful;
+			});
+			let _ = Evaluations::<T>::clear_prefix((project_id,), u32::MAX, None);
+			let _ = Bids::<T>::clear_prefix((project_id,), u32::MAX, None);
+			let _ = Contributions::<T>::clear_prefix((project_id,), u32::MAX, None);
+			let _ = UserMigrations::<T>::clear_prefix(project_id, u32::MAX, None);
+			for id in 0..x {
+				let participant = account::<AccountIdOf<T>>("migrating_participant", id, 0);
+				let origin = MigrationOrigin {
+					user: T::AccountId32Conversion::convert(participant.clone()),
+					id,
+					participation_type: ParticipationType::Contribution,
+				};
+				let info = MigrationInfo::from((CT_UNIT, 1_000u64));
+				let migrations = BoundedVec::try_from(vec![Migration::new(origin, info)]).unwrap();
+				UserMigrations::<T>::insert(project_id, participant, (MigrationStatus::NotStarted, migrations));
+			}
+		});
+
+		let jwt = get_mock_jwt_with_cid(
+			issuer.clone(),
+			InvestorType::Institutional,
+			generate_did_from_account(issuer.clone()),
+			project_metadata.policy_ipfs_cid.unwrap(),
+		);
+
+		#[extrinsic_call]
+		start_offchain_migration(RawOrigin::Signed(issuer), jwt, project_id, x);
+
+		// * validity checks *
+		assert_eq!(ProjectsDetails::<T>::get(project_id).unwrap().migration_type, MigrationType::Offchain);
+		assert!(MigrationMerkleRoots::<T>::contains_key(project_id));
+	}
+
+	// Every participant in the batch had its migration executed, so its CTs are burned
+	#[benchmark]
+	fn confirm_migrations(x: Linear<1, 100>) {
+		// setup
+		let mut inst = BenchInstantiator::<T>::new(None);
+
+		// real benchmark starts at block 0, and we can't call `events()` at block 0
+		inst.advance_time(1u32.into()).unwrap();
+
+		let issuer = account::<AccountIdOf<T>>("issuer", 0, 0);
+		let project_id = inst.create_finished_project(
+			default_project_metadata::<T>(issuer.clone()),
+			issuer,
+			default_evaluations::<T>(),
+			default_bids::<T>(),
+			default_community_contributions::<T>(),
+			vec![],
+		);
+		run_blocks_to_execute_next_transition(project_id, UpdateType::StartSettlement, &mut inst);
+
+		let para_id = ParaId::from(6969u32);
+		let location = MultiLocation { parents: 1, interior: X1(Parachain(para_id.into())) };
+		let query_id = 0;
+		let participants = (0..x).map(|i| account::<AccountIdOf<T>>("migrating_participant", i, 0)).collect::<Vec<_>>();
+		inst.execute(|| {
+			ProjectsDetails::<T>::mutate(project_id, |details| {
+				details.as_mut().unwrap().parachain_id = Some(para_id);
+			});
+			for (id, participant) in participants.iter().enumerate() {
+				let origin = MigrationOrigin {
+					user: T::AccountId32Conversion::convert(participant.clone()),
+					id: id as u32,
+					participation_type: ParticipationType::Contribution,
+				};
+				let info = MigrationInfo::from((CT_UNIT, 1_000u64));
+				let migrations = BoundedVec::try_from(vec![Migration::new(origin, info)]).unwrap();
+				UserMigrations::<T>::insert(
+					project_id,
+					participant.clone(),
+					(MigrationStatus::Sent(query_id), migrations),
+				);
+				<T::ContributionTokenCurrency as fungibles::Mutate<AccountIdOf<T>>>::mint_into(
+					project_id,
+					participant,
+					CT_UNIT.into(),
+				)
+				.unwrap();
+			}
+			let timeout_block = frame_system::Pallet::<T>::block_number() + T::MigrationQueryTimeout::get();
+			ActiveMigrationQueue::<T>::insert(
+				query_id,
+				(project_id, BoundedVec::try_from(participants.clone()).unwrap(), timeout_block),
+			);
+			MigrationTimeouts::<T>::insert(timeout_block, query_id, ());
+			MigrationQueriesPerParachain::<T>::insert(para_id, 1);
+		});
+
+		#[block]
+		{
+			Pallet::<T>::do_confirm_migrations(location, query_id, Response::DispatchResult(MaybeErrorCode::Success))
+				.unwrap();
+		}
+
+		// * validity checks *
+		for participant in participants {
+			let (status, _) = UserMigrations::<T>::get(project_id, participant.clone()).unwrap();
+			assert_eq!(status, MigrationStatus::Confirmed);
+			assert!(UnburnedMigratedContributionTokens::<T>::get(project_id, participant).is_zero());
+		}
+	}
+
+	#[benchmark]
+	fn report_failed_migrations(x: Linear<1, 100>) {
+		// setup
+		let para_id = ParaId::from(6969u32);
+		let location = MultiLocation { parents: 1, interior: X1(Parachain(para_id.into())) };
+		MigrationQueriesPerParachain::<T>::insert(para_id, 1);
+		let failed = (0..x)
+			.map(|id| MigrationOrigin {
+				user: T::AccountId32Conversion::convert(account::<AccountIdOf<T>>("migrating_participant", id, 0)),
+				id,
+				participation_type: ParticipationType::Contribution,
+			})
+			.collect::<Vec<_>>();
+
+		#[block]
+		{
+			Pallet::<T>::do_report_failed_migrations(location, failed.clone()).unwrap();
+		}
+
+		// * validity checks *
+		for MigrationOrigin { user, .. } in failed {
+			assert!(FailedMigrationReports::<T>::contains_key(para_id, user));
+		}
+	}
+
 	#[cfg(test)]
 	mod tests {
 		use super::*;
@@ -2887,5 +3113,26 @@ mod benchmarks {
 				assert_ok!(PalletFunding::<TestRuntime>::test_end_funding_awaiting_decision_evaluators_slashed());
 			});
 		}
+
+		#[test]
+		fn bench_start_offchain_migration() {
+			new_test_ext().execute_with(|| {
+				assert_ok!(PalletFunding::<TestRuntime>::test_start_offchain_migration());
+			});
+		}
+
+		#[test]
+		fn bench_confirm_migrations() {
+			new_test_ext().execute_with(|| {
+				assert_ok!(PalletFunding::<TestRuntime>::test_confirm_migrations());
+			});
+		}
+
+		#[test]
+		fn bench_report_failed_migrations() {
+			new_test_ext().execute_with(|| {
+				assert_ok!(PalletFunding::<TestRuntime>::test_report_failed_migrations());
+			});
+		}
 	}
 }
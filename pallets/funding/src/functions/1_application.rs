@@ -18,13 +18,35 @@ impl<T: Config> Pallet<T> {
 				MetadataError::CidNotProvided => Error::<T>::CidNotProvided,
 				MetadataError::BadDecimals => Error::<T>::BadDecimals,
 				MetadataError::BadTokenomics => Error::<T>::BadTokenomics,
+				MetadataError::FundingCapsError => Error::<T>::FundingCapsError,
 			};
 			return Err(pallet_error.into());
 		}
+		if let Some(phase_durations) = project_metadata.phase_durations {
+			ensure!(
+				phase_durations.is_within(&T::MinPhaseDurations::get(), &T::MaxPhaseDurations::get()),
+				Error::<T>::PhaseDurationsOutOfBounds
+			);
+		}
+		let now = <frame_system::Pallet<T>>::block_number();
+		let scheduled_starts = project_metadata.scheduled_starts;
+		if let Some(evaluation_start) = scheduled_starts.evaluation {
+			ensure!(evaluation_start > now, Error::<T>::InvalidPhaseSchedule);
+		}
+		if let Some(auction_start) = scheduled_starts.auction {
+			let earliest_auction_start = scheduled_starts
+				.evaluation
+				.unwrap_or(now)
+				.saturating_add(Self::project_phase_durations(&project_metadata).evaluation);
+			let latest_auction_start = earliest_auction_start.saturating_add(T::AuctionInitializePeriodDuration::get());
+			ensure!(
+				auction_start > earliest_auction_start && auction_start <= latest_auction_start,
+				Error::<T>::InvalidPhaseSchedule
+			);
+		}
 		let total_allocation_size = project_metadata.total_allocation_size;
 
 		// * Calculate new variables *
-		let now = <frame_system::Pallet<T>>::block_number();
 
 		let fundraising_target =
 			project_metadata.minimum_price.checked_mul_int(total_allocation_size).ok_or(Error::<T>::BadMath)?;
@@ -52,6 +74,8 @@ impl<T: Config> Pallet<T> {
 				project_to_polimec: ChannelStatus::Closed,
 				polimec_to_project: ChannelStatus::Closed,
 			},
+			migration_type: MigrationType::Pallet,
+			receiver_call_indices: None,
 		};
 
 		let bucket: BucketOf<T> = Self::create_bucket_from_metadata(&project_metadata)?;
@@ -93,6 +117,10 @@ impl<T: Config> Pallet<T> {
 		Buckets::<T>::insert(project_id, bucket);
 		NextProjectId::<T>::mutate(|n| n.saturating_inc());
 		DidWithActiveProjects::<T>::set(did, Some(project_id));
+		if let Some(evaluation_start) = project_metadata.scheduled_starts.evaluation {
+			Self::add_to_update_store(evaluation_start, (&project_id, UpdateType::EvaluationStart))
+				.map_err(|_| Error::<T>::TooManyInsertionAttempts)?;
+		}
 
 		// * Emit events *
 		Self::deposit_event(Event::ProjectCreated { project_id, issuer: issuer.clone(), metadata: project_metadata });
@@ -107,6 +135,7 @@ impl<T: Config> Pallet<T> {
 		new_project_metadata: ProjectMetadataOf<T>,
 	) -> DispatchResult {
 		// * Get variables *
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
 		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
 
 		// * Validity checks *
@@ -121,6 +150,14 @@ impl<T: Config> Pallet<T> {
 		ProjectsMetadata::<T>::insert(project_id, new_project_metadata.clone());
 		ProjectsDetails::<T>::insert(project_id, project_details);
 		Buckets::<T>::insert(project_id, bucket);
+		// A previously scheduled start that no longer matches the metadata is skipped by `do_start_evaluation`.
+		let new_evaluation_start = new_project_metadata.scheduled_starts.evaluation;
+		if let Some(evaluation_start) =
+			new_evaluation_start.filter(|_| new_evaluation_start != project_metadata.scheduled_starts.evaluation)
+		{
+			Self::add_to_update_store(evaluation_start, (&project_id, UpdateType::EvaluationStart))
+				.map_err(|_| Error::<T>::TooManyInsertionAttempts)?;
+		}
 
 		// * Emit events *
 		Self::deposit_event(Event::MetadataEdited { project_id, metadata: new_project_metadata });
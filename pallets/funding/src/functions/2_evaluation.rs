@@ -1,7 +1,7 @@
 use super::*;
 
 impl<T: Config> Pallet<T> {
-	/// Called by user extrinsic
+	/// Called by user extrinsic, or automatically by on_initialize if the issuer scheduled the start.
 	/// Starts the evaluation round of a project. It needs to be called by the project issuer.
 	///
 	/// # Arguments
@@ -26,13 +26,21 @@ impl<T: Config> Pallet<T> {
 		let now = <frame_system::Pallet<T>>::block_number();
 
 		// * Validity checks *
-		ensure!(project_details.issuer_account == caller, Error::<T>::NotIssuer);
+		if caller == T::PalletId::get().into_account_truncating() {
+			// Automatic start scheduled by the issuer. An outdated schedule that was later moved or removed
+			// with `edit_project` fails here.
+			let scheduled_start = project_metadata.scheduled_starts.evaluation;
+			ensure!(scheduled_start.is_some_and(|block| now >= block), Error::<T>::TooEarlyForRound);
+		} else {
+			ensure!(project_details.issuer_account == caller, Error::<T>::NotIssuer);
+		}
 		ensure!(project_details.status == ProjectStatus::Application, Error::<T>::IncorrectRound);
 		ensure!(!project_details.is_frozen, Error::<T>::ProjectAlreadyFrozen);
 		ensure!(project_metadata.policy_ipfs_cid.is_some(), Error::<T>::CidNotProvided);
 
 		// * Calculate new variables *
-		let evaluation_end_block = now.saturating_add(T::EvaluationDuration::get()).saturating_sub(One::one());
+		let evaluation_duration = Self::project_phase_durations(&project_metadata).evaluation;
+		let evaluation_end_block = now.saturating_add(evaluation_duration).saturating_sub(One::one());
 		project_details.phase_transition_points.application.update(None, Some(now));
 		project_details.phase_transition_points.evaluation.update(Some(now), Some(evaluation_end_block));
 		project_details.is_frozen = true;
@@ -95,6 +103,7 @@ impl<T: Config> Pallet<T> {
 	#[transactional]
 	pub fn do_evaluation_end(project_id: ProjectId) -> DispatchResultWithPostInfo {
 		// * Get variables *
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
 		let mut project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
 		let now = <frame_system::Pallet<T>>::block_number();
 		let evaluation_end_block =
@@ -110,9 +119,22 @@ impl<T: Config> Pallet<T> {
 		let evaluation_target_usd = <T as Config>::EvaluationSuccessThreshold::get() * fundraising_target_usd;
 
 		let auction_initialize_period_start_block = now;
-		let auction_initialize_period_end_block = auction_initialize_period_start_block
+		let default_auction_initialize_period_end_block = auction_initialize_period_start_block
 			.saturating_add(T::AuctionInitializePeriodDuration::get())
 			.saturating_sub(One::one());
+		// A schedule made for a later evaluation keeps the project waiting for it if the issuer started the
+		// evaluation earlier by hand.
+		let auction_initialize_period_end_block = match project_metadata.scheduled_starts.auction {
+			Some(block) => default_auction_initialize_period_end_block.max(block.saturating_sub(One::one())),
+			None => default_auction_initialize_period_end_block,
+		};
+		// If the issuer scheduled the auction, it starts then instead of at the end of the initialize period.
+		// A schedule that was already missed starts the auction on the next block.
+		let auction_opening_start_block = Self::scheduled_auction_opening_block(
+			project_metadata.scheduled_starts.auction,
+			now,
+			auction_initialize_period_end_block,
+		);
 
 		// Check which logic path to follow
 		let is_funded = usd_total_amount_bonded >= evaluation_target_usd;
@@ -128,7 +150,7 @@ impl<T: Config> Pallet<T> {
 			project_details.status = ProjectStatus::AuctionInitializePeriod;
 			ProjectsDetails::<T>::insert(project_id, project_details);
 			let insertion_attempts = match Self::add_to_update_store(
-				auction_initialize_period_end_block + 1u32.into(),
+				auction_opening_start_block,
 				(&project_id, UpdateType::AuctionOpeningStart),
 			) {
 				Ok(insertions) => insertions,
@@ -183,8 +205,9 @@ impl<T: Config> Pallet<T> {
 		let mut project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
 		let now = <frame_system::Pallet<T>>::block_number();
 		let evaluation_id = NextEvaluationId::<T>::get();
-		let plmc_usd_price = T::PriceProvider::get_decimals_aware_price(PLMC_FOREIGN_ID, USD_DECIMALS, PLMC_DECIMALS)
-			.ok_or(Error::<T>::PriceNotFound)?;
+		let plmc_usd_price =
+			T::BondingPriceProvider::get_decimals_aware_price(PLMC_FOREIGN_ID, USD_DECIMALS, PLMC_DECIMALS)
+				.ok_or(Error::<T>::PriceNotFound)?;
 		let early_evaluation_reward_threshold_usd =
 			T::EvaluationSuccessThreshold::get() * project_details.fundraising_target_usd;
 		let evaluation_round_info = &mut project_details.evaluation_round_info;
@@ -193,6 +216,7 @@ impl<T: Config> Pallet<T> {
 		let project_policy = project_metadata.policy_ipfs_cid.ok_or(Error::<T>::ImpossibleState)?;
 
 		// * Validity Checks *
+		Self::ensure_price_feeds_active(AcceptedFundingAsset::PLMC)?;
 		ensure!(project_policy == whitelisted_policy, Error::<T>::PolicyMismatch);
 		ensure!(usd_amount >= T::MinUsdPerEvaluation::get(), Error::<T>::TooLow);
 		ensure!(project_details.issuer_did != did, Error::<T>::ParticipationToOwnProject);
@@ -237,6 +261,7 @@ impl<T: Config> Pallet<T> {
 			early_usd_amount,
 			late_usd_amount,
 			when: now,
+			plmc_usd_price,
 		};
 
 		T::NativeCurrency::hold(&HoldReason::Evaluation(project_id).into(), evaluator, plmc_bond)?;
@@ -1,6 +1,74 @@
 use super::*;
 
 impl<T: Config> Pallet<T> {
+	/// Called by user extrinsic
+	/// Sets, moves or removes the block at which the auction of a project starts automatically. Can be called
+	/// by the issuer until the auction starts, which also covers the frozen evaluation and initialize periods.
+	///
+	/// # Storage access
+	/// * [`ProjectsMetadata`] - Updating the scheduled auction start.
+	/// * [`ProjectsToUpdate`] - Scheduling the new start if the project is already waiting for its auction.
+	#[transactional]
+	pub fn do_schedule_auction_start(
+		caller: AccountIdOf<T>,
+		project_id: ProjectId,
+		auction_start: Option<BlockNumberFor<T>>,
+	) -> DispatchResult {
+		// * Get variables *
+		let mut project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let now = <frame_system::Pallet<T>>::block_number();
+		let transition_points = &project_details.phase_transition_points;
+
+		// * Validity checks *
+		ensure!(caller == project_details.issuer_account, Error::<T>::NotIssuer);
+		// The auction must start within the initialize period, or right after it as it would without a schedule.
+		let initialize_period_duration = T::AuctionInitializePeriodDuration::get();
+		let (earliest_auction_start, latest_auction_start) = match project_details.status {
+			ProjectStatus::Application => {
+				let evaluation_start = project_metadata.scheduled_starts.evaluation.unwrap_or(now);
+				let evaluation_duration = Self::project_phase_durations(&project_metadata).evaluation;
+				let earliest_auction_start = evaluation_start.saturating_add(evaluation_duration);
+				(earliest_auction_start, earliest_auction_start.saturating_add(initialize_period_duration))
+			},
+			ProjectStatus::EvaluationRound => {
+				let evaluation_end = transition_points.evaluation.end().ok_or(Error::<T>::TransitionPointNotSet)?;
+				let earliest_auction_start = evaluation_end.saturating_add(One::one());
+				(earliest_auction_start, earliest_auction_start.saturating_add(initialize_period_duration))
+			},
+			ProjectStatus::AuctionInitializePeriod => {
+				let auction_initialize_period_end_block =
+					transition_points.auction_initialize_period.end().ok_or(Error::<T>::TransitionPointNotSet)?;
+				(now, auction_initialize_period_end_block.saturating_add(One::one()))
+			},
+			_ => return Err(Error::<T>::IncorrectRound.into()),
+		};
+		if let Some(block) = auction_start {
+			ensure!(
+				block > earliest_auction_start && block <= latest_auction_start,
+				Error::<T>::InvalidPhaseSchedule
+			);
+		}
+
+		// * Update storage *
+		project_metadata.scheduled_starts.auction = auction_start;
+		ProjectsMetadata::<T>::insert(project_id, project_metadata);
+		// Otherwise, the start is scheduled by `do_evaluation_end`.
+		if project_details.status == ProjectStatus::AuctionInitializePeriod {
+			let auction_initialize_period_end_block =
+				transition_points.auction_initialize_period.end().ok_or(Error::<T>::TransitionPointNotSet)?;
+			let auction_opening_start_block =
+				Self::scheduled_auction_opening_block(auction_start, now, auction_initialize_period_end_block);
+			Self::add_to_update_store(auction_opening_start_block, (&project_id, UpdateType::AuctionOpeningStart))
+				.map_err(|_| Error::<T>::TooManyInsertionAttempts)?;
+		}
+
+		// * Emit events *
+		Self::deposit_event(Event::AuctionStartScheduled { project_id, block: auction_start });
+
+		Ok(())
+	}
+
 	/// Called by user extrinsic
 	/// Starts the auction round for a project. From the next block forward, any professional or
 	/// institutional user can set bids for a token_amount/token_price pair.
@@ -26,6 +94,7 @@ impl<T: Config> Pallet<T> {
 	#[transactional]
 	pub fn do_start_auction_opening(caller: AccountIdOf<T>, project_id: ProjectId) -> DispatchResultWithPostInfo {
 		// * Get variables *
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
 		let mut project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
 		let now = <frame_system::Pallet<T>>::block_number();
 
@@ -36,10 +105,22 @@ impl<T: Config> Pallet<T> {
 			.ok_or(Error::<T>::TransitionPointNotSet)?;
 
 		// * Validity checks *
-		ensure!(
-			caller == T::PalletId::get().into_account_truncating() || caller == project_details.issuer_account,
-			Error::<T>::NotIssuer
-		);
+		if caller == T::PalletId::get().into_account_truncating() {
+			// Automatic transitions made outdated by a rescheduling of the auction fail here.
+			let auction_initialize_period_end_block = project_details
+				.phase_transition_points
+				.auction_initialize_period
+				.end()
+				.ok_or(Error::<T>::TransitionPointNotSet)?;
+			let automatic_start_block = Self::scheduled_auction_opening_block(
+				project_metadata.scheduled_starts.auction,
+				auction_initialize_period_start_block,
+				auction_initialize_period_end_block,
+			);
+			ensure!(now >= automatic_start_block, Error::<T>::TooEarlyForRound);
+		} else {
+			ensure!(caller == project_details.issuer_account, Error::<T>::NotIssuer);
+		}
 
 		ensure!(now >= auction_initialize_period_start_block, Error::<T>::TooEarlyForRound);
 		// If the auction is first manually started, the automatic transition fails here. This
@@ -49,7 +130,8 @@ impl<T: Config> Pallet<T> {
 
 		// * Calculate new variables *
 		let opening_start_block = now;
-		let opening_end_block = now.saturating_add(T::AuctionOpeningDuration::get()).saturating_sub(One::one());
+		let opening_duration = Self::project_phase_durations(&project_metadata).auction_opening;
+		let opening_end_block = now.saturating_add(opening_duration).saturating_sub(One::one());
 
 		// * Update Storage *
 		project_details
@@ -111,6 +193,7 @@ impl<T: Config> Pallet<T> {
 	#[transactional]
 	pub fn do_start_auction_closing(project_id: ProjectId) -> DispatchResultWithPostInfo {
 		// * Get variables *
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
 		let mut project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
 		let now = <frame_system::Pallet<T>>::block_number();
 		let opening_end_block =
@@ -122,7 +205,8 @@ impl<T: Config> Pallet<T> {
 
 		// * Calculate new variables *
 		let closing_start_block = now;
-		let closing_end_block = now.saturating_add(T::AuctionClosingDuration::get()).saturating_sub(One::one());
+		let closing_duration = Self::project_phase_durations(&project_metadata).auction_closing;
+		let closing_end_block = now.saturating_add(closing_duration).saturating_sub(One::one());
 
 		// * Update Storage *
 		project_details
@@ -239,8 +323,9 @@ impl<T: Config> Pallet<T> {
 		// * Get variables *
 		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
 		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
-		let plmc_usd_price = T::PriceProvider::get_decimals_aware_price(PLMC_FOREIGN_ID, USD_DECIMALS, PLMC_DECIMALS)
-			.ok_or(Error::<T>::PriceNotFound)?;
+		let plmc_usd_price =
+			T::BondingPriceProvider::get_decimals_aware_price(PLMC_FOREIGN_ID, USD_DECIMALS, PLMC_DECIMALS)
+				.ok_or(Error::<T>::PriceNotFound)?;
 
 		// Fetch current bucket details and other required info
 		let mut current_bucket = Buckets::<T>::get(project_id).ok_or(Error::<T>::BucketNotFound)?;
@@ -271,6 +356,7 @@ impl<T: Config> Pallet<T> {
 		};
 
 		// * Validity checks *
+		Self::ensure_price_feeds_active(funding_asset)?;
 		ensure!(project_policy == whitelisted_policy, Error::<T>::PolicyMismatch);
 		ensure!(
 			matches!(investor_type, InvestorType::Institutional | InvestorType::Professional),
@@ -371,11 +457,7 @@ impl<T: Config> Pallet<T> {
 		ensure!(total_bids_by_bidder < T::MaxBidsPerUser::get(), Error::<T>::TooManyUserParticipations);
 		ensure!(total_bids_for_project < T::MaxBidsPerProject::get(), Error::<T>::TooManyProjectParticipations);
 
-		let funding_asset_id = funding_asset.to_assethub_id();
-		let funding_asset_decimals = T::FundingCurrency::decimals(funding_asset_id);
-		let funding_asset_usd_price =
-			T::PriceProvider::get_decimals_aware_price(funding_asset_id, USD_DECIMALS, funding_asset_decimals)
-				.ok_or(Error::<T>::PriceNotFound)?;
+		let funding_asset_usd_price = Self::get_funding_asset_price(funding_asset)?;
 
 		// * Calculate new variables *
 		let plmc_bond =
@@ -383,7 +465,6 @@ impl<T: Config> Pallet<T> {
 
 		let funding_asset_amount_locked =
 			funding_asset_usd_price.reciprocal().ok_or(Error::<T>::BadMath)?.saturating_mul_int(ticket_size);
-		let asset_id = funding_asset.to_assethub_id();
 
 		let new_bid = BidInfoOf::<T> {
 			id: bid_id,
@@ -400,10 +481,12 @@ impl<T: Config> Pallet<T> {
 			multiplier,
 			plmc_bond,
 			when: now,
+			plmc_usd_price,
 		};
 
-		Self::try_plmc_participation_lock(bidder, project_id, plmc_bond)?;
-		Self::try_funding_asset_hold(bidder, project_id, funding_asset_amount_locked, asset_id)?;
+		// The bond is held first, so if PLMC is also the funding asset, the payment can only come from the free balance.
+		Self::try_plmc_participation_lock(bidder, project_id, plmc_bond, multiplier)?;
+		Self::try_funding_asset_hold(bidder, project_id, funding_asset_amount_locked, funding_asset)?;
 
 		Bids::<T>::insert((project_id, bidder, bid_id), &new_bid);
 		NextBidId::<T>::set(bid_id.saturating_add(One::one()));
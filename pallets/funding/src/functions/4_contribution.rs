@@ -26,6 +26,7 @@ impl<T: Config> Pallet<T> {
 	#[transactional]
 	pub fn do_start_community_funding(project_id: ProjectId) -> DispatchResultWithPostInfo {
 		// * Get variables *
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
 		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
 		let now = <frame_system::Pallet<T>>::block_number();
 		let auction_closing_end_block =
@@ -37,7 +38,8 @@ impl<T: Config> Pallet<T> {
 
 		// * Calculate new variables *
 		let community_start_block = now;
-		let community_end_block = now.saturating_add(T::CommunityFundingDuration::get()).saturating_sub(One::one());
+		let community_duration = Self::project_phase_durations(&project_metadata).community_funding;
+		let community_end_block = now.saturating_add(community_duration).saturating_sub(One::one());
 
 		// * Update Storage *
 		let wap_result = Self::calculate_weighted_average_price(project_id);
@@ -52,12 +54,15 @@ impl<T: Config> Pallet<T> {
 					.community
 					.update(Some(community_start_block), Some(community_end_block));
 				project_details.status = ProjectStatus::CommunityRound;
+				// The bids alone can already reach the hard cap, in which case the funding ends right away.
+				let (update_block, update_type) = if Self::is_hard_cap_reached(&project_metadata, &project_details) {
+					(now + 1u32.into(), UpdateType::FundingEnd)
+				} else {
+					(community_end_block + 1u32.into(), UpdateType::RemainderFundingStart)
+				};
 				ProjectsDetails::<T>::insert(project_id, project_details);
 
-				let insertion_iterations = match Self::add_to_update_store(
-					community_end_block + 1u32.into(),
-					(&project_id, UpdateType::RemainderFundingStart),
-				) {
+				let insertion_iterations = match Self::add_to_update_store(update_block, (&project_id, update_type)) {
 					Ok(iterations) => iterations,
 					Err(_iterations) => return Err(Error::<T>::TooManyInsertionAttempts.into()),
 				};
@@ -106,6 +111,7 @@ impl<T: Config> Pallet<T> {
 	#[transactional]
 	pub fn do_start_remainder_funding(project_id: ProjectId) -> DispatchResultWithPostInfo {
 		// * Get variables *
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
 		let mut project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
 		let now = <frame_system::Pallet<T>>::block_number();
 		let community_end_block =
@@ -124,7 +130,8 @@ impl<T: Config> Pallet<T> {
 
 		// * Calculate new variables *
 		let remainder_start_block = now;
-		let remainder_end_block = now.saturating_add(T::RemainderFundingDuration::get()).saturating_sub(One::one());
+		let remainder_duration = Self::project_phase_durations(&project_metadata).remainder_funding;
+		let remainder_end_block = now.saturating_add(remainder_duration).saturating_sub(One::one());
 
 		// * Update Storage *
 		project_details
@@ -169,13 +176,14 @@ impl<T: Config> Pallet<T> {
 		investor_type: InvestorType,
 		whitelisted_policy: Cid,
 	) -> DispatchResultWithPostInfo {
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
 		let mut project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
 		let did_has_winning_bid = DidWithWinningBids::<T>::get(project_id, did.clone());
 
 		ensure!(project_details.status == ProjectStatus::CommunityRound, Error::<T>::IncorrectRound);
 		ensure!(!did_has_winning_bid, Error::<T>::UserHasWinningBid);
 
-		let buyable_tokens = token_amount.min(project_details.remaining_contribution_tokens);
+		let buyable_tokens = token_amount.min(Self::buyable_contribution_tokens(&project_metadata, &project_details)?);
 		project_details.remaining_contribution_tokens.saturating_reduce(buyable_tokens);
 
 		Self::do_contribute(
@@ -211,10 +219,11 @@ impl<T: Config> Pallet<T> {
 		investor_type: InvestorType,
 		whitelisted_policy: Cid,
 	) -> DispatchResultWithPostInfo {
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
 		let mut project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
 
 		ensure!(project_details.status == ProjectStatus::RemainderRound, Error::<T>::IncorrectRound);
-		let buyable_tokens = token_amount.min(project_details.remaining_contribution_tokens);
+		let buyable_tokens = token_amount.min(Self::buyable_contribution_tokens(&project_metadata, &project_details)?);
 
 		let before = project_details.remaining_contribution_tokens;
 		let remaining_cts_in_round = before.saturating_sub(buyable_tokens);
@@ -224,7 +233,7 @@ impl<T: Config> Pallet<T> {
 			contributor,
 			project_id,
 			&mut project_details,
-			token_amount,
+			buyable_tokens,
 			multiplier,
 			asset,
 			investor_type,
@@ -251,16 +260,13 @@ impl<T: Config> Pallet<T> {
 		let total_usd_bought_by_did = ContributionBoughtUSD::<T>::get((project_id, did.clone()));
 		let now = <frame_system::Pallet<T>>::block_number();
 		let ct_usd_price = project_details.weighted_average_price.ok_or(Error::<T>::WapNotSet)?;
-		let plmc_usd_price = T::PriceProvider::get_decimals_aware_price(PLMC_FOREIGN_ID, USD_DECIMALS, PLMC_DECIMALS)
-			.ok_or(Error::<T>::PriceNotFound)?;
-
-		let funding_asset_id = funding_asset.to_assethub_id();
-		let funding_asset_decimals = T::FundingCurrency::decimals(funding_asset_id);
-		let funding_asset_usd_price =
-			T::PriceProvider::get_decimals_aware_price(funding_asset_id, USD_DECIMALS, funding_asset_decimals)
+		let plmc_usd_price =
+			T::BondingPriceProvider::get_decimals_aware_price(PLMC_FOREIGN_ID, USD_DECIMALS, PLMC_DECIMALS)
 				.ok_or(Error::<T>::PriceNotFound)?;
 
-		let project_policy = project_metadata.policy_ipfs_cid.ok_or(Error::<T>::ImpossibleState)?;
+		let funding_asset_usd_price = Self::get_funding_asset_price(funding_asset)?;
+
+		let project_policy = project_metadata.policy_ipfs_cid.clone().ok_or(Error::<T>::ImpossibleState)?;
 
 		let ticket_size = ct_usd_price.checked_mul_int(buyable_tokens).ok_or(Error::<T>::BadMath)?;
 		let contributor_ticket_size = match investor_type {
@@ -283,6 +289,7 @@ impl<T: Config> Pallet<T> {
 			InvestorType::Institutional => INSTITUTIONAL_MAX_MULTIPLIER,
 		};
 		// * Validity checks *
+		Self::ensure_price_feeds_active(funding_asset)?;
 		ensure!(project_policy == whitelisted_policy, Error::<T>::PolicyMismatch);
 		ensure!(multiplier.into() <= max_multiplier && multiplier.into() > 0u8, Error::<T>::ForbiddenMultiplier);
 		ensure!(
@@ -303,7 +310,6 @@ impl<T: Config> Pallet<T> {
 		let plmc_bond = Self::calculate_plmc_bond(ticket_size, multiplier, plmc_usd_price)?;
 		let funding_asset_amount =
 			funding_asset_usd_price.reciprocal().ok_or(Error::<T>::BadMath)?.saturating_mul_int(ticket_size);
-		let asset_id = funding_asset.to_assethub_id();
 
 		let contribution_id = NextContributionId::<T>::get();
 		let new_contribution = ContributionInfoOf::<T> {
@@ -317,11 +323,13 @@ impl<T: Config> Pallet<T> {
 			funding_asset,
 			funding_asset_amount,
 			plmc_bond,
+			plmc_usd_price,
 		};
 
 		// Try adding the new contribution to the system
-		Self::try_plmc_participation_lock(contributor, project_id, plmc_bond)?;
-		Self::try_funding_asset_hold(contributor, project_id, funding_asset_amount, asset_id)?;
+		// The bond is held first, so if PLMC is also the funding asset, the payment can only come from the free balance.
+		Self::try_plmc_participation_lock(contributor, project_id, plmc_bond, multiplier)?;
+		Self::try_funding_asset_hold(contributor, project_id, funding_asset_amount, funding_asset)?;
 
 		Contributions::<T>::insert((project_id, contributor, contribution_id), &new_contribution);
 		NextContributionId::<T>::set(contribution_id.saturating_add(One::one()));
@@ -329,11 +337,12 @@ impl<T: Config> Pallet<T> {
 
 		let remaining_cts_after_purchase = project_details.remaining_contribution_tokens;
 		project_details.funding_amount_reached_usd.saturating_accrue(new_contribution.usd_contribution_amount);
+		let hard_cap_reached = Self::is_hard_cap_reached(&project_metadata, project_details);
 		ProjectsDetails::<T>::insert(project_id, project_details);
-		// If no CTs remain, end the funding phase
+		// If no CTs remain, or the hard cap was reached, end the funding phase
 
 		let mut weight_round_end_flag: Option<u32> = None;
-		if remaining_cts_after_purchase.is_zero() {
+		if remaining_cts_after_purchase.is_zero() || hard_cap_reached {
 			let fully_filled_vecs_from_insertion =
 				match Self::add_to_update_store(now + 1u32.into(), (&project_id, UpdateType::FundingEnd)) {
 					Ok(iterations) => iterations,
@@ -15,10 +15,11 @@ impl<T: Config> Pallet<T> {
 	/// # Success Path
 	/// The validity checks pass, and either of 2 paths happen:
 	///
-	/// * Project achieves its funding target - the project info is set to a successful funding state,
+	/// * Project achieves its soft cap - the project info is set to a successful funding state,
 	/// and the contribution token asset class is created with the same id as the project.
 	///
-	/// * Project doesn't achieve its funding target - the project info is set to an unsuccessful funding state.
+	/// * Project raised less than its soft cap - depending on how far it is from it, the project info is set
+	/// to an unsuccessful funding state, or the issuer has to decide on the outcome.
 	///
 	/// # Next step
 	/// If **successful**, bidders can claim:
@@ -39,6 +40,7 @@ impl<T: Config> Pallet<T> {
 		let remainder_end_block = project_details.phase_transition_points.remainder.end();
 		let now = <frame_system::Pallet<T>>::block_number();
 		let issuer_did = project_details.issuer_did.clone();
+		let phase_durations = Self::project_phase_durations(&project_metadata);
 
 		// * Validity checks *
 		ensure!(
@@ -47,7 +49,9 @@ impl<T: Config> Pallet<T> {
 				// or the auction being empty
 				project_details.status == ProjectStatus::AuctionClosing ||
 				// or the last funding round ending
-				matches!(remainder_end_block, Some(end_block) if now > end_block),
+				matches!(remainder_end_block, Some(end_block) if now > end_block) ||
+				// or the hard cap being reached
+				Self::is_hard_cap_reached(&project_metadata, &project_details),
 			Error::<T>::TooEarlyForRound
 		);
 		// do_end_funding was already executed, but automatic transition was included in the
@@ -69,10 +73,12 @@ impl<T: Config> Pallet<T> {
 			.ok_or(Error::<T>::BadMath)?;
 		let funding_reached = project_details.funding_amount_reached_usd;
 		let funding_ratio = Perquintill::from_rational(funding_reached, funding_target);
+		let (failure_threshold, slash_threshold, success_threshold) =
+			Self::funding_outcome_thresholds(&project_metadata, funding_target);
 
 		// * Update Storage *
 		DidWithActiveProjects::<T>::set(issuer_did, None);
-		if funding_ratio <= Perquintill::from_percent(33u64) {
+		if funding_ratio <= failure_threshold {
 			project_details.evaluation_round_info.evaluators_outcome = EvaluatorsOutcome::Slashed;
 			let insertion_iterations =
 				Self::finalize_funding(project_id, project_details, ProjectOutcome::FundingFailed, 1u32.into())?;
@@ -82,11 +88,11 @@ impl<T: Config> Pallet<T> {
 				)),
 				pays_fee: Pays::Yes,
 			});
-		} else if funding_ratio <= Perquintill::from_percent(75u64) {
+		} else if funding_ratio <= slash_threshold {
 			project_details.evaluation_round_info.evaluators_outcome = EvaluatorsOutcome::Slashed;
 			project_details.status = ProjectStatus::AwaitingProjectDecision;
 			let insertion_iterations = match Self::add_to_update_store(
-				now + T::ManualAcceptanceDuration::get() + 1u32.into(),
+				now + phase_durations.manual_acceptance + 1u32.into(),
 				(&project_id, UpdateType::ProjectDecision(FundingOutcomeDecision::AcceptFunding)),
 			) {
 				Ok(iterations) => iterations,
@@ -99,11 +105,11 @@ impl<T: Config> Pallet<T> {
 				)),
 				pays_fee: Pays::Yes,
 			})
-		} else if funding_ratio < Perquintill::from_percent(90u64) {
+		} else if funding_ratio < success_threshold {
 			project_details.evaluation_round_info.evaluators_outcome = EvaluatorsOutcome::Unchanged;
 			project_details.status = ProjectStatus::AwaitingProjectDecision;
 			let insertion_iterations = match Self::add_to_update_store(
-				now + T::ManualAcceptanceDuration::get() + 1u32.into(),
+				now + phase_durations.manual_acceptance + 1u32.into(),
 				(&project_id, UpdateType::ProjectDecision(FundingOutcomeDecision::AcceptFunding)),
 			) {
 				Ok(iterations) => iterations,
@@ -124,7 +130,7 @@ impl<T: Config> Pallet<T> {
 				project_id,
 				project_details,
 				ProjectOutcome::FundingSuccessful,
-				T::SuccessToSettlementTime::get(),
+				phase_durations.success_to_settlement,
 			)?;
 			return Ok(PostDispatchInfo {
 				actual_weight: Some(WeightInfoOf::<T>::end_funding_automatically_accepted_evaluators_rewarded(
@@ -140,6 +146,7 @@ impl<T: Config> Pallet<T> {
 	pub fn do_project_decision(project_id: ProjectId, decision: FundingOutcomeDecision) -> DispatchResultWithPostInfo {
 		// * Get variables *
 		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
 		ensure!(
 			project_details.status == ProjectStatus::AwaitingProjectDecision,
 			Error::<T>::RoundTransitionAlreadyHappened
@@ -150,7 +157,8 @@ impl<T: Config> Pallet<T> {
 		};
 
 		// * Update storage *
-		Self::finalize_funding(project_id, project_details, outcome, T::SuccessToSettlementTime::get())?;
+		let settlement_delta = Self::project_phase_durations(&project_metadata).success_to_settlement;
+		Self::finalize_funding(project_id, project_details, outcome, settlement_delta)?;
 		Ok(PostDispatchInfo { actual_weight: Some(WeightInfoOf::<T>::project_decision()), pays_fee: Pays::Yes })
 	}
 
@@ -72,6 +72,10 @@ impl<T: Config> Pallet<T> {
 				&contribution_token_treasury_account,
 				liquidity_pools_ct_amount,
 			)?;
+			MintedContributionTokens::<T>::mutate(project_id, |minted| {
+				*minted =
+					minted.saturating_add(long_term_holder_bonus_ct_amount).saturating_add(liquidity_pools_ct_amount)
+			});
 
 			Ok(PostDispatchInfo {
 				actual_weight: Some(WeightInfoOf::<T>::start_settlement_funding_success()),
@@ -122,6 +126,7 @@ impl<T: Config> Pallet<T> {
 			)?;
 		}
 		Evaluations::<T>::remove((project_id, evaluation.evaluator.clone(), evaluation.id));
+		Self::add_pending_migrations_if_settled(project_id, &evaluation.evaluator);
 
 		Self::deposit_event(Event::EvaluationSettled {
 			project_id,
@@ -194,7 +199,7 @@ impl<T: Config> Pallet<T> {
 			)?;
 		} else {
 			// Release the held PLMC bond
-			Self::release_participation_bond(project_id, &bidder, bid.plmc_bond)?;
+			Self::release_participation_bond(project_id, &bidder, bid.plmc_bond, bid.multiplier)?;
 		}
 
 		// Mint the contribution tokens
@@ -218,6 +223,7 @@ impl<T: Config> Pallet<T> {
 		)?;
 
 		Bids::<T>::remove((project_id, bidder.clone(), bid.id));
+		Self::add_pending_migrations_if_settled(project_id, &bidder);
 
 		Self::deposit_event(Event::BidSettled {
 			project_id,
@@ -239,7 +245,7 @@ impl<T: Config> Pallet<T> {
 		Self::release_funding_asset(project_id, &bidder, bid.funding_asset_amount_locked, bid.funding_asset)?;
 
 		// Release the held PLMC bond
-		Self::release_participation_bond(project_id, &bidder, bid.plmc_bond)?;
+		Self::release_participation_bond(project_id, &bidder, bid.plmc_bond, bid.multiplier)?;
 
 		// Remove the bid from the storage
 		Bids::<T>::remove((project_id, bidder.clone(), bid.id));
@@ -278,7 +284,12 @@ impl<T: Config> Pallet<T> {
 			)?;
 		} else {
 			// Release the held PLMC bond
-			Self::release_participation_bond(project_id, &contributor, contribution.plmc_bond)?;
+			Self::release_participation_bond(
+				project_id,
+				&contributor,
+				contribution.plmc_bond,
+				contribution.multiplier,
+			)?;
 		}
 
 		// Mint the contribution tokens
@@ -303,6 +314,7 @@ impl<T: Config> Pallet<T> {
 		)?;
 
 		Contributions::<T>::remove((project_id, contributor.clone(), contribution.id));
+		Self::add_pending_migrations_if_settled(project_id, &contributor);
 
 		Self::deposit_event(Event::ContributionSettled {
 			project_id,
@@ -330,7 +342,7 @@ impl<T: Config> Pallet<T> {
 		)?;
 
 		// Release the held PLMC bond
-		Self::release_participation_bond(project_id, &contributor, contribution.plmc_bond)?;
+		Self::release_participation_bond(project_id, &contributor, contribution.plmc_bond, contribution.multiplier)?;
 
 		// Remove the bid from the storage
 		Contributions::<T>::remove((project_id, contributor.clone(), contribution.id));
@@ -354,6 +366,7 @@ impl<T: Config> Pallet<T> {
 			T::ContributionTokenCurrency::touch(project_id, participant, participant)?;
 		}
 		T::ContributionTokenCurrency::mint_into(project_id, participant, amount)?;
+		MintedContributionTokens::<T>::mutate(project_id, |minted| *minted = minted.saturating_add(amount));
 		Ok(())
 	}
 
@@ -364,26 +377,36 @@ impl<T: Config> Pallet<T> {
 		asset: AcceptedFundingAsset,
 	) -> DispatchResult {
 		let project_pot = Self::fund_account_id(project_id);
-		T::FundingCurrency::transfer(
-			asset.to_assethub_id(),
-			&project_pot,
-			&participant,
-			amount,
-			Preservation::Expendable,
-		)?;
+		// The project pot was created with the issuer's ED, so a PLMC payout never reaps it.
+		Self::transfer_funding_asset(asset, &project_pot, &participant, amount, Preservation::Expendable)?;
 		Ok(())
 	}
 
-	fn release_participation_bond(
+	/// Release `amount` of a participation bond of `participant` in a project. The part backed by stake is
+	/// released first, so it can be unbonded again as soon as possible. Only bonds with a multiplier of 1 can be
+	/// backed by stake, so bonds with a higher one are released from the held balance only.
+	pub(crate) fn release_participation_bond(
 		project_id: ProjectId,
 		participant: &AccountIdOf<T>,
 		amount: BalanceOf<T>,
+		multiplier: MultiplierOf<T>,
 	) -> DispatchResult {
+		let staked_bond = StakedParticipationBonds::<T>::get(participant, project_id);
+		let from_stake = if multiplier.into() == 1u8 { amount.min(staked_bond) } else { Zero::zero() };
+		if !from_stake.is_zero() {
+			T::StakedPlmc::release_stake(participant, from_stake);
+			if from_stake == staked_bond {
+				StakedParticipationBonds::<T>::remove(participant, project_id);
+			} else {
+				StakedParticipationBonds::<T>::insert(participant, project_id, staked_bond.saturating_sub(from_stake));
+			}
+		}
+
 		// Release the held PLMC bond
 		T::NativeCurrency::release(
 			&HoldReason::Participation(project_id).into(),
 			&participant,
-			amount,
+			amount.saturating_sub(from_stake),
 			Precision::Exact,
 		)?;
 		Ok(())
@@ -445,7 +468,10 @@ impl<T: Config> Pallet<T> {
 			let migration_origin =
 				MigrationOrigin { user: T::AccountId32Conversion::convert(origin.clone()), id, participation_type };
 			let vesting_time: u64 = vesting_time.try_into().map_err(|_| Error::<T>::BadMath)?;
-			let migration_info: MigrationInfo = (ct_amount.into(), vesting_time.into()).into();
+			let DestinationVesting { start_block, cliff } =
+				DestinationVestings::<T>::get(project_id).unwrap_or_default();
+			let migration_info =
+				MigrationInfo { contribution_token_amount: ct_amount.into(), vesting_time, start_block, cliff };
 			let migration = Migration::new(migration_origin, migration_info);
 			if let Some((_, migrations)) = maybe_migrations {
 				migrations.try_push(migration).map_err(|_| Error::<T>::TooManyMigrations)?;
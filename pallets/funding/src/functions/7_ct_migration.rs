@@ -23,14 +23,51 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
-	pub fn do_handle_channel_open_request(message: Instruction) -> XcmResult {
-		// TODO: set these constants with a proper value
-		const EXECUTION_DOT: MultiAsset = MultiAsset {
-			id: Concrete(MultiLocation { parents: 0, interior: Here }),
-			fun: Fungible(1_0_000_000_000u128),
-		};
-		const MAX_WEIGHT: Weight = Weight::from_parts(20_000_000_000, 1_000_000);
+	#[transactional]
+	pub fn do_set_migration_execution_fee(
+		caller: &AccountIdOf<T>,
+		project_id: ProjectId,
+		fee: Option<u128>,
+	) -> DispatchResult {
+		// * Get variables *
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+
+		// * Validity checks *
+		ensure!(&project_details.issuer_account == caller, Error::<T>::NotIssuer);
+
+		// * Update storage *
+		MigrationExecutionFees::<T>::set(project_id, fee);
+
+		// * Emit events *
+		Self::deposit_event(Event::MigrationExecutionFeeSet { project_id, fee });
+
+		Ok(())
+	}
 
+	#[transactional]
+	pub fn do_set_destination_vesting(
+		caller: &AccountIdOf<T>,
+		project_id: ProjectId,
+		vesting: DestinationVesting,
+	) -> DispatchResult {
+		// * Get variables *
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+
+		// * Validity checks *
+		ensure!(&project_details.issuer_account == caller, Error::<T>::NotIssuer);
+		// Migrations copy the vesting when they are created at settlement
+		ensure!(project_details.funding_end_block.is_none(), Error::<T>::NotAllowed);
+
+		// * Update storage *
+		DestinationVestings::<T>::insert(project_id, vesting);
+
+		// * Emit events *
+		Self::deposit_event(Event::DestinationVestingSet { project_id, vesting });
+
+		Ok(())
+	}
+
+	pub fn do_handle_channel_open_request(message: Instruction) -> XcmResult {
 		let max_message_size_thresholds = T::MaxMessageSizeThresholds::get();
 		let max_capacity_thresholds = T::MaxCapacityThresholds::get();
 
@@ -51,48 +88,27 @@ impl<T: Config> Pallet<T> {
 					})
 					.ok_or(XcmError::BadOrigin)?;
 
-				let mut accept_channel_relay_call = vec![60u8, 1];
+				let hrmp_call_indices = T::RelayHrmpCallIndices::get();
+				let mut accept_channel_relay_call =
+					vec![hrmp_call_indices.pallet, hrmp_call_indices.accept_open_channel];
 				let sender_id = ParaId::from(sender).encode();
 				accept_channel_relay_call.extend_from_slice(&sender_id);
 
-				let mut request_channel_relay_call = vec![60u8, 0];
-				let recipient = ParaId::from(sender).encode();
-				request_channel_relay_call.extend_from_slice(&recipient);
-				let proposed_max_capacity = T::RequiredMaxCapacity::get().encode();
-				request_channel_relay_call.extend_from_slice(&proposed_max_capacity);
-				let proposed_max_message_size = T::RequiredMaxMessageSize::get().encode();
-				request_channel_relay_call.extend_from_slice(&proposed_max_message_size);
-
-				let xcm: Xcm<()> = Xcm(vec![
-					WithdrawAsset(vec![EXECUTION_DOT.clone()].into()),
-					BuyExecution { fees: EXECUTION_DOT.clone(), weight_limit: Unlimited },
-					Transact {
-						origin_kind: OriginKind::Native,
-						require_weight_at_most: MAX_WEIGHT,
-						call: accept_channel_relay_call.into(),
-					},
-					Transact {
-						origin_kind: OriginKind::Native,
-						require_weight_at_most: MAX_WEIGHT,
-						call: request_channel_relay_call.into(),
-					},
-					RefundSurplus,
-					DepositAsset {
-						assets: Wild(All),
-						beneficiary: MultiLocation { parents: 0, interior: X1(Parachain(POLIMEC_PARA_ID)) },
-					},
-				]);
-				let mut message = Some(xcm);
-
-				let dest_loc = MultiLocation { parents: 1, interior: Here };
-				let mut destination = Some(dest_loc);
-				let (ticket, _price) = T::XcmRouter::validate(&mut destination, &mut message)?;
+				// A project re-opening its channel after a closure might still have ours open
+				let mut relay_calls = vec![accept_channel_relay_call];
+				let request_channel = project_details.hrmp_channel_status.polimec_to_project != ChannelStatus::Open;
+				if request_channel {
+					relay_calls.push(Self::init_open_channel_relay_call(ParaId::from(sender)));
+				}
 
-				match T::XcmRouter::deliver(ticket) {
+				match Self::send_hrmp_relay_calls(relay_calls) {
 					Ok(_) => {
 						log::trace!(target: "pallet_funding::hrmp", "HrmpNewChannelOpenRequest: acceptance successfully sent");
 						project_details.hrmp_channel_status.project_to_polimec = ChannelStatus::Open;
-						project_details.hrmp_channel_status.polimec_to_project = ChannelStatus::AwaitingAcceptance;
+						if request_channel {
+							project_details.hrmp_channel_status.polimec_to_project = ChannelStatus::AwaitingAcceptance;
+							HrmpChannelRequests::<T>::insert(project_id, <frame_system::Pallet<T>>::block_number());
+						}
 						ProjectsDetails::<T>::insert(project_id, project_details);
 
 						Pallet::<T>::deposit_event(Event::<T>::HrmpChannelAccepted {
@@ -125,17 +141,61 @@ impl<T: Config> Pallet<T> {
 					.ok_or(XcmError::BadOrigin)?;
 
 				project_details.hrmp_channel_status.polimec_to_project = ChannelStatus::Open;
+				let is_reopened = project_details.migration_readiness_check.is_some();
 				ProjectsDetails::<T>::insert(project_id, project_details);
+				HrmpChannelRequests::<T>::remove(project_id);
 				Pallet::<T>::deposit_event(Event::<T>::HrmpChannelEstablished {
 					project_id,
 					para_id: ParaId::from(recipient),
 				});
 
-				Pallet::<T>::do_start_migration_readiness_check(
-					&(T::PalletId::get().into_account_truncating()),
+				// After a closure the receiver was already checked, and migrations resume right away
+				if !is_reopened {
+					Pallet::<T>::do_start_migration_readiness_check(
+						&(T::PalletId::get().into_account_truncating()),
+						project_id,
+					)
+					.map_err(|_| XcmError::NoDeal)?;
+				}
+				Ok(())
+			},
+			instr => {
+				log::trace!(target: "pallet_funding::hrmp", "Bad instruction: {:?}", instr);
+				Err(XcmError::Unimplemented)
+			},
+		}
+	}
+
+	/// The relay notifies both ends of a channel when it is closed. Migrations to the project pause until the
+	/// channels in both directions are open again.
+	pub fn do_handle_channel_closing(message: Instruction) -> XcmResult {
+		match message {
+			Instruction::HrmpChannelClosing { initiator, sender, recipient } => {
+				log::trace!(target: "pallet_funding::hrmp", "HrmpChannelClosing received: {:?}", message);
+				let project_para_id = match (sender, recipient) {
+					(POLIMEC_PARA_ID, project_para_id) | (project_para_id, POLIMEC_PARA_ID) => project_para_id,
+					_ => return Err(XcmError::BadOrigin),
+				};
+				let (project_id, mut project_details) = ProjectsDetails::<T>::iter()
+					.find(|(_id, details)| {
+						details.parachain_id == Some(ParaId::from(project_para_id)) &&
+							details.status == FundingSuccessful
+					})
+					.ok_or(XcmError::BadOrigin)?;
+
+				if sender == POLIMEC_PARA_ID {
+					project_details.hrmp_channel_status.polimec_to_project = ChannelStatus::Closed;
+				} else {
+					project_details.hrmp_channel_status.project_to_polimec = ChannelStatus::Closed;
+				}
+				let hrmp_channel_status = project_details.hrmp_channel_status;
+				ProjectsDetails::<T>::insert(project_id, project_details);
+				Pallet::<T>::deposit_event(Event::<T>::HrmpChannelClosed {
 					project_id,
-				)
-				.map_err(|_| XcmError::NoDeal)?;
+					para_id: ParaId::from(project_para_id),
+					initiator: ParaId::from(initiator),
+					hrmp_channel_status,
+				});
 				Ok(())
 			},
 			instr => {
@@ -145,6 +205,90 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Request again the channel from Polimec to the project, after it was closed or its request went unanswered.
+	/// The project chain has to request the channel in the other direction itself.
+	#[transactional]
+	pub fn do_request_hrmp_channel(caller: &AccountIdOf<T>, project_id: ProjectId) -> DispatchResult {
+		// * Get variables *
+		let mut project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let project_para_id = project_details.parachain_id.ok_or(Error::<T>::ImpossibleState)?;
+		let now = <frame_system::Pallet<T>>::block_number();
+
+		// * Validity checks *
+		ensure!(&project_details.issuer_account == caller, Error::<T>::NotIssuer);
+		ensure!(project_details.status == ProjectStatus::FundingSuccessful, Error::<T>::IncorrectRound);
+		ensure!(project_details.migration_type == MigrationType::Pallet, Error::<T>::WrongMigrationType);
+		let is_stale = |requested_at: BlockNumberFor<T>| {
+			now >= requested_at.saturating_add(T::HrmpChannelRequestTimeout::get())
+		};
+		match project_details.hrmp_channel_status.polimec_to_project {
+			ChannelStatus::Closed => (),
+			ChannelStatus::AwaitingAcceptance => ensure!(
+				HrmpChannelRequests::<T>::get(project_id).map_or(true, is_stale),
+				Error::<T>::HrmpChannelRequestPending
+			),
+			ChannelStatus::Open => return Err(Error::<T>::HrmpChannelAlreadyOpen.into()),
+		}
+
+		// * Process Data *
+		Self::send_hrmp_relay_calls(vec![Self::init_open_channel_relay_call(project_para_id)])
+			.map_err(|_| Error::<T>::XcmFailed)?;
+
+		// * Update storage *
+		project_details.hrmp_channel_status.polimec_to_project = ChannelStatus::AwaitingAcceptance;
+		ProjectsDetails::<T>::insert(project_id, project_details);
+		HrmpChannelRequests::<T>::insert(project_id, now);
+
+		// * Emit events *
+		Self::deposit_event(Event::HrmpChannelRequested { project_id, para_id: project_para_id });
+
+		Ok(())
+	}
+
+	/// Encoded relay call requesting a channel from Polimec to the project, with the capacity and message size
+	/// Polimec requires.
+	fn init_open_channel_relay_call(project_para_id: ParaId) -> Vec<u8> {
+		let hrmp_call_indices = T::RelayHrmpCallIndices::get();
+		let mut request_channel_relay_call = vec![hrmp_call_indices.pallet, hrmp_call_indices.init_open_channel];
+		request_channel_relay_call.extend_from_slice(&project_para_id.encode());
+		request_channel_relay_call.extend_from_slice(&T::RequiredMaxCapacity::get().encode());
+		request_channel_relay_call.extend_from_slice(&T::RequiredMaxMessageSize::get().encode());
+		request_channel_relay_call
+	}
+
+	/// Dispatch the given calls on the relay, paying with DOT from Polimec's sovereign account.
+	fn send_hrmp_relay_calls(relay_calls: Vec<Vec<u8>>) -> Result<XcmHash, SendError> {
+		// TODO: set these constants with a proper value
+		const EXECUTION_DOT: MultiAsset = MultiAsset {
+			id: Concrete(MultiLocation { parents: 0, interior: Here }),
+			fun: Fungible(1_0_000_000_000u128),
+		};
+		const MAX_WEIGHT: Weight = Weight::from_parts(20_000_000_000, 1_000_000);
+
+		let mut instructions = vec![
+			WithdrawAsset(vec![EXECUTION_DOT.clone()].into()),
+			BuyExecution { fees: EXECUTION_DOT.clone(), weight_limit: Unlimited },
+		];
+		instructions.extend(relay_calls.into_iter().map(|call| Transact {
+			origin_kind: OriginKind::Native,
+			require_weight_at_most: MAX_WEIGHT,
+			call: call.into(),
+		}));
+		instructions.extend(vec![
+			RefundSurplus,
+			DepositAsset {
+				assets: Wild(All),
+				beneficiary: MultiLocation { parents: 0, interior: X1(Parachain(POLIMEC_PARA_ID)) },
+			},
+		]);
+		let mut message = Some(Xcm(instructions));
+
+		let dest_loc = MultiLocation { parents: 1, interior: Here };
+		let mut destination = Some(dest_loc);
+		let (ticket, _price) = T::XcmRouter::validate(&mut destination, &mut message)?;
+		T::XcmRouter::deliver(ticket)
+	}
+
 	#[transactional]
 	pub fn do_start_migration_readiness_check(caller: &AccountIdOf<T>, project_id: ProjectId) -> DispatchResult {
 		// * Get variables *
@@ -158,6 +302,7 @@ impl<T: Config> Pallet<T> {
 
 		// * Validity checks *
 		ensure!(project_details.status == ProjectStatus::FundingSuccessful, Error::<T>::IncorrectRound);
+		ensure!(project_details.migration_type == MigrationType::Pallet, Error::<T>::WrongMigrationType);
 		ensure!(
 			project_details.hrmp_channel_status ==
 				HRMPChannelStatus {
@@ -166,16 +311,19 @@ impl<T: Config> Pallet<T> {
 				},
 			Error::<T>::ChannelNotOpen
 		);
+		let metadata_check_failed = MigrationReadinessReports::<T>::get(project_id)
+			.map_or(false, |report| matches!(report.metadata_check, Some((_, CheckOutcome::Failed))));
 		if project_details.migration_readiness_check.is_none() {
 			ensure!(caller.clone() == T::PalletId::get().into_account_truncating(), Error::<T>::NotAllowed);
-		} else if matches!(
-			project_details.migration_readiness_check,
-			Some(MigrationReadinessCheck {
-				holding_check: (_, CheckOutcome::Failed),
-				pallet_check: (_, CheckOutcome::Failed),
-				..
-			})
-		) {
+		} else if metadata_check_failed ||
+			matches!(
+				project_details.migration_readiness_check,
+				Some(MigrationReadinessCheck {
+					holding_check: (_, CheckOutcome::Failed),
+					pallet_check: (_, CheckOutcome::Failed),
+					..
+				})
+			) {
 			ensure!(caller == &project_details.issuer_account, Error::<T>::NotIssuer);
 		}
 
@@ -195,11 +343,23 @@ impl<T: Config> Pallet<T> {
 			Here,
 		);
 
+		// Responses to the previous check are ignored from now on
+		if let Some(previous_check) = project_details.migration_readiness_check {
+			MigrationReadinessQueries::<T>::remove(previous_check.holding_check.0);
+			MigrationReadinessQueries::<T>::remove(previous_check.pallet_check.0);
+		}
+		if let Some((query_id, _)) = MigrationReadinessReports::<T>::get(project_id).and_then(|r| r.metadata_check) {
+			MigrationReadinessQueries::<T>::remove(query_id);
+		}
+		MigrationReadinessQueries::<T>::insert(query_id_holdings, project_id);
+		MigrationReadinessQueries::<T>::insert(query_id_pallet, project_id);
+
 		project_details.migration_readiness_check = Some(MigrationReadinessCheck {
 			holding_check: (query_id_holdings, CheckOutcome::AwaitingResponse),
 			pallet_check: (query_id_pallet, CheckOutcome::AwaitingResponse),
 		});
 		ProjectsDetails::<T>::insert(project_id, project_details.clone());
+		MigrationReadinessReports::<T>::insert(project_id, MigrationReadinessReport::default());
 
 		let total_cts_minted = <T as Config>::ContributionTokenCurrency::total_issuance(project_id);
 
@@ -243,26 +403,26 @@ impl<T: Config> Pallet<T> {
 		response: xcm::v3::Response,
 	) -> DispatchResult {
 		use xcm::v3::prelude::*;
-		// TODO: check if this is too low performance. Maybe we want a new map of query_id -> project_id
-		let (project_id, mut project_details, mut migration_check) = ProjectsDetails::<T>::iter()
-			.find_map(|(project_id, details)| {
-				if let Some(check @ MigrationReadinessCheck { holding_check, pallet_check }) =
-					details.migration_readiness_check
-				{
-					if holding_check.0 == query_id || pallet_check.0 == query_id {
-						return Some((project_id, details, check));
-					}
-				}
-				None
-			})
-			.ok_or(Error::<T>::ProjectDetailsNotFound)?;
-
 		let para_id = if let MultiLocation { parents: 1, interior: X1(Parachain(para_id)) } = location {
 			ParaId::from(para_id)
 		} else {
 			return Err(Error::<T>::WrongParaId.into());
 		};
 
+		let project_id = MigrationReadinessQueries::<T>::take(query_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let mut report = MigrationReadinessReports::<T>::get(project_id).unwrap_or_default();
+
+		// The token metadata check is only sent once the other two passed, so it has its own query
+		if matches!(report.metadata_check, Some((id, CheckOutcome::AwaitingResponse)) if id == query_id) {
+			return Self::do_token_metadata_check_response(para_id, project_id, report, query_id, response);
+		}
+
+		let mut project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let mut migration_check = project_details
+			.migration_readiness_check
+			.filter(|check| check.holding_check.0 == query_id || check.pallet_check.0 == query_id)
+			.ok_or(Error::<T>::ProjectDetailsNotFound)?;
+
 		let project_metadata = ProjectsMetadata::<T>::get(project_id).ok_or(Error::<T>::ProjectMetadataNotFound)?;
 		let contribution_tokens_sold =
 			project_metadata.total_allocation_size.saturating_sub(project_details.remaining_contribution_tokens);
@@ -274,22 +434,67 @@ impl<T: Config> Pallet<T> {
 				MigrationReadinessCheck { holding_check: (_, CheckOutcome::AwaitingResponse), .. },
 			) => {
 				let ct_sold_as_u128: u128 = contribution_tokens_sold.try_into().map_err(|_| Error::<T>::BadMath)?;
+				report.ct_required = ct_sold_as_u128;
 				let assets: Vec<MultiAsset> = assets.into_inner();
-				let asset_1 = assets[0].clone();
-				match asset_1 {
-					MultiAsset {
+				let ct_found = match assets.first() {
+					Some(MultiAsset {
 						id: Concrete(MultiLocation { parents: 1, interior: X1(Parachain(pid)) }),
 						fun: Fungible(amount),
-					} if amount >= ct_sold_as_u128 && pid == u32::from(para_id) => {
-						migration_check.holding_check.1 = CheckOutcome::Passed;
+					}) if *pid == u32::from(para_id) => Some(*amount),
+					_ => None,
+				};
+				report.ct_found = ct_found;
+				let failure = match ct_found {
+					Some(amount) if amount >= ct_sold_as_u128 => None,
+					Some(amount) => Some(ReadinessFailure::NotEnoughCTs { required: ct_sold_as_u128, found: amount }),
+					None => Some(ReadinessFailure::UnexpectedHoldings),
+				};
+				if let Some(failure) = failure {
+					migration_check.holding_check.1 = CheckOutcome::Failed;
+					report.record_failure(failure);
+					Self::deposit_event(Event::<T>::MigrationCheckResponseRejected { project_id, query_id, response });
+				} else {
+					migration_check.holding_check.1 = CheckOutcome::Passed;
+					Self::deposit_event(Event::<T>::MigrationCheckResponseAccepted { project_id, query_id, response });
+				}
+			},
+
+			(
+				Response::PalletsInfo(pallets_info),
+				MigrationReadinessCheck { pallet_check: (_, CheckOutcome::AwaitingResponse), .. },
+			) => {
+				let call_indices = match pallets_info.as_slice() {
+					[pallet_info] => {
+						report.receiver_version = Some((pallet_info.major, pallet_info.minor, pallet_info.patch));
+						Self::check_receiver_compatibility(pallet_info).and_then(|_| {
+							Self::receiver_call_indices(pallet_info).ok_or(ReadinessFailure::ReceiverMismatch)
+						})
+					},
+					_ => Err(ReadinessFailure::ReceiverNotFound),
+				};
+				match call_indices {
+					Ok(call_indices) => {
+						migration_check.pallet_check.1 = CheckOutcome::Passed;
+						project_details.receiver_call_indices = Some(call_indices);
+						report.metadata_check = Self::send_token_metadata_check(
+							para_id,
+							call_indices.pallet,
+							&project_metadata.token_information,
+						);
+						match report.metadata_check {
+							Some((metadata_query_id, _)) =>
+								MigrationReadinessQueries::<T>::insert(metadata_query_id, project_id),
+							None => report.record_failure(ReadinessFailure::TokenMetadataCheckNotSent),
+						}
 						Self::deposit_event(Event::<T>::MigrationCheckResponseAccepted {
 							project_id,
 							query_id,
 							response,
 						});
 					},
-					_ => {
-						migration_check.holding_check.1 = CheckOutcome::Failed;
+					Err(failure) => {
+						migration_check.pallet_check.1 = CheckOutcome::Failed;
+						report.record_failure(failure);
 						Self::deposit_event(Event::<T>::MigrationCheckResponseRejected {
 							project_id,
 							query_id,
@@ -298,33 +503,69 @@ impl<T: Config> Pallet<T> {
 					},
 				}
 			},
-
-			(
-				Response::PalletsInfo(pallets_info),
-				MigrationReadinessCheck { pallet_check: (_, CheckOutcome::AwaitingResponse), .. },
-			) =>
-				if pallets_info.len() == 1 && pallets_info[0] == T::PolimecReceiverInfo::get() {
-					migration_check.pallet_check.1 = CheckOutcome::Passed;
-					Self::deposit_event(Event::<T>::MigrationCheckResponseAccepted { project_id, query_id, response });
-				} else {
-					migration_check.pallet_check.1 = CheckOutcome::Failed;
-					Self::deposit_event(Event::<T>::MigrationCheckResponseRejected { project_id, query_id, response });
-				},
 			_ => return Err(Error::<T>::NotAllowed.into()),
 		};
 
 		project_details.migration_readiness_check = Some(migration_check);
 		ProjectsDetails::<T>::insert(project_id, project_details);
+		MigrationReadinessReports::<T>::insert(project_id, report);
 		Ok(())
 	}
 
+	/// Last step of the readiness check. The receiver call fails if the token of the project's chain does not have
+	/// the decimals and symbol of the CT.
+	fn do_token_metadata_check_response(
+		para_id: ParaId,
+		project_id: ProjectId,
+		mut report: MigrationReadinessReport,
+		query_id: xcm::v3::QueryId,
+		response: xcm::v3::Response,
+	) -> DispatchResult {
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		ensure!(project_details.parachain_id == Some(para_id), Error::<T>::WrongParaId);
+
+		if let Response::DispatchResult(MaybeErrorCode::Success) = response {
+			report.metadata_check = Some((query_id, CheckOutcome::Passed));
+			Self::deposit_event(Event::<T>::MigrationCheckResponseAccepted { project_id, query_id, response });
+		} else {
+			report.metadata_check = Some((query_id, CheckOutcome::Failed));
+			report.record_failure(ReadinessFailure::TokenMetadataMismatch);
+			Self::deposit_event(Event::<T>::MigrationCheckResponseRejected { project_id, query_id, response });
+		}
+		MigrationReadinessReports::<T>::insert(project_id, report);
+
+		Ok(())
+	}
+
+	/// Send the token metadata check to the receiver, returning the query awaiting its outcome.
+	fn send_token_metadata_check(
+		para_id: ParaId,
+		pallet_index: u8,
+		token_information: &CurrencyMetadata<BoundedVec<u8, StringLimitOf<T>>>,
+	) -> Option<(xcm::v3::QueryId, CheckOutcome)> {
+		let project_multilocation = ParentThen(X1(Parachain(para_id.into())));
+		let now = <frame_system::Pallet<T>>::block_number();
+		let call = Call::<T>::migration_check_response { query_id: Default::default(), response: Default::default() };
+		let query_id = pallet_xcm::Pallet::<T>::new_notify_query(
+			project_multilocation.clone(),
+			<T as Config>::RuntimeCall::from(call),
+			now + QUERY_RESPONSE_TIME_WINDOW_BLOCKS.into(),
+			Here,
+		);
+		let xcm = Self::construct_token_metadata_check_xcm_message(pallet_index, token_information, query_id);
+		<pallet_xcm::Pallet<T>>::send_xcm(Here, project_multilocation, xcm).ok()?;
+
+		Some((query_id, CheckOutcome::AwaitingResponse))
+	}
+
 	#[transactional]
 	pub fn do_migrate_one_participant(project_id: ProjectId, participant: AccountIdOf<T>) -> DispatchResult {
 		// * Get variables *
 		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		ensure!(project_details.migration_type == MigrationType::Pallet, Error::<T>::WrongMigrationType);
 		let migration_readiness_check = project_details.migration_readiness_check.ok_or(Error::<T>::ChannelNotReady)?;
 		let project_para_id = project_details.parachain_id.ok_or(Error::<T>::ImpossibleState)?;
-		let now = <frame_system::Pallet<T>>::block_number();
+		let call_indices = project_details.receiver_call_indices.ok_or(Error::<T>::ChannelNotReady)?;
 		ensure!(
 			Self::user_has_no_participations(project_id, participant.clone()),
 			Error::<T>::ParticipationsNotSettled
@@ -333,56 +574,536 @@ impl<T: Config> Pallet<T> {
 			UserMigrations::<T>::get(project_id, participant.clone()).ok_or(Error::<T>::NoMigrationsFound)?;
 
 		// * Validity Checks *
+		ensure!(project_details.hrmp_channel_status.is_open(), Error::<T>::ChannelNotOpen);
 		ensure!(migration_readiness_check.is_ready(), Error::<T>::ChannelNotReady);
+		ensure!(
+			MigrationReadinessReports::<T>::get(project_id).map_or(false, |report| report.token_metadata_matches()),
+			Error::<T>::ChannelNotReady
+		);
 
+		// * Process Data *
+		let participants = BoundedVec::try_from(vec![participant]).map_err(|_| Error::<T>::ImpossibleState)?;
+		let migrations = Migrations::from(migrations.into());
+		Self::send_migration_batch(project_id, project_para_id, call_indices, participants, migrations)
+	}
+
+	#[transactional]
+	pub fn do_migrate_participants_batch(project_id: ProjectId) -> DispatchResultWithPostInfo {
+		// * Get variables *
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		ensure!(project_details.migration_type == MigrationType::Pallet, Error::<T>::WrongMigrationType);
+		let migration_readiness_check = project_details.migration_readiness_check.ok_or(Error::<T>::ChannelNotReady)?;
+		let project_para_id = project_details.parachain_id.ok_or(Error::<T>::ImpossibleState)?;
+		let call_indices = project_details.receiver_call_indices.ok_or(Error::<T>::ChannelNotReady)?;
+		let max_migrations = Self::migrations_per_xcm_message_allowed() as usize;
+
+		// * Validity Checks *
+		ensure!(project_details.hrmp_channel_status.is_open(), Error::<T>::ChannelNotOpen);
+		ensure!(migration_readiness_check.is_ready(), Error::<T>::ChannelNotReady);
+		ensure!(
+			MigrationReadinessReports::<T>::get(project_id).map_or(false, |report| report.token_metadata_matches()),
+			Error::<T>::ChannelNotReady
+		);
+
+		// * Process Data *
+		let mut participants = BoundedVec::<AccountIdOf<T>, MaxParticipantsPerMigrationBatch<T>>::new();
+		let mut batch_migrations = Migrations::new();
+		let mut visited = 0u32;
+		let pending = PendingMigrations::<T>::iter_key_prefix(project_id);
+		for participant in pending.take(MaxParticipantsPerMigrationBatch::<T>::get() as usize) {
+			visited.saturating_inc();
+			let Some((_, migrations)) = UserMigrations::<T>::get(project_id, participant.clone()) else { continue };
+			if batch_migrations.len().saturating_add(migrations.len()) > max_migrations {
+				break;
+			}
+			if participants.try_push(participant).is_err() {
+				break;
+			}
+			migrations.into_iter().for_each(|migration| batch_migrations.push(migration));
+		}
+		ensure!(!participants.is_empty(), Error::<T>::NoMigrationsFound);
+
+		Self::send_migration_batch(project_id, project_para_id, call_indices, participants, batch_migrations)?;
+
+		Ok(PostDispatchInfo {
+			actual_weight: Some(WeightInfoOf::<T>::migrate_participants_batch(visited)),
+			pays_fee: Pays::Yes,
+		})
+	}
+
+	/// Send the migrations of all the given participants in one XCM message, and track them under a single query.
+	fn send_migration_batch(
+		project_id: ProjectId,
+		project_para_id: ParaId,
+		call_indices: ReceiverCallIndices,
+		participants: BoundedVec<AccountIdOf<T>, MaxParticipantsPerMigrationBatch<T>>,
+		migrations: Migrations,
+	) -> DispatchResult {
+		let now = <frame_system::Pallet<T>>::block_number();
+		let timeout_block = now.saturating_add(T::MigrationQueryTimeout::get());
 		let project_multilocation = MultiLocation { parents: 1, interior: X1(Parachain(project_para_id.into())) };
 		let call: <T as Config>::RuntimeCall =
 			Call::confirm_migrations { query_id: Default::default(), response: Default::default() }.into();
 		let query_id =
-			pallet_xcm::Pallet::<T>::new_notify_query(project_multilocation, call.into(), now + 20u32.into(), Here);
+			pallet_xcm::Pallet::<T>::new_notify_query(project_multilocation, call.into(), timeout_block, Here);
 
-		Self::change_migration_status(project_id, participant.clone(), MigrationStatus::Sent(query_id))?;
+		for participant in participants.iter() {
+			Self::change_migration_status(project_id, participant.clone(), MigrationStatus::Sent(query_id))?;
+		}
 
-		// * Process Data *
-		let xcm = Self::construct_migration_xcm_message(migrations.into(), query_id);
+		let execution_fee = MigrationExecutionFees::<T>::get(project_id);
+		let xcm = Self::construct_migration_xcm_message(call_indices, migrations, query_id, execution_fee);
 
 		<pallet_xcm::Pallet<T>>::send_xcm(Here, project_multilocation, xcm).map_err(|_| Error::<T>::XcmFailed)?;
-		ActiveMigrationQueue::<T>::insert(query_id, (project_id, participant.clone()));
-
-		Self::deposit_event(Event::<T>::MigrationStatusUpdated {
-			project_id,
-			account: participant,
-			status: MigrationStatus::Sent(query_id),
+		ActiveMigrationQueue::<T>::insert(query_id, (project_id, participants.clone(), timeout_block));
+		MigrationTimeouts::<T>::insert(timeout_block, query_id, ());
+		MigrationQueriesPerParachain::<T>::mutate(project_para_id, |queries| {
+			*queries = Some(queries.unwrap_or_default().saturating_add(1))
 		});
 
+		for participant in participants {
+			Self::deposit_event(Event::<T>::MigrationStatusUpdated {
+				project_id,
+				account: participant,
+				status: MigrationStatus::Sent(query_id),
+			});
+		}
+
 		Ok(())
 	}
 
 	#[transactional]
-	pub fn do_confirm_migrations(location: MultiLocation, query_id: QueryId, response: Response) -> DispatchResult {
+	pub fn do_confirm_migrations(
+		location: MultiLocation,
+		query_id: QueryId,
+		response: Response,
+	) -> DispatchResultWithPostInfo {
 		use xcm::v3::prelude::*;
-		let (project_id, participant) =
+		let (project_id, participants, timeout_block) =
 			ActiveMigrationQueue::<T>::take(query_id).ok_or(Error::<T>::NoActiveMigrationsFound)?;
+		MigrationTimeouts::<T>::remove(timeout_block, query_id);
 		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
 
 		ensure!(
 			matches!(location, MultiLocation { parents: 1, interior: X1(Parachain(para_id))} if Some(ParaId::from(para_id)) == project_details.parachain_id),
 			Error::<T>::WrongParaId
 		);
+		let project_para_id = project_details.parachain_id.ok_or(Error::<T>::ImpossibleState)?;
+		Self::remove_migration_query(project_para_id);
 
-		let status = match response {
-			Response::DispatchResult(MaybeErrorCode::Success) => {
-				Self::change_migration_status(project_id, participant.clone(), MigrationStatus::Confirmed)?;
-				MigrationStatus::Confirmed
-			},
+		let batch_status = match response {
+			Response::DispatchResult(MaybeErrorCode::Success) => MigrationStatus::Confirmed,
 			Response::DispatchResult(MaybeErrorCode::Error(_)) |
-			Response::DispatchResult(MaybeErrorCode::TruncatedError(_)) => {
-				Self::change_migration_status(project_id, participant.clone(), MigrationStatus::Failed)?;
-				MigrationStatus::Failed
-			},
+			Response::DispatchResult(MaybeErrorCode::TruncatedError(_)) => MigrationStatus::Failed,
 			_ => return Err(Error::<T>::NotAllowed.into()),
 		};
-		Self::deposit_event(Event::<T>::MigrationStatusUpdated { project_id, account: participant, status });
+		let participants_count = participants.len() as u32;
+		for participant in participants {
+			// The receiver executes migrations one by one, and reports the ones it could not execute
+			let user = T::AccountId32Conversion::convert(participant.clone());
+			let reported_failed = FailedMigrationReports::<T>::take(project_para_id, user).is_some();
+			let status = if reported_failed { MigrationStatus::Failed } else { batch_status.clone() };
+
+			Self::change_migration_status(project_id, participant.clone(), status.clone())?;
+			if status == MigrationStatus::Confirmed {
+				Self::burn_migrated_contribution_tokens(project_id, &participant)?;
+				Self::remove_migration_retry(project_id, &participant);
+			} else {
+				Self::schedule_migration_retry(project_id, participant.clone());
+			}
+			Self::deposit_event(Event::<T>::MigrationStatusUpdated {
+				project_id,
+				account: participant,
+				status: status.clone(),
+			});
+		}
+		Ok(Some(WeightInfoOf::<T>::confirm_migrations(participants_count)).into())
+	}
+
+	#[transactional]
+	pub fn do_report_failed_migrations(location: MultiLocation, failed: Vec<MigrationOrigin>) -> DispatchResult {
+		// * Get variables *
+		let MultiLocation { parents: 1, interior: X1(Parachain(para_id)) } = location else {
+			return Err(Error::<T>::WrongParaId.into());
+		};
+		let para_id = ParaId::from(para_id);
+
+		// * Validity checks *
+		ensure!(MigratingParachains::<T>::contains(&location), Error::<T>::NoActiveMigrationsFound);
+		ensure!(
+			failed.len() <= Self::migrations_per_xcm_message_allowed() as usize,
+			Error::<T>::TooManyFailedMigrations
+		);
+
+		// * Update storage *
+		for MigrationOrigin { user, .. } in failed {
+			FailedMigrationReports::<T>::insert(para_id, user, ());
+		}
+
+		Ok(())
+	}
+
+	#[transactional]
+	pub fn do_start_offchain_migration(
+		caller: &AccountIdOf<T>,
+		project_id: ProjectId,
+		migrations_count: u32,
+	) -> DispatchResultWithPostInfo {
+		// * Get variables *
+		let mut project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+
+		// * Validity checks *
+		ensure!(&project_details.issuer_account == caller, Error::<T>::NotIssuer);
+		ensure!(project_details.status == ProjectStatus::FundingSuccessful, Error::<T>::IncorrectRound);
+		ensure!(
+			project_details.migration_type == MigrationType::Pallet &&
+				project_details.migration_readiness_check.is_none(),
+			Error::<T>::WrongMigrationType
+		);
+		// The root can only be published once every participation turned into a migration
+		ensure!(
+			Evaluations::<T>::iter_prefix_values((project_id,)).next().is_none() &&
+				Bids::<T>::iter_prefix_values((project_id,)).next().is_none() &&
+				Contributions::<T>::iter_prefix_values((project_id,)).next().is_none(),
+			Error::<T>::ParticipationsNotSettled
+		);
+
+		// * Calculate new variables *
+		let mut leaves = Vec::new();
+		for (_participant, (status, migrations)) in UserMigrations::<T>::iter_prefix(project_id) {
+			ensure!(status == MigrationStatus::NotStarted, Error::<T>::NotAllowed);
+			leaves.extend(migrations.iter().map(Self::migration_merkle_leaf));
+			// Stop before building a tree bigger than the one paid for
+			ensure!(leaves.len() <= migrations_count as usize, Error::<T>::MigrationsCountTooLow);
+		}
+		ensure!(!leaves.is_empty(), Error::<T>::NoMigrationsFound);
+		let leaves_count = leaves.len() as u32;
+		let merkle_root = Self::merkle_root(leaves);
+
+		// * Update storage *
+		project_details.migration_type = MigrationType::Offchain;
+		ProjectsDetails::<T>::insert(project_id, project_details);
+		MigrationMerkleRoots::<T>::insert(project_id, merkle_root);
+
+		// * Emit events *
+		Self::deposit_event(Event::<T>::OffchainMigrationStarted { project_id, merkle_root });
+
+		Ok(PostDispatchInfo {
+			actual_weight: Some(WeightInfoOf::<T>::start_offchain_migration(leaves_count)),
+			pays_fee: Pays::Yes,
+		})
+	}
+
+	pub fn do_consent_offchain_migration(participant: AccountIdOf<T>, project_id: ProjectId) -> DispatchResult {
+		// * Get variables *
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let (status, _) =
+			UserMigrations::<T>::get(project_id, participant.clone()).ok_or(Error::<T>::NoMigrationsFound)?;
+
+		// * Validity checks *
+		ensure!(project_details.migration_type == MigrationType::Offchain, Error::<T>::WrongMigrationType);
+		ensure!(status == MigrationStatus::NotStarted, Error::<T>::NotAllowed);
+
+		// * Update storage *
+		OffchainMigrationConsents::<T>::insert(project_id, participant.clone(), ());
+
+		// * Emit events *
+		Self::deposit_event(Event::<T>::OffchainMigrationConsented { project_id, account: participant });
+
+		Ok(())
+	}
+
+	#[transactional]
+	pub fn do_confirm_offchain_migration(
+		caller: &AccountIdOf<T>,
+		project_id: ProjectId,
+		participant: AccountIdOf<T>,
+	) -> DispatchResult {
+		// * Get variables *
+		let project_details = ProjectsDetails::<T>::get(project_id).ok_or(Error::<T>::ProjectDetailsNotFound)?;
+		let (status, migrations) =
+			UserMigrations::<T>::get(project_id, participant.clone()).ok_or(Error::<T>::NoMigrationsFound)?;
+
+		// * Validity checks *
+		ensure!(&project_details.issuer_account == caller, Error::<T>::NotIssuer);
+		ensure!(project_details.migration_type == MigrationType::Offchain, Error::<T>::WrongMigrationType);
+		ensure!(status == MigrationStatus::NotStarted, Error::<T>::NotAllowed);
+		// The issuer cannot burn the CTs of a participant who did not claim them on the destination chain
+		ensure!(
+			OffchainMigrationConsents::<T>::take(project_id, participant.clone()).is_some(),
+			Error::<T>::OffchainMigrationNotConsented
+		);
+
+		// * Update storage *
+		UserMigrations::<T>::insert(project_id, participant.clone(), (MigrationStatus::Confirmed, migrations));
+		PendingMigrations::<T>::remove(project_id, participant.clone());
+		Self::burn_migrated_contribution_tokens(project_id, &participant)?;
+
+		// * Emit events *
+		Self::deposit_event(Event::<T>::MigrationStatusUpdated {
+			project_id,
+			account: participant,
+			status: MigrationStatus::Confirmed,
+		});
+
+		Ok(())
+	}
+
+	/// Merkle proofs of every migration of a participant in a project using [`MigrationType::Offchain`], to claim
+	/// the CTs on the destination chain. Empty if the project did not publish its Merkle root.
+	pub fn offchain_migration_proofs(
+		project_id: ProjectId,
+		participant: AccountIdOf<T>,
+	) -> Vec<(Migration, Vec<H256>)> {
+		if !MigrationMerkleRoots::<T>::contains_key(project_id) {
+			return Vec::new();
+		}
+		let Some((_, migrations)) = UserMigrations::<T>::get(project_id, participant) else { return Vec::new() };
+		let leaves = UserMigrations::<T>::iter_prefix_values(project_id)
+			.flat_map(|(_, migrations)| migrations.into_iter().map(|migration| Self::migration_merkle_leaf(&migration)))
+			.collect::<Vec<_>>();
+
+		migrations
+			.into_iter()
+			.filter_map(|migration| {
+				let proof = Self::merkle_proof(leaves.clone(), Self::migration_merkle_leaf(&migration))?;
+				Some((migration, proof))
+			})
+			.collect()
+	}
+
+	/// Burn on Polimec the CTs of a confirmed migration, so they don't exist on two chains at once.
+	/// The migration already happened, so if the participant moved some of the CTs, only the ones left are burned
+	/// and the rest is recorded in [`UnburnedMigratedContributionTokens`].
+	fn burn_migrated_contribution_tokens(project_id: ProjectId, participant: &AccountIdOf<T>) -> DispatchResult {
+		let (_, migrations) =
+			UserMigrations::<T>::get(project_id, participant.clone()).ok_or(Error::<T>::NoMigrationsFound)?;
+		let migrated_amount = migrations
+			.iter()
+			.fold(0u128, |total, migration| total.saturating_add(migration.info.contribution_token_amount));
+		let migrated_amount = BalanceOf::<T>::try_from(migrated_amount).map_err(|_| Error::<T>::BadMath)?;
+
+		// A failed burn must not revert the confirmations of the other participants in the batch
+		let burned_amount = T::ContributionTokenCurrency::burn_from(
+			project_id,
+			participant,
+			migrated_amount,
+			Precision::BestEffort,
+			Fortitude::Force,
+		)
+		.unwrap_or_default();
+		MigratedContributionTokens::<T>::mutate(project_id, |migrated| {
+			*migrated = migrated.saturating_add(burned_amount)
+		});
+
+		let shortfall = migrated_amount.saturating_sub(burned_amount);
+		if !shortfall.is_zero() {
+			UnburnedMigratedContributionTokens::<T>::mutate(project_id, participant, |unburned| {
+				*unburned = unburned.saturating_add(shortfall)
+			});
+			Self::deposit_event(Event::<T>::MigratedContributionTokensNotBurned {
+				project_id,
+				account: participant.clone(),
+				shortfall,
+			});
+		}
+
+		Ok(())
+	}
+
+	/// Mark the migrations of every query that the project chain did not answer in time as failed.
+	/// Goes through the queries by timeout block, stops before exceeding `max_weight`, and returns the weight used.
+	pub fn do_process_migration_timeouts(now: BlockNumberFor<T>, max_weight: Weight) -> Weight {
+		let db_weight = <T as frame_system::Config>::DbWeight::get();
+		// Reading and updating the next block to process
+		let mut used_weight = db_weight.reads_writes(1, 1);
+		if used_weight.any_gt(max_weight) {
+			return Weight::zero();
+		}
+
+		// * Get variables *
+		let mut block = NextMigrationTimeoutBlock::<T>::get();
+		let mut timed_out_queries = Vec::new();
+		// Queries time out once their timeout block is over
+		'blocks: while block < now {
+			if used_weight.saturating_add(db_weight.reads(1)).any_gt(max_weight) {
+				break;
+			}
+			used_weight.saturating_accrue(db_weight.reads(1));
+			for query_id in MigrationTimeouts::<T>::iter_key_prefix(block) {
+				let participants_count = ActiveMigrationQueue::<T>::get(query_id)
+					.map_or(0, |(_, participants, _)| participants.len() as u64);
+				// Each participant gets its status changed, its failure report cleared and a retry scheduled
+				let timeout_weight = db_weight.reads_writes(3 + 2 * participants_count, 3 + 5 * participants_count);
+				if used_weight.saturating_add(timeout_weight).any_gt(max_weight) {
+					break 'blocks;
+				}
+				used_weight.saturating_accrue(timeout_weight);
+				timed_out_queries.push((block, query_id));
+			}
+			block.saturating_inc();
+		}
+
+		// * Update storage *
+		NextMigrationTimeoutBlock::<T>::put(block);
+		for (timeout_block, query_id) in timed_out_queries {
+			MigrationTimeouts::<T>::remove(timeout_block, query_id);
+			let Some((project_id, participants, _)) = ActiveMigrationQueue::<T>::take(query_id) else { continue };
+			let maybe_para_id = ProjectsDetails::<T>::get(project_id).and_then(|details| details.parachain_id);
+			if let Some(para_id) = maybe_para_id {
+				Self::remove_migration_query(para_id);
+			}
+			for participant in participants {
+				if let Some(para_id) = maybe_para_id {
+					let user = T::AccountId32Conversion::convert(participant.clone());
+					FailedMigrationReports::<T>::remove(para_id, user);
+				}
+				if Self::change_migration_status(project_id, participant.clone(), MigrationStatus::Failed).is_err() {
+					continue;
+				}
+				Self::schedule_migration_retry(project_id, participant.clone());
+				Self::deposit_event(Event::<T>::MigrationStatusUpdated {
+					project_id,
+					account: participant,
+					status: MigrationStatus::Failed,
+				});
+			}
+
+			// * Emit events *
+			Self::deposit_event(Event::<T>::MigrationQueryTimedOut { project_id, query_id });
+		}
+
+		used_weight
+	}
+
+	/// Send again the failed migrations whose retry backoff has elapsed.
+	/// Goes through the retries by due block, stops before exceeding `max_weight`, and returns the weight used.
+	pub fn do_process_migration_retries(now: BlockNumberFor<T>, max_weight: Weight) -> Weight {
+		let db_weight = <T as frame_system::Config>::DbWeight::get();
+		// Reading and updating the next block to process
+		let mut used_weight = db_weight.reads_writes(1, 1);
+		if used_weight.any_gt(max_weight) {
+			return Weight::zero();
+		}
+		// Taking the retry from the schedule, checking the migration and the channel, and postponing it if needed
+		let check_weight = db_weight.reads_writes(4, 3);
+
+		// * Get variables *
+		let mut block = NextMigrationRetryBlock::<T>::get();
+		let mut checked_retries = Vec::new();
+		let mut due_retries = Vec::new();
+		let mut postponed_retries = Vec::new();
+		'blocks: while block <= now {
+			if used_weight.saturating_add(db_weight.reads(1)).any_gt(max_weight) {
+				break;
+			}
+			used_weight.saturating_accrue(db_weight.reads(1));
+			for (project_id, participant) in MigrationRetrySchedule::<T>::iter_key_prefix(block) {
+				let retry_weight = Call::<T>::migrate_one_participant { project_id, participant: participant.clone() }
+					.get_dispatch_info()
+					.weight;
+				if used_weight.saturating_add(check_weight).saturating_add(retry_weight).any_gt(max_weight) {
+					break 'blocks;
+				}
+				used_weight.saturating_accrue(check_weight);
+				checked_retries.push((block, project_id, participant.clone()));
+
+				// A retry might already have been sent manually by the user
+				let is_failed = matches!(
+					UserMigrations::<T>::get(project_id, participant.clone()),
+					Some((MigrationStatus::Failed, _))
+				);
+				let channel_open = ProjectsDetails::<T>::get(project_id)
+					.map(|details| details.hrmp_channel_status.is_open())
+					.unwrap_or(false);
+				if !is_failed {
+					continue;
+				}
+				if channel_open {
+					used_weight.saturating_accrue(retry_weight);
+					due_retries.push((project_id, participant));
+				} else {
+					postponed_retries.push((project_id, participant));
+				}
+			}
+			block.saturating_inc();
+		}
+
+		// * Update storage *
+		NextMigrationRetryBlock::<T>::put(block);
+		for (retry_block, project_id, participant) in checked_retries {
+			MigrationRetrySchedule::<T>::remove(retry_block, (project_id, participant));
+		}
+		// Retries are kept while the channel is closed, and checked again after the backoff
+		let next_check = now.saturating_add(T::MigrationRetryBackoff::get());
+		for (project_id, participant) in postponed_retries {
+			if let Some((retries, _)) = MigrationRetries::<T>::get(project_id, participant.clone()) {
+				MigrationRetries::<T>::insert(project_id, participant.clone(), (retries, next_check));
+				MigrationRetrySchedule::<T>::insert(next_check, (project_id, participant), ());
+			}
+		}
+
+		// * Process Data *
+		for (project_id, participant) in due_retries {
+			if let Err(e) = Self::do_migrate_one_participant(project_id, participant.clone()) {
+				log::trace!(target: "pallet_funding::migration", "Migration retry could not be sent: {:?}", e);
+				Self::schedule_migration_retry(project_id, participant);
+			}
+		}
+
+		used_weight
+	}
+
+	/// Schedule the next automatic retry of a failed migration, doubling the backoff on every retry.
+	/// Gives up once `MaxMigrationRetries` retries were scheduled.
+	pub(crate) fn schedule_migration_retry(project_id: ProjectId, participant: AccountIdOf<T>) {
+		let now = <frame_system::Pallet<T>>::block_number();
+		let retries = MigrationRetries::<T>::get(project_id, participant.clone())
+			.map(|(retries, _)| retries)
+			.unwrap_or_default()
+			.saturating_add(1);
+		Self::remove_migration_retry(project_id, &participant);
+
+		if retries > T::MaxMigrationRetries::get() {
+			Self::deposit_event(Event::<T>::MigrationRetriesExhausted { project_id, account: participant });
+			return;
+		}
+
+		let backoff = T::MigrationRetryBackoff::get().saturating_mul(2u32.saturating_pow(retries - 1).into());
+		let retry_block = now.saturating_add(backoff);
+		MigrationRetries::<T>::insert(project_id, participant.clone(), (retries, retry_block));
+		MigrationRetrySchedule::<T>::insert(retry_block, (project_id, participant.clone()), ());
+		Self::deposit_event(Event::<T>::MigrationRetryScheduled { project_id, account: participant, retry_block });
+	}
+
+	/// Keep [`MigrationQueriesPerParachain`] in sync when a query leaves the [`ActiveMigrationQueue`].
+	pub(crate) fn remove_migration_query(para_id: ParaId) {
+		MigrationQueriesPerParachain::<T>::mutate_exists(para_id, |queries| {
+			*queries = queries.and_then(|queries| queries.checked_sub(1)).filter(|queries| *queries > 0)
+		});
+	}
+
+	/// Remove the automatic retry of a migration, together with its entry in the retry schedule.
+	pub(crate) fn remove_migration_retry(project_id: ProjectId, participant: &AccountIdOf<T>) {
+		if let Some((_, retry_block)) = MigrationRetries::<T>::take(project_id, participant) {
+			MigrationRetrySchedule::<T>::remove(retry_block, (project_id, participant.clone()));
+		}
+	}
+}
+
+#[cfg(any(feature = "try-runtime", test))]
+impl<T: Config> Pallet<T> {
+	/// Contribution Token invariants. For every project:
+	///  - The CT supply on Polimec plus the CTs burned after their migration equals the total CTs minted.
+	pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+		for (project_id, minted) in MintedContributionTokens::<T>::iter() {
+			let supply = <T as Config>::ContributionTokenCurrency::total_issuance(project_id);
+			let migrated = MigratedContributionTokens::<T>::get(project_id);
+			ensure!(
+				supply.saturating_add(migrated) == minted,
+				"try_state checks: CT supply plus migrated CTs must equal the minted CTs"
+			);
+		}
 		Ok(())
 	}
 }
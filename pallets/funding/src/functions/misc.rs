@@ -1,5 +1,6 @@
 use super::*;
 use itertools::Itertools;
+use polimec_receiver::weights::WeightInfo as ReceiverWeightInfoT;
 
 // Helper functions
 // ATTENTION: if this is called directly, it will not be transactional
@@ -30,6 +31,81 @@ impl<T: Config> Pallet<T> {
 		return Err(T::MaxProjectsToUpdateInsertionAttempts::get());
 	}
 
+	/// Block at which the auction of a project in the `AuctionInitializePeriod` is automatically started.
+	/// A scheduled start is brought forward to the next block if it was missed. Schedules are checked to be
+	/// within the initialize period, so they never start the auction later than it would without one.
+	pub fn scheduled_auction_opening_block(
+		scheduled_start: Option<BlockNumberFor<T>>,
+		now: BlockNumberFor<T>,
+		auction_initialize_period_end_block: BlockNumberFor<T>,
+	) -> BlockNumberFor<T> {
+		match scheduled_start {
+			Some(block) => block.max(now.saturating_add(One::one())),
+			None => auction_initialize_period_end_block.saturating_add(One::one()),
+		}
+	}
+
+	/// The phase durations of a project. Falls back to the runtime defaults if the issuer did not choose any.
+	pub fn project_phase_durations(project_metadata: &ProjectMetadataOf<T>) -> PhaseDurations<BlockNumberFor<T>> {
+		project_metadata.phase_durations.unwrap_or_else(|| PhaseDurations {
+			evaluation: T::EvaluationDuration::get(),
+			auction_opening: T::AuctionOpeningDuration::get(),
+			auction_closing: T::AuctionClosingDuration::get(),
+			community_funding: T::CommunityFundingDuration::get(),
+			remainder_funding: T::RemainderFundingDuration::get(),
+			manual_acceptance: T::ManualAcceptanceDuration::get(),
+			success_to_settlement: T::SuccessToSettlementTime::get(),
+		})
+	}
+
+	/// Funding ratios of the target at or below which a project fails, at or below which its evaluators are
+	/// slashed, and below which the issuer has to decide on the outcome. Derived from the soft cap, which
+	/// defaults to 90% of the target, so the other thresholds default to 33% and 75%.
+	pub fn funding_outcome_thresholds(
+		project_metadata: &ProjectMetadataOf<T>,
+		funding_target: BalanceOf<T>,
+	) -> (Perquintill, Perquintill, Perquintill) {
+		let default_soft_cap_ratio = Perquintill::from_percent(90u64);
+		let Some(funding_caps) = project_metadata.funding_caps else {
+			return (Perquintill::from_percent(33u64), Perquintill::from_percent(75u64), default_soft_cap_ratio);
+		};
+		let soft_cap_ratio = Perquintill::from_rational(funding_caps.soft_cap, funding_target);
+		let scale_to_soft_cap = |default_ratio: Perquintill| default_ratio / default_soft_cap_ratio * soft_cap_ratio;
+		(
+			scale_to_soft_cap(Perquintill::from_percent(33u64)),
+			scale_to_soft_cap(Perquintill::from_percent(75u64)),
+			soft_cap_ratio,
+		)
+	}
+
+	/// Amount of contribution tokens that can still be bought at the weighted average price before the
+	/// project's hard cap is reached. `None` if the project has no hard cap.
+	pub fn tokens_until_hard_cap(
+		project_metadata: &ProjectMetadataOf<T>,
+		project_details: &ProjectDetailsOf<T>,
+	) -> Result<Option<BalanceOf<T>>, DispatchError> {
+		let Some(funding_caps) = project_metadata.funding_caps else { return Ok(None) };
+		let ct_usd_price = project_details.weighted_average_price.ok_or(Error::<T>::WapNotSet)?;
+		let usd_until_hard_cap = funding_caps.hard_cap.saturating_sub(project_details.funding_amount_reached_usd);
+		let tokens = ct_usd_price.reciprocal().ok_or(Error::<T>::BadMath)?.saturating_mul_int(usd_until_hard_cap);
+		Ok(Some(tokens))
+	}
+
+	/// Amount of contribution tokens that can still be bought, limited by the tokens left and the hard cap.
+	pub fn buyable_contribution_tokens(
+		project_metadata: &ProjectMetadataOf<T>,
+		project_details: &ProjectDetailsOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		let remaining_tokens = project_details.remaining_contribution_tokens;
+		let tokens_until_hard_cap = Self::tokens_until_hard_cap(project_metadata, project_details)?;
+		Ok(tokens_until_hard_cap.map_or(remaining_tokens, |tokens| tokens.min(remaining_tokens)))
+	}
+
+	/// Whether the project has a hard cap, and the funds raised leave no room for another contribution.
+	pub fn is_hard_cap_reached(project_metadata: &ProjectMetadataOf<T>, project_details: &ProjectDetailsOf<T>) -> bool {
+		matches!(Self::tokens_until_hard_cap(project_metadata, project_details), Ok(Some(tokens)) if tokens.is_zero())
+	}
+
 	pub fn create_bucket_from_metadata(metadata: &ProjectMetadataOf<T>) -> Result<BucketOf<T>, DispatchError> {
 		let auction_allocation_size = metadata.auction_round_allocation_percentage * metadata.total_allocation_size;
 		let bucket_delta_amount = Percent::from_percent(10) * auction_allocation_size;
@@ -155,8 +231,9 @@ impl<T: Config> Pallet<T> {
 		// Rejected bids were deleted in the previous block.
 		let accepted_bids = Bids::<T>::iter_prefix_values((project_id,)).collect_vec();
 		let project_account = Self::fund_account_id(project_id);
-		let plmc_price = T::PriceProvider::get_decimals_aware_price(PLMC_FOREIGN_ID, USD_DECIMALS, PLMC_DECIMALS)
-			.ok_or(Error::<T>::PriceNotFound)?;
+		let plmc_price =
+			T::BondingPriceProvider::get_decimals_aware_price(PLMC_FOREIGN_ID, USD_DECIMALS, PLMC_DECIMALS)
+				.ok_or(Error::<T>::PriceNotFound)?;
 
 		// Calculate the weighted price of the token for the next funding rounds, using winning bids.
 		// for example: if there are 3 winning bids,
@@ -212,11 +289,7 @@ impl<T: Config> Pallet<T> {
 				let new_ticket_size =
 					bid.final_ct_usd_price.checked_mul_int(bid.final_ct_amount).ok_or(Error::<T>::BadMath)?;
 
-				let funding_asset_id = bid.funding_asset.to_assethub_id();
-				let funding_asset_decimals = T::FundingCurrency::decimals(funding_asset_id);
-				let funding_asset_usd_price =
-					T::PriceProvider::get_decimals_aware_price(funding_asset_id, USD_DECIMALS, funding_asset_decimals)
-						.ok_or(Error::<T>::PriceNotFound)?;
+				let funding_asset_usd_price = Self::get_funding_asset_price(bid.funding_asset)?;
 
 				let funding_asset_amount_needed = funding_asset_usd_price
 					.reciprocal()
@@ -225,12 +298,11 @@ impl<T: Config> Pallet<T> {
 					.ok_or(Error::<T>::BadMath)?;
 
 				let amount_returned = bid.funding_asset_amount_locked.saturating_sub(funding_asset_amount_needed);
-				let asset_id = bid.funding_asset.to_assethub_id();
-				let min_amount = T::FundingCurrency::minimum_balance(asset_id);
+				let min_amount = Self::funding_asset_minimum_balance(bid.funding_asset);
 				// Transfers of less than min_amount return an error
 				if amount_returned > min_amount {
-					T::FundingCurrency::transfer(
-						bid.funding_asset.to_assethub_id(),
+					Self::transfer_funding_asset(
+						bid.funding_asset,
 						&project_account,
 						&bid.bidder,
 						amount_returned,
@@ -252,12 +324,7 @@ impl<T: Config> Pallet<T> {
 				let plmc_bond_returned = bid.plmc_bond.saturating_sub(plmc_bond_needed);
 				// If the free balance of a user is zero and we want to send him less than ED, it will fail.
 				if plmc_bond_returned > T::ExistentialDeposit::get() {
-					T::NativeCurrency::release(
-						&HoldReason::Participation(project_id).into(),
-						&bid.bidder,
-						plmc_bond_returned,
-						Precision::Exact,
-					)?;
+					Self::release_participation_bond(project_id, &bid.bidder, plmc_bond_returned, bid.multiplier)?;
 				}
 
 				bid.plmc_bond = plmc_bond_needed;
@@ -287,19 +354,14 @@ impl<T: Config> Pallet<T> {
 		project_id: ProjectId,
 		project_account: &AccountIdOf<T>,
 	) -> Result<(), DispatchError> {
-		T::FundingCurrency::transfer(
-			bid.funding_asset.to_assethub_id(),
+		Self::transfer_funding_asset(
+			bid.funding_asset,
 			project_account,
 			&bid.bidder,
 			bid.funding_asset_amount_locked,
 			Preservation::Expendable,
 		)?;
-		T::NativeCurrency::release(
-			&HoldReason::Participation(project_id).into(),
-			&bid.bidder,
-			bid.plmc_bond,
-			Precision::Exact,
-		)?;
+		Self::release_participation_bond(project_id, &bid.bidder, bid.plmc_bond, bid.multiplier)?;
 
 		// Refund bid should only be called when the bid is rejected, so this if let should
 		// always match.
@@ -351,6 +413,7 @@ impl<T: Config> Pallet<T> {
 		who: &T::AccountId,
 		project_id: ProjectId,
 		amount: BalanceOf<T>,
+		multiplier: MultiplierOf<T>,
 	) -> DispatchResult {
 		// Check if the user has already locked tokens in the evaluation period
 		let user_evaluations = Evaluations::<T>::iter_prefix_values((project_id, who));
@@ -372,30 +435,180 @@ impl<T: Config> Pallet<T> {
 			to_convert = to_convert.saturating_sub(converted)
 		}
 
+		// If the free balance is not enough, use the participant's stake for the rest. It stays staked until the
+		// bond is released, so only bonds released at settlement can use it.
+		let free_balance = T::NativeCurrency::reducible_balance(who, Preservation::Preserve, Fortitude::Polite);
+		if free_balance < to_convert && multiplier.into() == 1u8 {
+			let from_stake = to_convert.saturating_sub(free_balance);
+			T::StakedPlmc::use_stake(who, from_stake).map_err(|_| Error::<T>::ParticipantNotEnoughFunds)?;
+			StakedParticipationBonds::<T>::mutate(who, project_id, |bond| bond.saturating_accrue(from_stake));
+			to_convert = free_balance;
+		}
+
 		T::NativeCurrency::hold(&HoldReason::Participation(project_id).into(), who, to_convert)
 			.map_err(|_| Error::<T>::ParticipantNotEnoughFunds)?;
 
 		Ok(())
 	}
 
+	/// Hold again `amount` of PLMC that backed participation bonds of `who` as stake, and was released to their free
+	/// balance because it stopped being staked without them asking for it (e.g. their collator left).
+	pub fn hold_freed_staked_bonds(who: &AccountIdOf<T>, amount: BalanceOf<T>) {
+		let mut to_hold = amount;
+		for (project_id, staked_bond) in StakedParticipationBonds::<T>::iter_prefix(who).collect_vec() {
+			if to_hold.is_zero() {
+				break;
+			}
+			let held = to_hold.min(staked_bond);
+			if let Err(e) = T::NativeCurrency::hold(&HoldReason::Participation(project_id).into(), who, held) {
+				log::error!(target: "pallet_funding", "Could not hold freed stake of {:?}: {:?}", who, e);
+				continue;
+			}
+			if held == staked_bond {
+				StakedParticipationBonds::<T>::remove(who, project_id);
+			} else {
+				StakedParticipationBonds::<T>::insert(who, project_id, staked_bond.saturating_sub(held));
+			}
+			to_hold = to_hold.saturating_sub(held);
+		}
+	}
+
 	// TODO(216): use the hold interface of the fungibles::MutateHold once its implemented on pallet_assets.
 	pub fn try_funding_asset_hold(
 		who: &T::AccountId,
 		project_id: ProjectId,
 		amount: BalanceOf<T>,
-		asset_id: AssetIdOf<T>,
+		funding_asset: AcceptedFundingAsset,
 	) -> DispatchResult {
 		let fund_account = Self::fund_account_id(project_id);
 		// Why `Preservation::Expendable`?
 		// the min_balance of funding assets (e.g USDT) are low enough so we don't expect users to care about their balance being dusted.
 		// We do think the UX would be bad if they cannot use all of their available tokens.
 		// Specially since a new funding asset account can be easily created by increasing the provider reference
-		T::FundingCurrency::transfer(asset_id, who, &fund_account, amount, Preservation::Expendable)
+		// PLMC is the exception, since the account also holds the participation bond and needs to stay alive.
+		let preservation = if funding_asset.is_native() { Preservation::Preserve } else { Preservation::Expendable };
+		Self::transfer_funding_asset(funding_asset, who, &fund_account, amount, preservation)
 			.map_err(|_| Error::<T>::ParticipantNotEnoughFunds)?;
 
 		Ok(())
 	}
 
+	/// Transfer a funding asset between two accounts. PLMC is moved with `NativeCurrency`, while the rest
+	/// of the accepted assets are moved with `FundingCurrency`.
+	pub fn transfer_funding_asset(
+		funding_asset: AcceptedFundingAsset,
+		from: &AccountIdOf<T>,
+		to: &AccountIdOf<T>,
+		amount: BalanceOf<T>,
+		preservation: Preservation,
+	) -> DispatchResult {
+		if funding_asset.is_native() {
+			T::NativeCurrency::transfer(from, to, amount, preservation)?;
+		} else {
+			T::FundingCurrency::transfer(funding_asset.to_assethub_id(), from, to, amount, preservation)?;
+		}
+		Ok(())
+	}
+
+	/// The decimals of a funding asset. Used to get the decimals aware price from the `PriceProvider`.
+	pub fn funding_asset_decimals(funding_asset: AcceptedFundingAsset) -> u8 {
+		if funding_asset.is_native() {
+			PLMC_DECIMALS
+		} else {
+			T::FundingCurrency::decimals(funding_asset.to_assethub_id())
+		}
+	}
+
+	/// The minimum balance an account needs to hold of a funding asset.
+	pub fn funding_asset_minimum_balance(funding_asset: AcceptedFundingAsset) -> BalanceOf<T> {
+		if funding_asset.is_native() {
+			<T as pallet_balances::Config>::ExistentialDeposit::get()
+		} else {
+			T::FundingCurrency::minimum_balance(funding_asset.to_assethub_id())
+		}
+	}
+
+	/// Trip or reset the price circuit breaker of every participation asset. Participations with an asset are paused
+	/// while its price is stale, and for at least `PriceCircuitBreakerCooldown` blocks after it moved too much.
+	pub fn do_check_price_feeds(now: BlockNumberFor<T>) -> Weight {
+		let db_weight = <T as frame_system::Config>::DbWeight::get();
+		let mut used_weight = Weight::zero();
+
+		let funding_assets = [
+			AcceptedFundingAsset::USDT,
+			AcceptedFundingAsset::USDC,
+			AcceptedFundingAsset::DOT,
+			AcceptedFundingAsset::PLMC,
+		];
+		for funding_asset in funding_assets {
+			let asset_id = funding_asset.to_assethub_id();
+			let price = T::PriceProvider::get_price(asset_id);
+			let last_price = LastCheckedPrices::<T>::get(asset_id);
+			let paused = PausedPriceFeeds::<T>::get(asset_id);
+			used_weight.saturating_accrue(db_weight.reads(4));
+
+			let issue = match price {
+				Some(_) if !T::PriceProvider::is_price_fresh(asset_id) => Some(PriceFeedIssue::Stale),
+				Some(price) => match last_price {
+					Some(last_price) if !Self::is_within_price_deviation(last_price, price) =>
+						Some(PriceFeedIssue::ExcessiveDeviation),
+					_ => None,
+				},
+				None => Some(PriceFeedIssue::Stale),
+			};
+			if price.is_some() && price != last_price {
+				LastCheckedPrices::<T>::set(asset_id, price);
+				used_weight.saturating_accrue(db_weight.writes(1));
+			}
+
+			match (issue, paused) {
+				(Some(issue), _) => {
+					PausedPriceFeeds::<T>::insert(asset_id, (issue, now));
+					used_weight.saturating_accrue(db_weight.writes(1));
+					if paused.map(|(paused_issue, _)| paused_issue) != Some(issue) {
+						Self::deposit_event(Event::<T>::PriceCircuitBreakerTripped { asset_id, issue });
+					}
+				},
+				(None, Some((PriceFeedIssue::ExcessiveDeviation, tripped_at)))
+					if now < tripped_at.saturating_add(T::PriceCircuitBreakerCooldown::get()) => (),
+				(None, Some(_)) => {
+					PausedPriceFeeds::<T>::remove(asset_id);
+					used_weight.saturating_accrue(db_weight.writes(1));
+					Self::deposit_event(Event::<T>::PriceCircuitBreakerReset { asset_id });
+				},
+				(None, None) => (),
+			}
+		}
+
+		used_weight
+	}
+
+	fn is_within_price_deviation(last_price: PriceOf<T>, price: PriceOf<T>) -> bool {
+		let max_deviation =
+			PriceOf::<T>::saturating_from_rational(T::PriceCircuitBreakerDeviation::get().deconstruct(), 100u8);
+		let max_change = last_price.saturating_mul(max_deviation);
+		price.max(last_price).saturating_sub(price.min(last_price)) <= max_change
+	}
+
+	/// New participations need trusted prices of PLMC, for the bond, and of the asset they pay with.
+	pub fn ensure_price_feeds_active(funding_asset: AcceptedFundingAsset) -> DispatchResult {
+		for asset_id in [PLMC_FOREIGN_ID, funding_asset.to_assethub_id()] {
+			ensure!(!PausedPriceFeeds::<T>::contains_key(asset_id), Error::<T>::PriceFeedPaused);
+		}
+		Ok(())
+	}
+
+	/// Get the decimals aware USD price of a funding asset.
+	pub fn get_funding_asset_price(funding_asset: AcceptedFundingAsset) -> Result<PriceOf<T>, DispatchError> {
+		let funding_asset_decimals = Self::funding_asset_decimals(funding_asset);
+		T::BondingPriceProvider::get_decimals_aware_price(
+			funding_asset.to_assethub_id(),
+			USD_DECIMALS,
+			funding_asset_decimals,
+		)
+		.ok_or(Error::<T>::PriceNotFound.into())
+	}
+
 	/// Calculate the total fees based on the funding reached.
 	pub fn calculate_fees(funding_reached: BalanceOf<T>) -> Perquintill {
 		let total_fee = Self::compute_total_fee_from_brackets(funding_reached);
@@ -562,26 +775,34 @@ impl<T: Config> Pallet<T> {
 	pub fn migrations_per_xcm_message_allowed() -> u32 {
 		const MAX_WEIGHT: Weight = Weight::from_parts(20_000_000_000, 1_000_000);
 
-		let one_migration_bytes = (0u128, 0u64).encode().len() as u32;
+		let one_migration_bytes = Migration::new(
+			MigrationOrigin { user: [0u8; 32], id: 0, participation_type: ParticipationType::Evaluation },
+			MigrationInfo { contribution_token_amount: 0, vesting_time: 0, start_block: Some(0), cliff: Some(0) },
+		)
+		.encode()
+		.len() as u32;
 
-		// our encoded call starts with pallet index 51, and call index 0
-		let mut encoded_call = vec![51u8, 0];
-		let encoded_first_param = [0u8; 32].encode();
-		let encoded_second_param = Vec::<MigrationInfo>::new().encode();
-		// we append the encoded parameters, with our migrations vec being empty for now
-		encoded_call.extend_from_slice(encoded_first_param.as_slice());
-		encoded_call.extend_from_slice(encoded_second_param.as_slice());
+		// our encoded call starts with the pallet index and the call index, one byte each
+		let mut encoded_call = vec![0u8, 0];
+		// we append the encoded parameter, with our migrations vec being empty for now
+		encoded_call.extend_from_slice(Migrations::new().encode().as_slice());
 
+		// The message paying for its execution is the biggest one we can send
+		let fees: MultiAsset = (MultiLocation::parent(), u128::MAX).into();
 		let base_xcm_message: Xcm<()> = Xcm(vec![
-			UnpaidExecution { weight_limit: WeightLimit::Unlimited, check_origin: None },
+			WithdrawAsset(fees.clone().into()),
+			BuyExecution { fees, weight_limit: WeightLimit::Limited(MAX_WEIGHT) },
 			Transact { origin_kind: OriginKind::Native, require_weight_at_most: MAX_WEIGHT, call: encoded_call.into() },
 			ReportTransactStatus(QueryResponseInfo {
 				destination: Parachain(3344).into(),
 				query_id: 0,
 				max_weight: MAX_WEIGHT,
 			}),
+			RefundSurplus,
+			DepositAsset { assets: Wild(All), beneficiary: Parachain(3344).into() },
 		]);
-		let xcm_size = base_xcm_message.encode().len();
+		// The length prefix of the migrations vec can grow up to 4 bytes more than the empty one
+		let xcm_size = base_xcm_message.encode().len().saturating_add(4);
 
 		let available_bytes_for_migration_per_message =
 			T::RequiredMaxMessageSize::get().saturating_sub(xcm_size as u32);
@@ -597,24 +818,161 @@ impl<T: Config> Pallet<T> {
 	}
 
 	pub fn construct_migration_xcm_message(
-		migrations: BoundedVec<Migration, MaxParticipationsPerUser<T>>,
+		call_indices: ReceiverCallIndices,
+		migrations: Migrations,
+		query_id: QueryId,
+		execution_fee: Option<u128>,
+	) -> Xcm<()> {
+		const MAX_RESPONSE_WEIGHT: Weight = Weight::from_parts(700_000_000, 10_000);
+
+		let call_weight =
+			<ReceiverWeightInfo<T> as ReceiverWeightInfoT>::execute_migrations(migrations.len() as u32);
+		let mut encoded_call = vec![call_indices.pallet, call_indices.call];
+		// migrations can contain at most `migrations_per_xcm_message_allowed` migrations, so the
+		// encoded call always fits in a message of `RequiredMaxMessageSize`.
+		if call_indices.call == POLIMEC_RECEIVER_EXECUTE_LEGACY_MIGRATIONS_CALL_INDEX {
+			let legacy_migrations = migrations.inner().into_iter().map(LegacyMigration::from).collect::<Vec<_>>();
+			encoded_call.extend_from_slice(legacy_migrations.encode().as_slice());
+		} else {
+			encoded_call.extend_from_slice(migrations.encode().as_slice());
+		}
+		let polimec_location: MultiLocation = ParentThen(X1(Parachain(POLIMEC_PARA_ID))).into();
+		let transact_instructions = vec![
+			Transact {
+				origin_kind: OriginKind::Native,
+				require_weight_at_most: call_weight,
+				call: encoded_call.into(),
+			},
+			ReportTransactStatus(QueryResponseInfo {
+				destination: polimec_location,
+				query_id,
+				max_weight: MAX_RESPONSE_WEIGHT,
+			}),
+		];
+
+		match execution_fee {
+			// DOT is withdrawn from Polimec's sovereign account on the project's chain, and the leftover returned to it
+			Some(fee) => {
+				let fees: MultiAsset = (MultiLocation::parent(), fee).into();
+				let mut instructions = vec![
+					WithdrawAsset(fees.clone().into()),
+					BuyExecution { fees, weight_limit: WeightLimit::Unlimited },
+				];
+				instructions.extend(transact_instructions);
+				instructions.extend(vec![
+					RefundSurplus,
+					DepositAsset { assets: Wild(All), beneficiary: polimec_location },
+				]);
+				Xcm(instructions)
+			},
+			None => {
+				let mut instructions =
+					vec![UnpaidExecution { weight_limit: WeightLimit::Unlimited, check_origin: None }];
+				instructions.extend(transact_instructions);
+				Xcm(instructions)
+			},
+		}
+	}
+
+	/// Leaf of the off-chain migrations Merkle tree. Every migration is a leaf, so it can be claimed on its own.
+	pub fn migration_merkle_leaf(migration: &Migration) -> H256 {
+		H256(sp_io::hashing::keccak_256(&migration.encode()))
+	}
+
+	/// Root of a binary Merkle tree over the sorted leaves. Each pair of nodes is sorted before hashing, as most
+	/// verifiers on EVM chains expect, and an unpaired node is carried over to the next level.
+	pub fn merkle_root(mut leaves: Vec<H256>) -> H256 {
+		leaves.sort();
+		let mut level = leaves;
+		while level.len() > 1 {
+			level = Self::next_merkle_level(&level);
+		}
+		level.first().copied().unwrap_or_default()
+	}
+
+	/// Sibling hashes needed to prove that `leaf` is part of the tree built by [`Self::merkle_root`].
+	pub fn merkle_proof(mut leaves: Vec<H256>, leaf: H256) -> Option<Vec<H256>> {
+		leaves.sort();
+		let mut index = leaves.iter().position(|node| *node == leaf)?;
+		let mut level = leaves;
+		let mut proof = Vec::new();
+		while level.len() > 1 {
+			if let Some(sibling) = level.get(index ^ 1) {
+				proof.push(*sibling);
+			}
+			index /= 2;
+			level = Self::next_merkle_level(&level);
+		}
+		Some(proof)
+	}
+
+	pub fn verify_merkle_proof(root: H256, leaf: H256, proof: &[H256]) -> bool {
+		proof.iter().fold(leaf, |node, sibling| Self::hash_merkle_pair(node, *sibling)) == root
+	}
+
+	fn next_merkle_level(level: &[H256]) -> Vec<H256> {
+		level
+			.chunks(2)
+			.map(|pair| match pair {
+				[left, right] => Self::hash_merkle_pair(*left, *right),
+				_ => pair[0],
+			})
+			.collect()
+	}
+
+	fn hash_merkle_pair(left: H256, right: H256) -> H256 {
+		let (first, second) = if left <= right { (left, right) } else { (right, left) };
+		H256(sp_io::hashing::keccak_256(&[first.as_bytes(), second.as_bytes()].concat()))
+	}
+
+	/// Where to send the migrations if the pallet reported by the readiness check is a compatible
+	/// `polimec-receiver`. Projects are free to install it at any pallet index.
+	pub fn receiver_call_indices(pallet_info: &PalletInfo) -> Option<ReceiverCallIndices> {
+		Self::check_receiver_compatibility(pallet_info).ok()?;
+		let pallet = u8::try_from(pallet_info.index).ok()?;
+		Some(ReceiverCallIndices { pallet, call: POLIMEC_RECEIVER_EXECUTE_MIGRATIONS_CALL_INDEX })
+	}
+
+	/// Newer minor and patch versions of the receiver keep the calls Polimec sends, so they are accepted.
+	pub fn check_receiver_compatibility(pallet_info: &PalletInfo) -> Result<(), ReadinessFailure> {
+		let expected_info = T::PolimecReceiverInfo::get();
+		ensure!(
+			pallet_info.name == expected_info.name && pallet_info.module_name == expected_info.module_name,
+			ReadinessFailure::ReceiverMismatch
+		);
+		let is_compatible_version = pallet_info.major == expected_info.major &&
+			(pallet_info.minor, pallet_info.patch) >= (expected_info.minor, expected_info.patch);
+		ensure!(
+			is_compatible_version,
+			ReadinessFailure::ReceiverVersionIncompatible {
+				major: pallet_info.major,
+				minor: pallet_info.minor,
+				patch: pallet_info.patch,
+			}
+		);
+		Ok(())
+	}
+
+	/// Ask the receiver to check that the token of the project's chain has the decimals and symbol of the CT. The
+	/// outcome of the call is the response to `query_id`.
+	pub fn construct_token_metadata_check_xcm_message(
+		pallet_index: u8,
+		token_information: &CurrencyMetadata<BoundedVec<u8, StringLimitOf<T>>>,
 		query_id: QueryId,
 	) -> Xcm<()> {
-		// TODO: adjust this as benchmarks for polimec-receiver are written
-		const MAX_WEIGHT: Weight = Weight::from_parts(10_000, 0);
 		const MAX_RESPONSE_WEIGHT: Weight = Weight::from_parts(700_000_000, 10_000);
-		// const MAX_WEIGHT: Weight = Weight::from_parts(100_003_000_000_000, 10_000_196_608);
-		let _polimec_receiver_info = T::PolimecReceiverInfo::get();
-		let migrations_item = Migrations::from(migrations.into());
-
-		let mut encoded_call = vec![51u8, 0];
-		// migrations_item can contain a Maximum of MaxParticipationsPerUser migrations which
-		// is 48. So we know that there is an upper limit to this encoded call, namely 48 *
-		// Migration encode size.
-		encoded_call.extend_from_slice(migrations_item.encode().as_slice());
+
+		let call_weight = <ReceiverWeightInfo<T> as ReceiverWeightInfoT>::check_token_metadata();
+		let mut encoded_call = vec![pallet_index, POLIMEC_RECEIVER_CHECK_TOKEN_METADATA_CALL_INDEX];
+		encoded_call.extend_from_slice(token_information.decimals.encode().as_slice());
+		encoded_call.extend_from_slice(token_information.symbol.encode().as_slice());
 		Xcm(vec![
 			UnpaidExecution { weight_limit: WeightLimit::Unlimited, check_origin: None },
-			Transact { origin_kind: OriginKind::Native, require_weight_at_most: MAX_WEIGHT, call: encoded_call.into() },
+			Transact {
+				origin_kind: OriginKind::Native,
+				require_weight_at_most: call_weight,
+				call: encoded_call.into(),
+			},
 			ReportTransactStatus(QueryResponseInfo {
 				destination: ParentThen(X1(Parachain(POLIMEC_PARA_ID))).into(),
 				query_id,
@@ -638,7 +996,20 @@ impl<T: Config> Pallet<T> {
 			MigrationStatus::Failed if matches!(current_status, MigrationStatus::Sent(_)) => status,
 			_ => return Err(Error::<T>::NotAllowed.into()),
 		};
+		if status == MigrationStatus::Failed {
+			PendingMigrations::<T>::insert(project_id, user.clone(), ());
+		} else {
+			PendingMigrations::<T>::remove(project_id, user.clone());
+		}
 		UserMigrations::<T>::insert(project_id, user, (status, migrations));
 		Ok(())
 	}
+
+	/// Make the migrations of a participant available for batch migrations, once all its participations are settled.
+	pub(crate) fn add_pending_migrations_if_settled(project_id: ProjectId, user: &AccountIdOf<T>) {
+		let not_started = matches!(UserMigrations::<T>::get(project_id, user), Some((MigrationStatus::NotStarted, _)));
+		if not_started && Self::user_has_no_participations(project_id, user.clone()) {
+			PendingMigrations::<T>::insert(project_id, user, ());
+		}
+	}
 }
@@ -1,12 +1,14 @@
 use super::*;
 
 use crate::{
-	traits::{BondingRequirementCalculation, ProvideAssetPrice, VestingDurationCalculation},
+	traits::{BondingRequirementCalculation, ProvideAssetPrice, ProvideStakedPlmc, VestingDurationCalculation},
 	ProjectStatus::FundingSuccessful,
 };
 use core::ops::Not;
 use frame_support::{
-	dispatch::{DispatchErrorWithPostInfo, DispatchResult, DispatchResultWithPostInfo, PostDispatchInfo},
+	dispatch::{
+		DispatchErrorWithPostInfo, DispatchResult, DispatchResultWithPostInfo, GetDispatchInfo, PostDispatchInfo,
+	},
 	ensure,
 	pallet_prelude::*,
 	traits::{
@@ -15,7 +17,7 @@ use frame_support::{
 			metadata::{Inspect as MetadataInspect, Mutate as MetadataMutate},
 			Create, Inspect as FungibleInspect, Mutate as FungiblesMutate,
 		},
-		tokens::{Precision, Preservation},
+		tokens::{Fortitude, Precision, Preservation},
 		Get,
 	},
 	transactional,
@@ -23,7 +25,7 @@ use frame_support::{
 use frame_system::pallet_prelude::BlockNumberFor;
 use polimec_common::{
 	credentials::{Did, InvestorType},
-	migration_types::{MigrationInfo, Migrations},
+	migration_types::{MigrationInfo, MigrationOrigin, Migrations, ParticipationType},
 	USD_DECIMALS,
 };
 use sp_arithmetic::{
@@ -33,6 +35,13 @@ use sp_arithmetic::{
 use sp_runtime::traits::Convert;
 
 const POLIMEC_PARA_ID: u32 = 3344u32;
+// Call index of `execute_migrations`, as declared by the `polimec-receiver` pallet. Takes migrations without vesting
+// start nor cliff, and is only used for receivers installed before `execute_scheduled_migrations` existed.
+const POLIMEC_RECEIVER_EXECUTE_LEGACY_MIGRATIONS_CALL_INDEX: u8 = 0u8;
+// Call index of `execute_scheduled_migrations`, as declared by the `polimec-receiver` pallet
+const POLIMEC_RECEIVER_EXECUTE_MIGRATIONS_CALL_INDEX: u8 = 2u8;
+// Call index of `check_token_metadata`, as declared by the `polimec-receiver` pallet
+const POLIMEC_RECEIVER_CHECK_TOKEN_METADATA_CALL_INDEX: u8 = 1u8;
 const QUERY_RESPONSE_TIME_WINDOW_BLOCKS: u32 = 20u32;
 #[path = "1_application.rs"]
 mod application;
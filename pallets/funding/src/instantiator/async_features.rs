@@ -760,17 +760,16 @@ pub async fn async_create_project_at<
 	block_orchestrator: Arc<BlockOrchestrator<T, AllPalletsWithoutSystem, RuntimeEvent>>,
 	test_project_params: TestProjectParams<T>,
 ) -> ProjectId {
+	let phase_durations = Pallet::<T>::project_phase_durations(&test_project_params.metadata);
 	let time_to_new_project: BlockNumberFor<T> = Zero::zero();
 	let time_to_evaluation: BlockNumberFor<T> = time_to_new_project + Zero::zero();
 	// we immediately start the auction, so we don't wait for T::AuctionInitializePeriodDuration.
-	let time_to_auction: BlockNumberFor<T> = time_to_evaluation + <T as Config>::EvaluationDuration::get();
+	let time_to_auction: BlockNumberFor<T> = time_to_evaluation + phase_durations.evaluation;
 	let wap_calculation_duration: BlockNumberFor<T> = 2u32.into();
-	let time_to_community: BlockNumberFor<T> = time_to_auction +
-		<T as Config>::AuctionOpeningDuration::get() +
-		<T as Config>::AuctionClosingDuration::get() +
-		wap_calculation_duration;
-	let time_to_remainder: BlockNumberFor<T> = time_to_community + <T as Config>::CommunityFundingDuration::get();
-	let time_to_finish: BlockNumberFor<T> = time_to_remainder + <T as Config>::RemainderFundingDuration::get();
+	let time_to_community: BlockNumberFor<T> =
+		time_to_auction + phase_durations.auction_opening + phase_durations.auction_closing + wap_calculation_duration;
+	let time_to_remainder: BlockNumberFor<T> = time_to_community + phase_durations.community_funding;
+	let time_to_finish: BlockNumberFor<T> = time_to_remainder + phase_durations.remainder_funding;
 
 	let mut inst = mutex_inst.lock().await;
 	let now = inst.current_block();
@@ -20,7 +20,7 @@ impl<
 		evaluations: Vec<UserToUSDBalance<T>>,
 	) -> Vec<UserToPLMCBalance<T>> {
 		let plmc_usd_price = self.execute(|| {
-			T::PriceProvider::get_decimals_aware_price(PLMC_FOREIGN_ID, USD_DECIMALS, PLMC_DECIMALS).unwrap()
+			T::BondingPriceProvider::get_decimals_aware_price(PLMC_FOREIGN_ID, USD_DECIMALS, PLMC_DECIMALS).unwrap()
 		});
 
 		let mut output = Vec::new();
@@ -70,7 +70,7 @@ impl<
 		ct_price: PriceOf<T>,
 	) -> Vec<UserToPLMCBalance<T>> {
 		let plmc_usd_price = self.execute(|| {
-			T::PriceProvider::get_decimals_aware_price(PLMC_FOREIGN_ID, USD_DECIMALS, PLMC_DECIMALS).unwrap()
+			T::BondingPriceProvider::get_decimals_aware_price(PLMC_FOREIGN_ID, USD_DECIMALS, PLMC_DECIMALS).unwrap()
 		});
 
 		let mut output = Vec::new();
@@ -92,7 +92,7 @@ impl<
 	) -> Vec<UserToPLMCBalance<T>> {
 		let mut output = Vec::new();
 		let plmc_usd_price = self.execute(|| {
-			T::PriceProvider::get_decimals_aware_price(PLMC_FOREIGN_ID, USD_DECIMALS, PLMC_DECIMALS).unwrap()
+			T::BondingPriceProvider::get_decimals_aware_price(PLMC_FOREIGN_ID, USD_DECIMALS, PLMC_DECIMALS).unwrap()
 		});
 
 		for (bid, price) in self.get_actual_price_charged_for_bucketed_bids(bids, project_metadata, maybe_bucket) {
@@ -123,7 +123,7 @@ impl<
 		grouped_by_price_bids.reverse();
 
 		let plmc_usd_price = self.execute(|| {
-			T::PriceProvider::get_decimals_aware_price(PLMC_FOREIGN_ID, USD_DECIMALS, PLMC_DECIMALS).unwrap()
+			T::BondingPriceProvider::get_decimals_aware_price(PLMC_FOREIGN_ID, USD_DECIMALS, PLMC_DECIMALS).unwrap()
 		});
 		let mut remaining_cts =
 			project_metadata.auction_round_allocation_percentage * project_metadata.total_allocation_size;
@@ -185,10 +185,14 @@ impl<
 		let mut output = Vec::new();
 		for bid in bids {
 			let funding_asset_id = bid.asset.to_assethub_id();
-			let funding_asset_decimals = self.execute(|| T::FundingCurrency::decimals(funding_asset_id));
+			let funding_asset_decimals = self.execute(|| Pallet::<T>::funding_asset_decimals(bid.asset));
 			let funding_asset_usd_price = self.execute(|| {
-				T::PriceProvider::get_decimals_aware_price(funding_asset_id, USD_DECIMALS, funding_asset_decimals)
-					.unwrap()
+				T::BondingPriceProvider::get_decimals_aware_price(
+					funding_asset_id,
+					USD_DECIMALS,
+					funding_asset_decimals,
+				)
+				.unwrap()
 			});
 			let usd_ticket_size = ct_price.saturating_mul_int(bid.amount);
 			let funding_asset_spent = funding_asset_usd_price.reciprocal().unwrap().saturating_mul_int(usd_ticket_size);
@@ -208,11 +212,15 @@ impl<
 
 		for (bid, price) in self.get_actual_price_charged_for_bucketed_bids(bids, project_metadata, maybe_bucket) {
 			let funding_asset_id = bid.asset.to_assethub_id();
-			let funding_asset_decimals = self.execute(|| T::FundingCurrency::decimals(funding_asset_id));
+			let funding_asset_decimals = self.execute(|| Pallet::<T>::funding_asset_decimals(bid.asset));
 			let funding_asset_usd_price = self.execute(|| {
-				T::PriceProvider::get_decimals_aware_price(funding_asset_id, USD_DECIMALS, funding_asset_decimals)
-					.ok_or(Error::<T>::PriceNotFound)
-					.unwrap()
+				T::BondingPriceProvider::get_decimals_aware_price(
+					funding_asset_id,
+					USD_DECIMALS,
+					funding_asset_decimals,
+				)
+				.ok_or(Error::<T>::PriceNotFound)
+				.unwrap()
 			});
 			let usd_ticket_size = price.saturating_mul_int(bid.amount);
 			let funding_asset_spent = funding_asset_usd_price.reciprocal().unwrap().saturating_mul_int(usd_ticket_size);
@@ -249,11 +257,15 @@ impl<
 		for (price_charged, bids) in grouped_by_price_bids {
 			for bid in bids {
 				let funding_asset_id = bid.asset.to_assethub_id();
-				let funding_asset_decimals = self.execute(|| T::FundingCurrency::decimals(funding_asset_id));
+				let funding_asset_decimals = self.execute(|| Pallet::<T>::funding_asset_decimals(bid.asset));
 				let funding_asset_usd_price = self.execute(|| {
-					T::PriceProvider::get_decimals_aware_price(funding_asset_id, USD_DECIMALS, funding_asset_decimals)
-						.ok_or(Error::<T>::PriceNotFound)
-						.unwrap()
+					T::BondingPriceProvider::get_decimals_aware_price(
+						funding_asset_id,
+						USD_DECIMALS,
+						funding_asset_decimals,
+					)
+					.ok_or(Error::<T>::PriceNotFound)
+					.unwrap()
 				});
 				let charged_usd_ticket_size = price_charged.saturating_mul_int(bid.amount);
 				let charged_usd_bond =
@@ -343,7 +355,7 @@ impl<
 		token_usd_price: PriceOf<T>,
 	) -> Vec<UserToPLMCBalance<T>> {
 		let plmc_usd_price = self.execute(|| {
-			T::PriceProvider::get_decimals_aware_price(PLMC_FOREIGN_ID, USD_DECIMALS, PLMC_DECIMALS).unwrap()
+			T::BondingPriceProvider::get_decimals_aware_price(PLMC_FOREIGN_ID, USD_DECIMALS, PLMC_DECIMALS).unwrap()
 		});
 
 		let mut output = Vec::new();
@@ -406,11 +418,15 @@ impl<
 		let mut output = Vec::new();
 		for cont in contributions {
 			let funding_asset_id = cont.asset.to_assethub_id();
-			let funding_asset_decimals = self.execute(|| T::FundingCurrency::decimals(funding_asset_id));
+			let funding_asset_decimals = self.execute(|| Pallet::<T>::funding_asset_decimals(cont.asset));
 			let funding_asset_usd_price = self.execute(|| {
-				T::PriceProvider::get_decimals_aware_price(funding_asset_id, USD_DECIMALS, funding_asset_decimals)
-					.ok_or(Error::<T>::PriceNotFound)
-					.unwrap()
+				T::BondingPriceProvider::get_decimals_aware_price(
+					funding_asset_id,
+					USD_DECIMALS,
+					funding_asset_decimals,
+				)
+				.ok_or(Error::<T>::PriceNotFound)
+				.unwrap()
 			});
 			let usd_ticket_size = token_usd_price.saturating_mul_int(cont.amount);
 			let funding_asset_spent = funding_asset_usd_price.reciprocal().unwrap().saturating_mul_int(usd_ticket_size);
@@ -300,6 +300,8 @@ impl<
 				project_to_polimec: crate::ChannelStatus::Closed,
 				polimec_to_project: crate::ChannelStatus::Closed,
 			},
+			migration_type: MigrationType::Pallet,
+			receiver_call_indices: None,
 		};
 		assert_eq!(metadata, expected_metadata);
 		assert_eq!(details, expected_details);
@@ -743,7 +745,7 @@ impl<
 		self.execute(|| Contributions::<T>::iter_prefix_values((project_id,)).collect())
 	}
 
-	// Used to check all the USDT/USDC/DOT was paid to the issuer funding account
+	// Used to check all the USDT/USDC/DOT/PLMC was paid to the issuer funding account
 	pub fn assert_total_funding_paid_out(
 		&mut self,
 		project_id: ProjectId,
@@ -754,12 +756,14 @@ impl<
 		let mut total_expected_dot: BalanceOf<T> = Zero::zero();
 		let mut total_expected_usdt: BalanceOf<T> = Zero::zero();
 		let mut total_expected_usdc: BalanceOf<T> = Zero::zero();
+		let mut total_expected_plmc: BalanceOf<T> = Zero::zero();
 
 		for bid in bids {
 			match bid.funding_asset {
 				AcceptedFundingAsset::DOT => total_expected_dot += bid.funding_asset_amount_locked,
 				AcceptedFundingAsset::USDT => total_expected_usdt += bid.funding_asset_amount_locked,
 				AcceptedFundingAsset::USDC => total_expected_usdc += bid.funding_asset_amount_locked,
+				AcceptedFundingAsset::PLMC => total_expected_plmc += bid.funding_asset_amount_locked,
 			}
 		}
 
@@ -768,6 +772,7 @@ impl<
 				AcceptedFundingAsset::DOT => total_expected_dot += contribution.funding_asset_amount,
 				AcceptedFundingAsset::USDT => total_expected_usdt += contribution.funding_asset_amount,
 				AcceptedFundingAsset::USDC => total_expected_usdc += contribution.funding_asset_amount,
+				AcceptedFundingAsset::PLMC => total_expected_plmc += contribution.funding_asset_amount,
 			}
 		}
 
@@ -786,10 +791,14 @@ impl<
 			vec![project_metadata.funding_destination_account.clone()],
 		)[0]
 		.asset_amount;
+		let total_stored_plmc =
+			self.get_free_plmc_balances_for(vec![project_metadata.funding_destination_account.clone()])[0].plmc_amount;
 
 		assert_eq!(total_expected_dot, total_stored_dot, "DOT amount is incorrect");
 		assert_eq!(total_expected_usdt, total_stored_usdt, "USDT amount is incorrect");
 		assert_eq!(total_expected_usdc, total_stored_usdc, "USDC amount is incorrect");
+		// The destination account might hold PLMC from other sources, so we can only check a lower bound.
+		assert!(total_stored_plmc >= total_expected_plmc, "PLMC amount is incorrect");
 	}
 
 	// Used to check if all evaluations are settled correctly. We cannot check amount of
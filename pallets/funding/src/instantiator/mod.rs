@@ -22,10 +22,7 @@ use frame_support::{
 	pallet_prelude::*,
 	traits::{
 		fungible::{Inspect as FungibleInspect, InspectHold as FungibleInspectHold, Mutate as FungibleMutate},
-		fungibles::{
-			metadata::Inspect as MetadataInspect, roles::Inspect as RolesInspect, Inspect as FungiblesInspect,
-			Mutate as FungiblesMutate,
-		},
+		fungibles::{roles::Inspect as RolesInspect, Inspect as FungiblesInspect, Mutate as FungiblesMutate},
 		AccountTouch, Get, OnFinalize, OnIdle, OnInitialize,
 	},
 	weights::Weight,
@@ -66,6 +66,7 @@
 //! * [`edit_metadata`](Pallet::edit_project) : Submit a new Hash of the project metadata.
 //! * [`start_evaluation`](Pallet::start_evaluation) : Start the Evaluation round of a project.
 //! * [`start_auction`](Pallet::start_auction) : Start the auction round of a project.
+//! * [`schedule_auction_start`](Pallet::schedule_auction_start) : Choose the block at which the auction starts automatically.
 //! * [`bond_evaluation`](Pallet::evaluate) : Bond PLMC on a project in the evaluation stage. A sort of "bet" that you think the project will be funded
 //! * [`failed_evaluation_unbond_for`](Pallet::failed_evaluation_unbond_for) : Unbond the PLMC bonded on a project's evaluation round for any user, if the project failed the evaluation.
 //! * [`bid`](Pallet::bid) : Perform a bid during the auction round.
@@ -78,6 +79,9 @@
 //!   : Mint the contribution tokens for a user who participated in the auction round,
 //!   based on their vesting schedule.
 //! * [`vested_contribution_token_purchase_mint_for`](Pallet::vested_contribution_token_purchase_mint_for) : Mint the contribution tokens for a user who participated in the Community or Remainder Round, based on their vesting schedule.
+//! * [`start_offchain_migration`](Pallet::start_offchain_migration) : Publish the Merkle root of all migrations, for projects launching on a non-parachain destination.
+//! * [`consent_offchain_migration`](Pallet::consent_offchain_migration) : Agree, as a participant, that the issuer confirms your off-chain migration.
+//! * [`confirm_offchain_migration`](Pallet::confirm_offchain_migration) : Attest, as the issuer, that a participant claimed their CTs on the destination chain.
 //!
 //! ### Storage Items
 //! * [`NextProjectId`] : Increasing counter to get the next id to assign to a project.
@@ -100,6 +104,7 @@
 //! | `edit_metadata`                               | X      |                 |                       |                        |
 //! | `start_evaluation`                            | X      |                 |                       |                        |
 //! | `start_auction`                               | X      |                 |                       |                        |
+//! | `schedule_auction_start`                      | X      |                 |                       |                        |
 //! | `bond_evaluation`                             |        | X               | X                     | X                      |
 //! | `failed_evaluation_unbond_for`                |        | X               | X                     | X                      |
 //! | `bid`                                         |        |                 | X                     | X                      |
@@ -121,19 +126,20 @@ pub use crate::weights::WeightInfo;
 use frame_support::{
 	traits::{
 		tokens::{fungible, fungibles, Balance},
-		AccountTouch, ContainsPair, Randomness,
+		AccountTouch, Contains, ContainsPair, Randomness,
 	},
 	BoundedVec, PalletId,
 };
 use frame_system::pallet_prelude::BlockNumberFor;
 pub use pallet::*;
-use pallet_xcm::ensure_response;
+use pallet_xcm::{ensure_response, ensure_xcm};
 use polimec_common::{
 	credentials::{Cid, Did, EnsureOriginWithCredentials, InvestorType, UntrustedToken},
 	migration_types::*,
 };
 use polkadot_parachain_primitives::primitives::Id as ParaId;
 use sp_arithmetic::traits::{One, Saturating};
+use sp_core::H256;
 use sp_runtime::{traits::AccountIdConversion, FixedPointNumber, FixedPointOperand, FixedU128};
 use sp_std::{marker::PhantomData, prelude::*};
 pub use types::*;
@@ -141,6 +147,7 @@ use xcm::v3::{opaque::Instruction, prelude::*, SendXcm};
 
 #[cfg(test)]
 pub mod mock;
+pub mod runtime_api;
 pub mod storage_migrations;
 pub mod types;
 pub mod weights;
@@ -170,18 +177,22 @@ pub type EvaluatorsOutcomeOf<T> = EvaluatorsOutcome<BalanceOf<T>>;
 
 pub type TicketSizeOf<T> = TicketSize<BalanceOf<T>>;
 pub type ProjectMetadataOf<T> =
-	ProjectMetadata<BoundedVec<u8, StringLimitOf<T>>, BalanceOf<T>, PriceOf<T>, AccountIdOf<T>, Cid>;
+	ProjectMetadata<BoundedVec<u8, StringLimitOf<T>>, BalanceOf<T>, PriceOf<T>, AccountIdOf<T>, Cid, BlockNumberFor<T>>;
 pub type ProjectDetailsOf<T> =
 	ProjectDetails<AccountIdOf<T>, Did, BlockNumberFor<T>, PriceOf<T>, BalanceOf<T>, EvaluationRoundInfoOf<T>>;
 pub type EvaluationRoundInfoOf<T> = EvaluationRoundInfo<BalanceOf<T>>;
-pub type EvaluationInfoOf<T> = EvaluationInfo<u32, Did, ProjectId, AccountIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>;
+pub type EvaluationInfoOf<T> =
+	EvaluationInfo<u32, Did, ProjectId, AccountIdOf<T>, BalanceOf<T>, BlockNumberFor<T>, PriceOf<T>>;
 pub type BidInfoOf<T> =
 	BidInfo<ProjectId, Did, BalanceOf<T>, PriceOf<T>, AccountIdOf<T>, BlockNumberFor<T>, MultiplierOf<T>>;
 
-pub type ContributionInfoOf<T> = ContributionInfo<u32, Did, ProjectId, AccountIdOf<T>, BalanceOf<T>, MultiplierOf<T>>;
+pub type ContributionInfoOf<T> =
+	ContributionInfo<u32, Did, ProjectId, AccountIdOf<T>, BalanceOf<T>, MultiplierOf<T>, PriceOf<T>>;
 
 pub type BucketOf<T> = Bucket<BalanceOf<T>, PriceOf<T>>;
 pub type WeightInfoOf<T> = <T as Config>::WeightInfo;
+/// Benchmarked weights of `polimec-receiver`, used to pay for the migrations executed on the project's chain.
+pub type ReceiverWeightInfo<T> = polimec_receiver::weights::SubstrateWeight<T>;
 
 pub const PLMC_FOREIGN_ID: u32 = 3344;
 pub const PLMC_DECIMALS: u8 = 10;
@@ -189,7 +200,9 @@ pub const PLMC_DECIMALS: u8 = 10;
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use crate::traits::{BondingRequirementCalculation, ProvideAssetPrice, VestingDurationCalculation};
+	use crate::traits::{
+		BondingRequirementCalculation, ProvideAssetPrice, ProvideStakedPlmc, VestingDurationCalculation,
+	};
 	use frame_support::{
 		dispatch::{GetDispatchInfo, PostDispatchInfo},
 		pallet_prelude::*,
@@ -242,6 +255,10 @@ pub mod pallet {
 		/// The length (expressed in number of blocks) of the Auction Round, Closing period.
 		type BlockNumberToBalance: Convert<BlockNumberFor<Self>, BalanceOf<Self>>;
 
+		/// Method to get the price of an asset when calculating bonds, funding asset amounts and refunds.
+		/// It should not follow short-lived price spikes, e.g. a time-weighted average price.
+		type BondingPriceProvider: ProvideAssetPrice<AssetId = u32, Price = Self::Price>;
+
 		/// The length (expressed in number of blocks) of the Auction Round, Closing period.
 		#[pallet::constant]
 		type AuctionClosingDuration: Get<BlockNumberFor<Self>>;
@@ -291,6 +308,10 @@ pub mod pallet {
 			+ fungibles::metadata::Mutate<AccountIdOf<Self>, AssetId = u32>
 			+ fungibles::Mutate<AccountIdOf<Self>, Balance = BalanceOf<Self>>;
 
+		/// How many blocks the relay has to open a channel Polimec requested, before the issuer can request it again
+		#[pallet::constant]
+		type HrmpChannelRequestTimeout: Get<BlockNumberFor<Self>>;
+
 		/// Credentialized investor Origin, ensures users are of investing type Retail, or Professional, or Institutional.
 		type InvestorOrigin: EnsureOriginWithCredentials<
 			<Self as frame_system::Config>::RuntimeOrigin,
@@ -325,6 +346,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxEvaluationsPerUser: Get<u32>;
 
+		/// The shortest phase durations an issuer can choose for its project
+		#[pallet::constant]
+		type MinPhaseDurations: Get<PhaseDurations<BlockNumberFor<Self>>>;
+
 		#[pallet::constant]
 		type MinUsdPerEvaluation: Get<BalanceOf<Self>>;
 
@@ -332,6 +357,14 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxMessageSizeThresholds: Get<(u32, u32)>;
 
+		/// How many times a failed CT migration is automatically retried before giving up
+		#[pallet::constant]
+		type MaxMigrationRetries: Get<u32>;
+
+		/// The longest phase durations an issuer can choose for its project
+		#[pallet::constant]
+		type MaxPhaseDurations: Get<PhaseDurations<BlockNumberFor<Self>>>;
+
 		/// max iterations for trying to insert a project on the projects_to_update storage
 		#[pallet::constant]
 		type MaxProjectsToUpdateInsertionAttempts: Get<u32>;
@@ -340,6 +373,14 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxProjectsToUpdatePerBlock: Get<u32>;
 
+		/// How many blocks a project chain has to answer a CT migration before it is considered failed
+		#[pallet::constant]
+		type MigrationQueryTimeout: Get<BlockNumberFor<Self>>;
+
+		/// Delay before the first automatic retry of a failed CT migration. It doubles after every retry.
+		#[pallet::constant]
+		type MigrationRetryBackoff: Get<BlockNumberFor<Self>>;
+
 		/// Multiplier type that decides how much PLMC needs to be bonded for a token buy/bid
 		type Multiplier: Parameter
 			+ BondingRequirementCalculation
@@ -364,7 +405,8 @@ pub mod pallet {
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
 
-		/// Pallet info of the polimec receiver pallet. Used for CT migrations
+		/// Pallet info of the polimec receiver pallet. Used for CT migrations.
+		/// Project chains need a receiver with the same major version, and at least this minor and patch version.
 		#[pallet::constant]
 		type PolimecReceiverInfo: Get<PalletInfo>;
 
@@ -375,12 +417,25 @@ pub mod pallet {
 		/// Type that represents the value of something in USD
 		type Price: FixedPointNumber + Parameter + Copy + MaxEncodedLen + MaybeSerializeDeserialize;
 
-		/// Method to get the price of an asset like USDT or PLMC. Likely to come from an oracle
+		/// Minimum number of blocks participations with an asset stay paused after its price moved too much.
+		#[pallet::constant]
+		type PriceCircuitBreakerCooldown: Get<BlockNumberFor<Self>>;
+
+		/// Maximum change of an asset price from one block to the next before participations with it are paused.
+		#[pallet::constant]
+		type PriceCircuitBreakerDeviation: Get<Percent>;
+
+		/// Method to get the latest price of an asset like USDT or PLMC. Likely to come from an oracle.
+		/// Used to watch the price feeds; bonds and refunds use `BondingPriceProvider`.
 		type PriceProvider: ProvideAssetPrice<AssetId = u32, Price = Self::Price>;
 
 		/// Something that provides randomness in the runtime.
 		type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
 
+		/// Indices of the relay chain's HRMP pallet and calls. Used to open channels with project chains
+		#[pallet::constant]
+		type RelayHrmpCallIndices: Get<HrmpCallIndices>;
+
 		/// The length (expressed in number of blocks) of the Remainder Round.
 		#[pallet::constant]
 		type RemainderFundingDuration: Get<BlockNumberFor<Self>>;
@@ -414,6 +469,9 @@ pub mod pallet {
 		#[cfg(any(feature = "runtime-benchmarks", feature = "std"))]
 		type SetPrices: SetPrices;
 
+		/// Source of staked PLMC that can be used as participation bond when the free balance is not enough.
+		type StakedPlmc: ProvideStakedPlmc<AccountIdOf<Self>, BalanceOf<Self>>;
+
 		/// The maximum length of data stored on-chain.
 		#[pallet::constant]
 		type StringLimit: Get<u32>;
@@ -524,6 +582,12 @@ pub mod pallet {
 		ContributionInfoOf<T>,
 	>;
 
+	#[pallet::storage]
+	/// Part of the participation bonds of an account in a project that is backed by its stake instead of held PLMC.
+	/// See [`ProvideStakedPlmc`].
+	pub type StakedParticipationBonds<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, AccountIdOf<T>, Blake2_128Concat, ProjectId, BalanceOf<T>, ValueQuery>;
+
 	#[pallet::storage]
 	pub type AuctionBoughtUSD<T: Config> = StorageNMap<
 		_,
@@ -554,6 +618,12 @@ pub mod pallet {
 		(MigrationStatus, BoundedVec<Migration, MaxParticipationsPerUser<T>>),
 	>;
 
+	/// Participants whose migrations can be sent in a batch. All their participations are settled, and their
+	/// migrations were not sent yet or failed.
+	#[pallet::storage]
+	pub type PendingMigrations<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ProjectId, Blake2_128Concat, T::AccountId, ()>;
+
 	pub struct MaxParticipationsPerUser<T: Config>(PhantomData<T>);
 	impl<T: Config> Get<u32> for MaxParticipationsPerUser<T> {
 		fn get() -> u32 {
@@ -561,8 +631,123 @@ pub mod pallet {
 		}
 	}
 
+	pub struct MaxParticipantsPerMigrationBatch<T: Config>(PhantomData<T>);
+	impl<T: Config> Get<u32> for MaxParticipantsPerMigrationBatch<T> {
+		fn get() -> u32 {
+			// Every participant in a batch has at least one migration
+			Pallet::<T>::migrations_per_xcm_message_allowed()
+		}
+	}
+
+	/// Parachains with migrations awaiting a response. They can report failed migrations without paying for it.
+	pub struct MigratingParachains<T: Config>(PhantomData<T>);
+	impl<T: Config> Contains<MultiLocation> for MigratingParachains<T> {
+		fn contains(location: &MultiLocation) -> bool {
+			let MultiLocation { parents: 1, interior: X1(Parachain(para_id)) } = location else { return false };
+			MigrationQueriesPerParachain::<T>::contains_key(ParaId::from(*para_id))
+		}
+	}
+
+	/// Participants whose migrations were sent in the XCM message awaiting a response with the given query id,
+	/// together with the block after which the query times out.
+	#[pallet::storage]
+	pub type ActiveMigrationQueue<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		QueryId,
+		(ProjectId, BoundedVec<T::AccountId, MaxParticipantsPerMigrationBatch<T>>, BlockNumberFor<T>),
+	>;
+
+	/// Results of the last migration readiness check of each project.
+	#[pallet::storage]
+	pub type MigrationReadinessReports<T: Config> =
+		StorageMap<_, Blake2_128Concat, ProjectId, MigrationReadinessReport, OptionQuery>;
+
+	/// Project of each migration readiness check query awaiting a response from the project's chain.
+	#[pallet::storage]
+	pub type MigrationReadinessQueries<T: Config> = StorageMap<_, Blake2_128Concat, QueryId, ProjectId>;
+
+	/// Block at which Polimec last requested a channel to the project, while it awaits the relay's acceptance.
+	#[pallet::storage]
+	pub type HrmpChannelRequests<T: Config> = StorageMap<_, Blake2_128Concat, ProjectId, BlockNumberFor<T>>;
+
+	/// Users whose migrations failed on the project's chain, as reported by its `polimec-receiver`. The report
+	/// arrives before the response to the migration query, which then marks these users as `Failed`.
+	#[pallet::storage]
+	pub type FailedMigrationReports<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ParaId, Blake2_128Concat, [u8; 32], (), OptionQuery>;
+
+	/// Automatic retries of failed migrations. Stores how many retries were already sent, and from which block
+	/// the next one can be sent.
+	#[pallet::storage]
+	pub type MigrationRetries<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ProjectId, Blake2_128Concat, T::AccountId, (u32, BlockNumberFor<T>)>;
+
+	/// Number of queries in the `ActiveMigrationQueue` sent to each project chain. Removed once it reaches zero.
+	#[pallet::storage]
+	pub type MigrationQueriesPerParachain<T: Config> = StorageMap<_, Blake2_128Concat, ParaId, u32>;
+
+	/// Queries in the `ActiveMigrationQueue`, by the block after which they time out.
 	#[pallet::storage]
-	pub type ActiveMigrationQueue<T: Config> = StorageMap<_, Blake2_128Concat, QueryId, (ProjectId, T::AccountId)>;
+	pub type MigrationTimeouts<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, BlockNumberFor<T>, Blake2_128Concat, QueryId, ()>;
+
+	/// Retries in `MigrationRetries`, by the block from which they can be sent.
+	#[pallet::storage]
+	pub type MigrationRetrySchedule<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, BlockNumberFor<T>, Blake2_128Concat, (ProjectId, T::AccountId), ()>;
+
+	/// Next block of `MigrationTimeouts` to process. Earlier blocks have no queries left to time out.
+	#[pallet::storage]
+	pub type NextMigrationTimeoutBlock<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+	/// Next block of `MigrationRetrySchedule` to process. Earlier blocks have no retries left to send.
+	#[pallet::storage]
+	pub type NextMigrationRetryBlock<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+	/// Total Contribution Tokens minted on Polimec for a project.
+	#[pallet::storage]
+	pub type MintedContributionTokens<T: Config> = StorageMap<_, Blake2_128Concat, ProjectId, BalanceOf<T>, ValueQuery>;
+
+	/// Contribution Tokens burned on Polimec after their migration to the project's chain was confirmed.
+	#[pallet::storage]
+	pub type MigratedContributionTokens<T: Config> =
+		StorageMap<_, Blake2_128Concat, ProjectId, BalanceOf<T>, ValueQuery>;
+
+	/// Contribution Tokens of a confirmed migration that could not be burned on Polimec, because the participant
+	/// no longer held them. They exist on both chains until the issuer resolves it.
+	#[pallet::storage]
+	pub type UnburnedMigratedContributionTokens<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ProjectId, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+	/// Merkle root of all the migrations of a project using [`MigrationType::Offchain`]. Users claim their CTs on
+	/// the destination chain with a proof against it.
+	#[pallet::storage]
+	pub type MigrationMerkleRoots<T: Config> = StorageMap<_, Blake2_128Concat, ProjectId, H256>;
+
+	/// Participants who agreed that the issuer confirms their off-chain migration.
+	#[pallet::storage]
+	pub type OffchainMigrationConsents<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ProjectId, Blake2_128Concat, T::AccountId, ()>;
+
+	/// DOT withdrawn from Polimec's sovereign account on the project's chain to pay for each migration message.
+	/// Projects without an entry have their migrations executed as `UnpaidExecution`.
+	#[pallet::storage]
+	pub type MigrationExecutionFees<T: Config> = StorageMap<_, Blake2_128Concat, ProjectId, u128>;
+
+	/// Vesting start and cliff of the migrated CTs on the project's chain. Can only be changed before settlement.
+	#[pallet::storage]
+	pub type DestinationVestings<T: Config> = StorageMap<_, Blake2_128Concat, ProjectId, DestinationVesting>;
+
+	/// Price of each participation asset seen by the circuit breaker on the last block.
+	#[pallet::storage]
+	pub type LastCheckedPrices<T: Config> = StorageMap<_, Blake2_128Concat, u32, PriceOf<T>>;
+
+	/// Assets whose price feed tripped the circuit breaker, and the block it last tripped at. No new participations
+	/// are accepted with them.
+	#[pallet::storage]
+	pub type PausedPriceFeeds<T: Config> =
+		StorageMap<_, Blake2_128Concat, u32, (PriceFeedIssue, BlockNumberFor<T>)>;
 
 	/// A map to keep track of what issuer's did has an active project. It prevents one issuer having multiple active projects
 	#[pallet::storage]
@@ -591,6 +776,11 @@ pub mod pallet {
 			project_id: ProjectId,
 			metadata: ProjectMetadataOf<T>,
 		},
+		/// The issuer (re)scheduled the automatic start of the auction. `None` removes the schedule.
+		AuctionStartScheduled {
+			project_id: ProjectId,
+			block: Option<BlockNumberFor<T>>,
+		},
 		/// Project transitioned to a new phase.
 		ProjectPhaseTransition {
 			project_id: ProjectId,
@@ -672,6 +862,18 @@ pub mod pallet {
 			project_id: ProjectId,
 			para_id: ParaId,
 		},
+		/// A channel between Polimec and a project was closed. Migrations are paused until it is open again.
+		HrmpChannelClosed {
+			project_id: ProjectId,
+			para_id: ParaId,
+			initiator: ParaId,
+			hrmp_channel_status: HRMPChannelStatus,
+		},
+		/// The issuer requested again the channel from Polimec to the project.
+		HrmpChannelRequested {
+			project_id: ProjectId,
+			para_id: ParaId,
+		},
 		/// Started a migration readiness check
 		MigrationReadinessCheckStarted {
 			project_id: ProjectId,
@@ -692,6 +894,58 @@ pub mod pallet {
 			account: AccountIdOf<T>,
 			status: MigrationStatus,
 		},
+		/// The project chain did not answer a migration message in time. All its migrations are marked as failed.
+		MigrationQueryTimedOut {
+			project_id: ProjectId,
+			query_id: QueryId,
+		},
+		/// A migration was confirmed, but the participant no longer held all the migrated CTs on Polimec.
+		MigratedContributionTokensNotBurned {
+			project_id: ProjectId,
+			account: AccountIdOf<T>,
+			shortfall: BalanceOf<T>,
+		},
+		/// A failed migration will be sent again automatically.
+		MigrationRetryScheduled {
+			project_id: ProjectId,
+			account: AccountIdOf<T>,
+			retry_block: BlockNumberFor<T>,
+		},
+		/// A migration failed too many times and will no longer be retried automatically.
+		MigrationRetriesExhausted {
+			project_id: ProjectId,
+			account: AccountIdOf<T>,
+		},
+		/// The project will migrate its CTs off-chain. Users can claim them on the destination chain with a proof
+		/// against the published Merkle root.
+		OffchainMigrationStarted {
+			project_id: ProjectId,
+			merkle_root: H256,
+		},
+		/// The participant agreed that the issuer confirms its off-chain migration.
+		OffchainMigrationConsented {
+			project_id: ProjectId,
+			account: AccountIdOf<T>,
+		},
+		/// The DOT fee paid for executing the project's migration messages was set, or removed if `None`.
+		MigrationExecutionFeeSet {
+			project_id: ProjectId,
+			fee: Option<u128>,
+		},
+		/// The vesting start and cliff of the project's CTs on its own chain were set.
+		DestinationVestingSet {
+			project_id: ProjectId,
+			vesting: DestinationVesting,
+		},
+		/// The price feed of an asset is stale or moving too much. Participations with it are paused.
+		PriceCircuitBreakerTripped {
+			asset_id: u32,
+			issue: PriceFeedIssue,
+		},
+		/// The price feed of an asset is healthy again. Participations with it are accepted.
+		PriceCircuitBreakerReset {
+			asset_id: u32,
+		},
 	}
 
 	#[pallet::error]
@@ -704,6 +958,8 @@ pub mod pallet {
 		BadMath,
 		/// Could not get the price in USD equivalent for an asset/PLMC.
 		PriceNotFound,
+		/// The price feed of PLMC or the funding asset tripped the circuit breaker. Try again later.
+		PriceFeedPaused,
 		/// Tried to retrieve a evaluation, bid or contribution but it does not exist.
 		ParticipationNotFound,
 		/// The user investor type is not eligible for the action.
@@ -779,6 +1035,13 @@ pub mod pallet {
 		// The combination of decimals and price of this project is not representable within our 6 decimals USD system,
 		// and integer space of 128 bits.
 		BadTokenomics,
+		/// One of the custom phase durations is outside the bounds set by the runtime.
+		PhaseDurationsOutOfBounds,
+		/// A scheduled phase start is in the past, does not leave enough time for the previous phase, or is
+		/// after the auction initialize period.
+		InvalidPhaseSchedule,
+		/// The soft cap is zero, or higher than the funding target or the hard cap.
+		FundingCapsError,
 
 		// * Error related to an participation action. Evaluation, bid or contribution failed. *
 		/// The amount is too low.
@@ -815,10 +1078,22 @@ pub mod pallet {
 		NoActiveMigrationsFound,
 		/// Wrong para_id is provided.
 		WrongParaId,
+		/// The report contains more failed migrations than fit in one migration message.
+		TooManyFailedMigrations,
 		/// Migration channel is not ready for migrations.
 		ChannelNotReady,
+		/// The channel from Polimec to the project is already open.
+		HrmpChannelAlreadyOpen,
+		/// Polimec requested the channel recently, and the relay can still accept it.
+		HrmpChannelRequestPending,
 		/// User still has participations that need to be settled before migration.
 		ParticipationsNotSettled,
+		/// The action is not available for the migration type chosen by the project.
+		WrongMigrationType,
+		/// The project has more migrations than the count given to pay for building their Merkle tree.
+		MigrationsCountTooLow,
+		/// The participant did not consent to the confirmation of their off-chain migration.
+		OffchainMigrationNotConsented,
 	}
 
 	#[pallet::call]
@@ -891,6 +1166,22 @@ pub mod pallet {
 			Self::do_start_auction_opening(account, project_id)
 		}
 
+		/// Sets, moves or removes the block at which the auction of a project starts automatically.
+		/// Can be called until the auction starts.
+		#[pallet::call_index(36)]
+		#[pallet::weight(WeightInfoOf::<T>::start_auction_manually(<T as Config>::MaxProjectsToUpdateInsertionAttempts::get() - 1))]
+		pub fn schedule_auction_start(
+			origin: OriginFor<T>,
+			jwt: UntrustedToken,
+			project_id: ProjectId,
+			auction_start: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let (account, _did, investor_type, _cid) =
+				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
+			ensure!(investor_type == InvestorType::Institutional, Error::<T>::WrongInvestorType);
+			Self::do_schedule_auction_start(account, project_id, auction_start)
+		}
+
 		/// Bond PLMC for a project in the evaluation stage
 		#[pallet::call_index(4)]
 		#[pallet::weight(
@@ -1151,14 +1442,126 @@ pub mod pallet {
 			Self::do_migrate_one_participant(project_id, participant)
 		}
 
+		/// Migrate as many participants of a project as fit into a single XCM message.
+		#[pallet::call_index(38)]
+		#[pallet::weight(WeightInfoOf::<T>::migrate_participants_batch(MaxParticipantsPerMigrationBatch::<T>::get()))]
+		pub fn migrate_participants_batch(origin: OriginFor<T>, project_id: ProjectId) -> DispatchResultWithPostInfo {
+			let _caller = ensure_signed(origin)?;
+			Self::do_migrate_participants_batch(project_id)
+		}
+
 		#[pallet::call_index(27)]
-		#[pallet::weight(Weight::from_parts(1000, 0))]
-		pub fn confirm_migrations(origin: OriginFor<T>, query_id: QueryId, response: Response) -> DispatchResult {
+		#[pallet::weight(WeightInfoOf::<T>::confirm_migrations(MaxParticipantsPerMigrationBatch::<T>::get()))]
+		pub fn confirm_migrations(
+			origin: OriginFor<T>,
+			query_id: QueryId,
+			response: Response,
+		) -> DispatchResultWithPostInfo {
 			let location = ensure_response(<T as Config>::RuntimeOrigin::from(origin))?;
 
 			Self::do_confirm_migrations(location, query_id, response)
 		}
 
+		/// Called by the `polimec-receiver` of a project's chain with the migrations it could not execute.
+		#[pallet::call_index(43)]
+		#[pallet::weight(WeightInfoOf::<T>::report_failed_migrations(failed.len() as u32))]
+		pub fn report_failed_migrations(origin: OriginFor<T>, failed: Vec<MigrationOrigin>) -> DispatchResult {
+			let location = ensure_xcm(<T as Config>::RuntimeOrigin::from(origin))?;
+
+			Self::do_report_failed_migrations(location, failed)
+		}
+
+		/// Migrate the CTs off-chain, by publishing the Merkle root of all the project's migrations.
+		/// `migrations_count` must be at least the number of migrations of the project, and is what the call pays for.
+		#[pallet::call_index(39)]
+		#[pallet::weight(WeightInfoOf::<T>::start_offchain_migration(*migrations_count))]
+		pub fn start_offchain_migration(
+			origin: OriginFor<T>,
+			jwt: UntrustedToken,
+			project_id: ProjectId,
+			migrations_count: u32,
+		) -> DispatchResultWithPostInfo {
+			let (account, _did, investor_type, _cid) =
+				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
+			ensure!(investor_type == InvestorType::Institutional, Error::<T>::WrongInvestorType);
+			Self::do_start_offchain_migration(&account, project_id, migrations_count)
+		}
+
+		/// Agree, as a participant, that the issuer confirms the off-chain migration of your CTs, burning them on
+		/// Polimec. Give it once you claimed them on the destination chain.
+		#[pallet::call_index(45)]
+		#[pallet::weight(Weight::from_parts(1000, 0))]
+		pub fn consent_offchain_migration(origin: OriginFor<T>, project_id: ProjectId) -> DispatchResult {
+			let participant = ensure_signed(origin)?;
+			Self::do_consent_offchain_migration(participant, project_id)
+		}
+
+		/// Attest that a participant claimed their CTs on the destination chain of an off-chain migration.
+		#[pallet::call_index(40)]
+		#[pallet::weight(Weight::from_parts(1000, 0))]
+		pub fn confirm_offchain_migration(
+			origin: OriginFor<T>,
+			jwt: UntrustedToken,
+			project_id: ProjectId,
+			participant: AccountIdOf<T>,
+		) -> DispatchResult {
+			let (account, _did, investor_type, _cid) =
+				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
+			ensure!(investor_type == InvestorType::Institutional, Error::<T>::WrongInvestorType);
+			Self::do_confirm_offchain_migration(&account, project_id, participant)
+		}
+
+		/// Pay for the execution of the project's migration messages with DOT, instead of relying on unpaid execution.
+		#[pallet::call_index(41)]
+		#[pallet::weight(Weight::from_parts(1000, 0))]
+		pub fn set_migration_execution_fee(
+			origin: OriginFor<T>,
+			jwt: UntrustedToken,
+			project_id: ProjectId,
+			fee: Option<u128>,
+		) -> DispatchResult {
+			let (account, _did, investor_type, _cid) =
+				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
+			ensure!(investor_type == InvestorType::Institutional, Error::<T>::WrongInvestorType);
+			Self::do_set_migration_execution_fee(&account, project_id, fee)
+		}
+
+		/// Request again the channel from Polimec to the project, after it was closed or its request went stale.
+		#[pallet::call_index(44)]
+		#[pallet::weight(Weight::from_parts(1000, 0))]
+		pub fn request_hrmp_channel(
+			origin: OriginFor<T>,
+			jwt: UntrustedToken,
+			project_id: ProjectId,
+		) -> DispatchResult {
+			let (account, _did, investor_type, _cid) =
+				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
+			ensure!(investor_type == InvestorType::Institutional, Error::<T>::WrongInvestorType);
+			Self::do_request_hrmp_channel(&account, project_id)
+		}
+
+		/// Set when the migrated CTs start vesting on the project's chain, and their cliff.
+		#[pallet::call_index(42)]
+		#[pallet::weight(Weight::from_parts(1000, 0))]
+		pub fn set_destination_vesting(
+			origin: OriginFor<T>,
+			jwt: UntrustedToken,
+			project_id: ProjectId,
+			vesting: DestinationVesting,
+		) -> DispatchResult {
+			let (account, _did, investor_type, _cid) =
+				T::InvestorOrigin::ensure_origin(origin, &jwt, T::VerifierPublicKey::get())?;
+			ensure!(investor_type == InvestorType::Institutional, Error::<T>::WrongInvestorType);
+			Self::do_set_destination_vesting(&account, project_id, vesting)
+		}
+
+		#[pallet::call_index(37)]
+		#[pallet::weight(WeightInfoOf::<T>::start_evaluation(<T as Config>::MaxProjectsToUpdateInsertionAttempts::get() - 1))]
+		pub fn root_do_start_evaluation(origin: OriginFor<T>, project_id: ProjectId) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			Self::do_start_evaluation(T::PalletId::get().into_account_truncating(), project_id)
+		}
+
 		#[pallet::call_index(28)]
 		#[pallet::weight(WeightInfoOf::<T>::end_evaluation_success(
 			<T as Config>::MaxProjectsToUpdateInsertionAttempts::get() - 1,
@@ -1277,10 +1680,21 @@ pub mod pallet {
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			// Pause the participations with assets whose price can't be trusted
+			let mut used_weight = Self::do_check_price_feeds(now);
+
 			// Get the projects that need to be updated on this block and update them
-			let mut used_weight = Weight::from_parts(0, 0);
 			if let Some((project_id, update_type)) = ProjectsToUpdate::<T>::take(now) {
 				match update_type {
+					// Application -> EvaluationRound
+					// Only if it was scheduled by the issuer and not started manually before
+					UpdateType::EvaluationStart => {
+						let call = Self::do_start_evaluation(T::PalletId::get().into_account_truncating(), project_id);
+						let fallback_weight =
+							Call::<T>::root_do_start_evaluation { project_id }.get_dispatch_info().weight;
+						update_weight(&mut used_weight, call, fallback_weight);
+					},
+
 					// EvaluationRound -> AuctionInitializePeriod | ProjectFailed
 					UpdateType::EvaluationEnd => {
 						let call = Self::do_evaluation_end(project_id);
@@ -1354,6 +1768,19 @@ pub mod pallet {
 			}
 			used_weight
 		}
+
+		fn on_idle(now: BlockNumberFor<T>, max_weight: Weight) -> Weight {
+			// Fail the CT migrations that timed out, and retry the failed ones whose backoff elapsed
+			let mut used_weight = Self::do_process_migration_timeouts(now, max_weight);
+			let retries_weight = Self::do_process_migration_retries(now, max_weight.saturating_sub(used_weight));
+			used_weight.saturating_accrue(retries_weight);
+			used_weight
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::do_try_state()
+		}
 	}
 
 	#[pallet::genesis_config]
@@ -1417,5 +1844,9 @@ pub mod xcm_executor_impl {
 		fn handle_channel_accepted(message: Instruction) -> XcmResult {
 			<Pallet<T>>::do_handle_channel_accepted(message)
 		}
+
+		fn handle_channel_closing(message: Instruction) -> XcmResult {
+			<Pallet<T>>::do_handle_channel_closing(message)
+		}
 	}
 }
@@ -20,7 +20,7 @@
 
 use super::*;
 use crate as pallet_funding;
-use crate::traits::ProvideAssetPrice;
+use crate::traits::{ProvideAssetPrice, ProvideStakedPlmc};
 use frame_support::{
 	construct_runtime,
 	pallet_prelude::Weight,
@@ -36,7 +36,7 @@ use sp_arithmetic::Percent;
 use sp_core::H256;
 use sp_runtime::{
 	traits::{BlakeTwo256, ConvertBack, ConvertInto, Get, IdentityLookup, TryConvert},
-	BuildStorage,
+	BuildStorage, DispatchError, DispatchResult,
 };
 use sp_std::collections::btree_map::BTreeMap;
 use std::cell::RefCell;
@@ -298,6 +298,29 @@ parameter_types! {
 	pub const RemainderFundingDuration: BlockNumber = 10u64;
 	pub const ManualAcceptanceDuration: BlockNumber = 10u64;
 	pub const SuccessToSettlementTime: BlockNumber = 10u64;
+	pub const HrmpChannelRequestTimeout: BlockNumber = 10u64;
+	pub const PriceCircuitBreakerCooldown: BlockNumber = 10u64;
+	pub const PriceCircuitBreakerDeviation: Percent = Percent::from_percent(5);
+	pub const MigrationQueryTimeout: BlockNumber = 20u64;
+	pub const MigrationRetryBackoff: BlockNumber = 5u64;
+	pub const MinPhaseDurations: PhaseDurations<BlockNumber> = PhaseDurations {
+		evaluation: 5u64,
+		auction_opening: 5u64,
+		auction_closing: 5u64,
+		community_funding: 5u64,
+		remainder_funding: 5u64,
+		manual_acceptance: 5u64,
+		success_to_settlement: 5u64,
+	};
+	pub const MaxPhaseDurations: PhaseDurations<BlockNumber> = PhaseDurations {
+		evaluation: 20u64,
+		auction_opening: 20u64,
+		auction_closing: 20u64,
+		community_funding: 20u64,
+		remainder_funding: 20u64,
+		manual_acceptance: 20u64,
+		success_to_settlement: 20u64,
+	};
 
 	pub const FundingPalletId: PalletId = PalletId(*b"py/cfund");
 	pub FeeBrackets: Vec<(Percent, Balance)> = vec![
@@ -316,8 +339,11 @@ parameter_types! {
 	pub UnvestedFundsAllowedWithdrawReasons: WithdrawReasons =
 		WithdrawReasons::except(WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE);
 	pub PolimecReceiverInfo: xcm::v3::PalletInfo = xcm::v3::PalletInfo::new(
-		51, "PolimecReceiver".into(), "polimec_receiver".into(), 0, 1, 0
+		51, "PolimecReceiver".into(), "polimec_receiver".into(), 0, 2, 0
 	).unwrap();
+	pub RelayHrmpCallIndices: HrmpCallIndices = HrmpCallIndices {
+		pallet: 60, init_open_channel: 0, accept_open_channel: 1
+	};
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -390,6 +416,47 @@ impl ConstPriceProvider {
 		});
 	}
 }
+thread_local! {
+	pub static STAKED_PLMC: RefCell<BTreeMap<AccountId, Balance>> = RefCell::new(BTreeMap::new());
+	pub static STAKE_IN_USE: RefCell<BTreeMap<AccountId, Balance>> = RefCell::new(BTreeMap::new());
+}
+/// Keeps the stake outside of the account, and only tracks which part of it is in use.
+pub struct MockStakedPlmc;
+impl ProvideStakedPlmc<AccountId, Balance> for MockStakedPlmc {
+	fn usable_stake(who: &AccountId) -> Balance {
+		let staked = STAKED_PLMC.with(|stake| stake.borrow().get(who).cloned().unwrap_or_default());
+		staked.saturating_sub(Self::stake_in_use(who))
+	}
+
+	fn use_stake(who: &AccountId, amount: Balance) -> DispatchResult {
+		if Self::usable_stake(who) < amount {
+			return Err(DispatchError::Other("Not enough stake"));
+		}
+		STAKE_IN_USE.with(|in_use| *in_use.borrow_mut().entry(*who).or_default() += amount);
+		Ok(())
+	}
+
+	fn release_stake(who: &AccountId, amount: Balance) {
+		STAKE_IN_USE.with(|in_use| {
+			let mut in_use = in_use.borrow_mut();
+			let used = in_use.get(who).cloned().unwrap_or_default();
+			in_use.insert(*who, used.saturating_sub(amount));
+		});
+	}
+}
+
+impl MockStakedPlmc {
+	pub fn set_stake(who: AccountId, amount: Balance) {
+		STAKED_PLMC.with(|stake| {
+			stake.borrow_mut().insert(who, amount);
+		});
+	}
+
+	pub fn stake_in_use(who: &AccountId) -> Balance {
+		STAKE_IN_USE.with(|in_use| in_use.borrow().get(who).cloned().unwrap_or_default())
+	}
+}
+
 impl Config for TestRuntime {
 	type AccountId32Conversion = DummyConverter;
 	type AllPalletsWithoutSystem =
@@ -400,6 +467,7 @@ impl Config for TestRuntime {
 	type Balance = Balance;
 	type BlockNumber = BlockNumber;
 	type BlockNumberToBalance = ConvertInto;
+	type BondingPriceProvider = ConstPriceProvider;
 	type CommunityFundingDuration = CommunityRoundDuration;
 	type ContributionTokenCurrency = ContributionTokens;
 	type ContributionTreasury = ContributionTreasury;
@@ -409,6 +477,7 @@ impl Config for TestRuntime {
 	type EvaluatorSlash = EvaluatorSlash;
 	type FeeBrackets = FeeBrackets;
 	type FundingCurrency = ForeignAssets;
+	type HrmpChannelRequestTimeout = HrmpChannelRequestTimeout;
 	type InvestorOrigin = EnsureInvestor<TestRuntime>;
 	type ManualAcceptanceDuration = ManualAcceptanceDuration;
 	type MaxBidsPerProject = ConstU32<512>;
@@ -418,8 +487,13 @@ impl Config for TestRuntime {
 	type MaxEvaluationsPerProject = ConstU32<512>;
 	type MaxEvaluationsPerUser = ConstU32<4>;
 	type MaxMessageSizeThresholds = MaxMessageSizeThresholds;
+	type MaxMigrationRetries = ConstU32<3>;
+	type MaxPhaseDurations = MaxPhaseDurations;
 	type MaxProjectsToUpdateInsertionAttempts = ConstU32<100>;
 	type MaxProjectsToUpdatePerBlock = ConstU32<1>;
+	type MigrationQueryTimeout = MigrationQueryTimeout;
+	type MigrationRetryBackoff = MigrationRetryBackoff;
+	type MinPhaseDurations = MinPhaseDurations;
 	type MinUsdPerEvaluation = MinUsdPerEvaluation;
 	type Multiplier = Multiplier;
 	type NativeCurrency = Balances;
@@ -427,9 +501,12 @@ impl Config for TestRuntime {
 	type PolimecReceiverInfo = PolimecReceiverInfo;
 	type PreImageLimit = ConstU32<1024>;
 	type Price = FixedU128;
+	type PriceCircuitBreakerCooldown = PriceCircuitBreakerCooldown;
+	type PriceCircuitBreakerDeviation = PriceCircuitBreakerDeviation;
 	type PriceProvider = ConstPriceProvider;
 	type ProtocolGrowthTreasury = ProtocolGrowthTreasuryAccount;
 	type Randomness = RandomnessCollectiveFlip;
+	type RelayHrmpCallIndices = RelayHrmpCallIndices;
 	type RemainderFundingDuration = RemainderFundingDuration;
 	type RequiredMaxCapacity = RequiredMaxCapacity;
 	type RequiredMaxMessageSize = RequiredMaxMessageSize;
@@ -439,6 +516,7 @@ impl Config for TestRuntime {
 	type RuntimeOrigin = RuntimeOrigin;
 	#[cfg(any(feature = "runtime-benchmarks", feature = "std"))]
 	type SetPrices = ();
+	type StakedPlmc = MockStakedPlmc;
 	type StringLimit = ConstU32<64>;
 	type SuccessToSettlementTime = SuccessToSettlementTime;
 	type VerifierPublicKey = VerifierPublicKey;
@@ -0,0 +1,30 @@
+// Polimec Blockchain – https://www.polimec.org/
+// Copyright (C) Polimec 2022. All rights reserved.
+
+// The Polimec Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Polimec Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use crate::ProjectId;
+use parity_scale_codec::Codec;
+use polimec_common::migration_types::Migration;
+use sp_core::H256;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// Data needed by the participants of a project using off-chain migrations to claim their tokens.
+	pub trait OffchainMigrationApi<AccountId: Codec> {
+		/// Migrations of `participant` in `project_id`, each with its proof against the published Merkle root.
+		/// Empty if the project did not start its off-chain migration.
+		fn migration_merkle_proofs(project_id: ProjectId, participant: AccountId) -> Vec<(Migration, Vec<H256>)>;
+	}
+}
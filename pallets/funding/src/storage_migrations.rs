@@ -2,20 +2,21 @@
 use frame_support::traits::StorageVersion;
 
 /// The current storage version
-pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
 pub const LOG: &str = "runtime::funding::migration";
 
 pub mod v2 {
-	use crate::{AccountIdOf, BalanceOf, Config, ProjectsMetadata};
+	use crate::{AccountIdOf, BalanceOf, Config, ProjectId};
 	use frame_support::{
 		pallet_prelude::{Decode, Encode, MaxEncodedLen, RuntimeDebug, TypeInfo},
 		traits::{Get, OnRuntimeUpgrade},
-		BoundedVec,
+		Blake2_128Concat, BoundedVec,
 	};
 	use polimec_common::USD_DECIMALS;
 	use sp_arithmetic::{FixedPointNumber, Percent};
 	use sp_core::ConstU32;
 	use sp_std::marker::PhantomData;
+	use super::v5::OldProjectMetadataOf as NewProjectMetadataOf;
 
 	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
 	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
@@ -73,14 +74,19 @@ pub mod v2 {
 		pub policy_ipfs_cid: Option<Cid>,
 	}
 
+	/// `ProjectsMetadata` with the v2 layout this migration writes.
+	#[frame_support::storage_alias]
+	type ProjectsMetadata<T: Config> =
+		StorageMap<crate::Pallet<T>, Blake2_128Concat, ProjectId, NewProjectMetadataOf<T>>;
+
 	pub struct UncheckedMigrationToV2<T: Config>(PhantomData<T>);
 	impl<T: Config> OnRuntimeUpgrade for UncheckedMigrationToV2<T> {
 		fn on_runtime_upgrade() -> frame_support::weights::Weight {
 			let mut items = 0;
-			let mut translate = |_key, item: OldProjectMetadataOf<T>| -> Option<crate::ProjectMetadataOf<T>> {
+			let mut translate = |_key, item: OldProjectMetadataOf<T>| -> Option<NewProjectMetadataOf<T>> {
 				items += 1;
 				let usd_unit = sp_arithmetic::traits::checked_pow(BalanceOf::<T>::from(10u64), USD_DECIMALS as usize)?;
-				Some(crate::ProjectMetadataOf::<T> {
+				Some(NewProjectMetadataOf::<T> {
 					token_information: item.token_information,
 					mainnet_token_max_supply: item.mainnet_token_max_supply,
 					total_allocation_size: item.total_allocation_size,
@@ -152,3 +158,394 @@ pub mod v2 {
 		<T as frame_system::Config>::DbWeight,
 	>;
 }
+
+pub mod v3 {
+	use crate::{Config, MaxParticipationsPerUser, UserMigrations};
+	use frame_support::{
+		pallet_prelude::{Decode, Encode, RuntimeDebug},
+		traits::{Get, OnRuntimeUpgrade},
+		BoundedVec,
+	};
+	use polimec_common::migration_types::{Migration, MigrationInfo, MigrationOrigin, MigrationStatus};
+	use sp_std::{marker::PhantomData, vec::Vec};
+
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+	pub struct OldMigrationInfo {
+		pub contribution_token_amount: u128,
+		pub vesting_time: u64,
+	}
+
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+	pub struct OldMigration {
+		pub origin: MigrationOrigin,
+		pub info: OldMigrationInfo,
+	}
+
+	type OldUserMigrations<T> = (MigrationStatus, BoundedVec<OldMigration, MaxParticipationsPerUser<T>>);
+	type NewUserMigrations<T> = (MigrationStatus, BoundedVec<Migration, MaxParticipationsPerUser<T>>);
+
+	/// Add the optional vesting start and cliff to the stored migrations. Existing ones vest as before.
+	pub struct UncheckedMigrationToV3<T: Config>(PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for UncheckedMigrationToV3<T> {
+		fn on_runtime_upgrade() -> frame_support::weights::Weight {
+			let mut items = 0;
+			UserMigrations::<T>::translate(|_project_id, _account, (status, migrations): OldUserMigrations<T>| {
+				items += 1;
+				let migrations = migrations
+					.into_iter()
+					.map(|OldMigration { origin, info }| {
+						let info: MigrationInfo = (info.contribution_token_amount, info.vesting_time).into();
+						Migration::new(origin, info)
+					})
+					.collect::<Vec<_>>();
+				let migrations: Option<NewUserMigrations<T>> =
+					BoundedVec::try_from(migrations).ok().map(|migrations| (status, migrations));
+				migrations
+			});
+
+			T::DbWeight::get().reads_writes(items, items)
+		}
+	}
+
+	pub type MigrationToV3<T> = frame_support::migrations::VersionedMigration<
+		2,
+		3,
+		UncheckedMigrationToV3<T>,
+		crate::Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+pub mod v4 {
+	use crate::{
+		traits::BondingRequirementCalculation, AcceptedFundingAsset, AccountIdOf, BalanceOf, BidInfoOf, BidStatus, Bids,
+		Config, ContributionInfoOf, Contributions, EvaluationInfoOf, Evaluations, MultiplierOf, PriceOf, ProjectId,
+	};
+	use frame_support::{
+		pallet_prelude::{Decode, Encode, RuntimeDebug},
+		traits::{tokens::Balance as BalanceT, Get, OnRuntimeUpgrade},
+	};
+	use frame_system::pallet_prelude::BlockNumberFor;
+	use polimec_common::credentials::Did;
+	use sp_arithmetic::FixedPointNumber;
+	use sp_std::marker::PhantomData;
+
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+	pub struct OldEvaluationInfo<Id, Did, ProjectId, AccountId, Balance, BlockNumber> {
+		pub id: Id,
+		pub did: Did,
+		pub project_id: ProjectId,
+		pub evaluator: AccountId,
+		pub original_plmc_bond: Balance,
+		pub current_plmc_bond: Balance,
+		pub early_usd_amount: Balance,
+		pub late_usd_amount: Balance,
+		pub when: BlockNumber,
+	}
+
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+	pub struct OldBidInfo<ProjectId, Did, Balance: BalanceT, Price, AccountId, BlockNumber, Multiplier> {
+		pub id: u32,
+		pub project_id: ProjectId,
+		pub bidder: AccountId,
+		pub did: Did,
+		pub status: BidStatus<Balance>,
+		#[codec(compact)]
+		pub original_ct_amount: Balance,
+		pub original_ct_usd_price: Price,
+		pub final_ct_amount: Balance,
+		pub final_ct_usd_price: Price,
+		pub funding_asset: AcceptedFundingAsset,
+		pub funding_asset_amount_locked: Balance,
+		pub multiplier: Multiplier,
+		pub plmc_bond: Balance,
+		pub when: BlockNumber,
+	}
+
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+	pub struct OldContributionInfo<Id, Did, ProjectId, AccountId, Balance, Multiplier> {
+		pub id: Id,
+		pub did: Did,
+		pub project_id: ProjectId,
+		pub contributor: AccountId,
+		pub ct_amount: Balance,
+		pub usd_contribution_amount: Balance,
+		pub multiplier: Multiplier,
+		pub funding_asset: AcceptedFundingAsset,
+		pub funding_asset_amount: Balance,
+		pub plmc_bond: Balance,
+	}
+
+	type OldEvaluationInfoOf<T> =
+		OldEvaluationInfo<u32, Did, ProjectId, AccountIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>;
+	type OldBidInfoOf<T> =
+		OldBidInfo<ProjectId, Did, BalanceOf<T>, PriceOf<T>, AccountIdOf<T>, BlockNumberFor<T>, MultiplierOf<T>>;
+	type OldContributionInfoOf<T> =
+		OldContributionInfo<u32, Did, ProjectId, AccountIdOf<T>, BalanceOf<T>, MultiplierOf<T>>;
+
+	/// PLMC price a `plmc_bond` was calculated with, from the USD amount it bonds.
+	fn plmc_usd_price<T: Config>(usd_bond: BalanceOf<T>, plmc_bond: BalanceOf<T>) -> PriceOf<T> {
+		PriceOf::<T>::checked_from_rational(usd_bond, plmc_bond).unwrap_or_default()
+	}
+
+	/// Add the PLMC price the bond was calculated with to the stored participations. For existing ones it is derived
+	/// from their bond and USD amount.
+	pub struct UncheckedMigrationToV4<T: Config>(PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for UncheckedMigrationToV4<T> {
+		fn on_runtime_upgrade() -> frame_support::weights::Weight {
+			let mut items = 0u64;
+			Evaluations::<T>::translate(|_key, old: OldEvaluationInfoOf<T>| -> Option<EvaluationInfoOf<T>> {
+				items += 1;
+				let usd_amount = old.early_usd_amount.saturating_add(old.late_usd_amount);
+				Some(EvaluationInfoOf::<T> {
+					id: old.id,
+					did: old.did,
+					project_id: old.project_id,
+					evaluator: old.evaluator,
+					original_plmc_bond: old.original_plmc_bond,
+					current_plmc_bond: old.current_plmc_bond,
+					early_usd_amount: old.early_usd_amount,
+					late_usd_amount: old.late_usd_amount,
+					when: old.when,
+					plmc_usd_price: plmc_usd_price::<T>(usd_amount, old.original_plmc_bond),
+				})
+			});
+
+			Bids::<T>::translate(|_key, old: OldBidInfoOf<T>| -> Option<BidInfoOf<T>> {
+				items += 1;
+				let ticket_size = old.original_ct_usd_price.saturating_mul_int(old.original_ct_amount);
+				let usd_bond = old.multiplier.calculate_bonding_requirement::<T>(ticket_size).unwrap_or_default();
+				Some(BidInfoOf::<T> {
+					id: old.id,
+					project_id: old.project_id,
+					bidder: old.bidder,
+					did: old.did,
+					status: old.status,
+					original_ct_amount: old.original_ct_amount,
+					original_ct_usd_price: old.original_ct_usd_price,
+					final_ct_amount: old.final_ct_amount,
+					final_ct_usd_price: old.final_ct_usd_price,
+					funding_asset: old.funding_asset,
+					funding_asset_amount_locked: old.funding_asset_amount_locked,
+					multiplier: old.multiplier,
+					plmc_bond: old.plmc_bond,
+					when: old.when,
+					plmc_usd_price: plmc_usd_price::<T>(usd_bond, old.plmc_bond),
+				})
+			});
+
+			Contributions::<T>::translate(|_key, old: OldContributionInfoOf<T>| -> Option<ContributionInfoOf<T>> {
+				items += 1;
+				let usd_bond =
+					old.multiplier.calculate_bonding_requirement::<T>(old.usd_contribution_amount).unwrap_or_default();
+				Some(ContributionInfoOf::<T> {
+					id: old.id,
+					did: old.did,
+					project_id: old.project_id,
+					contributor: old.contributor,
+					ct_amount: old.ct_amount,
+					usd_contribution_amount: old.usd_contribution_amount,
+					multiplier: old.multiplier,
+					funding_asset: old.funding_asset,
+					funding_asset_amount: old.funding_asset_amount,
+					plmc_bond: old.plmc_bond,
+					plmc_usd_price: plmc_usd_price::<T>(usd_bond, old.plmc_bond),
+				})
+			});
+
+			T::DbWeight::get().reads_writes(items, items)
+		}
+	}
+
+	pub type MigrationToV4<T> = frame_support::migrations::VersionedMigration<
+		3,
+		4,
+		UncheckedMigrationToV4<T>,
+		crate::Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+pub mod v5 {
+	use crate::{
+		AcceptedFundingAsset, AccountIdOf, ActiveMigrationQueue, BalanceOf, BiddingTicketSizes, CheckOutcome, Config,
+		ContributingTicketSizes, CurrencyMetadata, EvaluationRoundInfoOf, HRMPChannelStatus,
+		MigrationQueriesPerParachain, MigrationReadinessCheck, MigrationReadinessQueries, MigrationReadinessReport,
+		MigrationReadinessReports, MigrationTimeouts, MigrationType, NextMigrationRetryBlock, NextMigrationTimeoutBlock,
+		Pallet, PendingMigrations, PhaseTransitionPoints, PriceOf, ProjectDetailsOf, ProjectId, ProjectMetadataOf,
+		ProjectStatus, ProjectsDetails, ProjectsMetadata, ReceiverCallIndices, StringLimitOf, UserMigrations,
+	};
+	use frame_support::{
+		pallet_prelude::{Decode, Encode, MaxEncodedLen, RuntimeDebug, TypeInfo},
+		traits::{Get, OnRuntimeUpgrade},
+		BoundedVec,
+	};
+	use frame_system::pallet_prelude::BlockNumberFor;
+	use polimec_common::{
+		credentials::{Cid, Did},
+		migration_types::MigrationStatus,
+	};
+	use polkadot_parachain_primitives::primitives::Id as ParaId;
+	use sp_arithmetic::{traits::Saturating, FixedPointNumber, Percent};
+	use sp_core::ConstU32;
+	use sp_std::{marker::PhantomData, vec};
+
+	pub type OldProjectMetadataOf<T> =
+		OldProjectMetadata<BoundedVec<u8, StringLimitOf<T>>, BalanceOf<T>, PriceOf<T>, AccountIdOf<T>, Cid>;
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	pub struct OldProjectMetadata<BoundedString, Balance: PartialOrd + Copy, Price: FixedPointNumber, AccountId, Cid> {
+		pub token_information: CurrencyMetadata<BoundedString>,
+		pub mainnet_token_max_supply: Balance,
+		pub total_allocation_size: Balance,
+		pub auction_round_allocation_percentage: Percent,
+		pub minimum_price: Price,
+		pub bidding_ticket_sizes: BiddingTicketSizes<Price, Balance>,
+		pub contributing_ticket_sizes: ContributingTicketSizes<Price, Balance>,
+		pub participation_currencies:
+			BoundedVec<AcceptedFundingAsset, ConstU32<{ AcceptedFundingAsset::VARIANT_COUNT as u32 }>>,
+		pub funding_destination_account: AccountId,
+		pub policy_ipfs_cid: Option<Cid>,
+	}
+
+	pub type OldProjectDetailsOf<T> =
+		OldProjectDetails<AccountIdOf<T>, Did, BlockNumberFor<T>, PriceOf<T>, BalanceOf<T>, EvaluationRoundInfoOf<T>>;
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	pub struct OldProjectDetails<AccountId, Did, BlockNumber, Price, Balance, EvaluationRoundInfo> {
+		pub issuer_account: AccountId,
+		pub issuer_did: Did,
+		pub is_frozen: bool,
+		pub weighted_average_price: Option<Price>,
+		pub status: ProjectStatus,
+		pub phase_transition_points: PhaseTransitionPoints<BlockNumber>,
+		pub fundraising_target_usd: Balance,
+		pub remaining_contribution_tokens: Balance,
+		pub funding_amount_reached_usd: Balance,
+		pub evaluation_round_info: EvaluationRoundInfo,
+		pub usd_bid_on_oversubscription: Option<Balance>,
+		pub funding_end_block: Option<BlockNumber>,
+		pub parachain_id: Option<ParaId>,
+		pub migration_readiness_check: Option<MigrationReadinessCheck>,
+		pub hrmp_channel_status: HRMPChannelStatus,
+	}
+
+	type OldActiveMigration<T> = (ProjectId, AccountIdOf<T>);
+
+	/// Add the custom phase durations, scheduled phase starts and funding caps to the stored project metadata.
+	/// Existing projects keep the runtime defaults, are started manually and have no caps.
+	///
+	/// Existing projects migrate their Contribution Tokens through the `polimec-receiver` pallet. Before v5, it was
+	/// always expected at pallet index 51 with the original `execute_migrations` call, so the projects that passed the
+	/// pallet check keep sending migrations there, without a token metadata check. Checks awaiting a response are
+	/// indexed by their query id.
+	///
+	/// Queries awaiting a migration response become batches of one participant, timing out from now on, and are
+	/// indexed by their timeout block and their project chain. Settled participants with migrations to send are
+	/// indexed for batch migrations.
+	pub struct UncheckedMigrationToV5<T: Config>(PhantomData<T>);
+	impl<T: Config> OnRuntimeUpgrade for UncheckedMigrationToV5<T> {
+		fn on_runtime_upgrade() -> frame_support::weights::Weight {
+			let mut items = 0u64;
+			ProjectsMetadata::<T>::translate(|_key, old: OldProjectMetadataOf<T>| -> Option<ProjectMetadataOf<T>> {
+				items += 1;
+				Some(ProjectMetadataOf::<T> {
+					token_information: old.token_information,
+					mainnet_token_max_supply: old.mainnet_token_max_supply,
+					total_allocation_size: old.total_allocation_size,
+					auction_round_allocation_percentage: old.auction_round_allocation_percentage,
+					minimum_price: old.minimum_price,
+					bidding_ticket_sizes: old.bidding_ticket_sizes,
+					contributing_ticket_sizes: old.contributing_ticket_sizes,
+					participation_currencies: old.participation_currencies,
+					funding_destination_account: old.funding_destination_account,
+					policy_ipfs_cid: old.policy_ipfs_cid,
+					phase_durations: None,
+					scheduled_starts: Default::default(),
+					funding_caps: None,
+				})
+			});
+
+			ProjectsDetails::<T>::translate(|project_id, old: OldProjectDetailsOf<T>| -> Option<ProjectDetailsOf<T>> {
+				items += 1;
+				let mut receiver_call_indices = None;
+				if let Some(MigrationReadinessCheck { holding_check, pallet_check }) = old.migration_readiness_check {
+					for (query_id, outcome) in [holding_check, pallet_check] {
+						if outcome == CheckOutcome::AwaitingResponse {
+							items += 1;
+							MigrationReadinessQueries::<T>::insert(query_id, project_id);
+						}
+					}
+					// The token metadata was not checked before v5
+					if pallet_check.1 == CheckOutcome::Passed {
+						items += 1;
+						receiver_call_indices = Some(ReceiverCallIndices { pallet: 51, call: 0 });
+						let report = MigrationReadinessReport {
+							metadata_check: Some((pallet_check.0, CheckOutcome::Passed)),
+							..Default::default()
+						};
+						MigrationReadinessReports::<T>::insert(project_id, report);
+					}
+				}
+				Some(ProjectDetailsOf::<T> {
+					issuer_account: old.issuer_account,
+					issuer_did: old.issuer_did,
+					is_frozen: old.is_frozen,
+					weighted_average_price: old.weighted_average_price,
+					status: old.status,
+					phase_transition_points: old.phase_transition_points,
+					fundraising_target_usd: old.fundraising_target_usd,
+					remaining_contribution_tokens: old.remaining_contribution_tokens,
+					funding_amount_reached_usd: old.funding_amount_reached_usd,
+					evaluation_round_info: old.evaluation_round_info,
+					usd_bid_on_oversubscription: old.usd_bid_on_oversubscription,
+					funding_end_block: old.funding_end_block,
+					parachain_id: old.parachain_id,
+					migration_readiness_check: old.migration_readiness_check,
+					hrmp_channel_status: old.hrmp_channel_status,
+					migration_type: MigrationType::Pallet,
+					receiver_call_indices,
+				})
+			});
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let timeout_block = now.saturating_add(T::MigrationQueryTimeout::get());
+			ActiveMigrationQueue::<T>::translate(|query_id, (project_id, participant): OldActiveMigration<T>| {
+				items += 4;
+				let participants = BoundedVec::try_from(vec![participant]).ok()?;
+				MigrationTimeouts::<T>::insert(timeout_block, query_id, ());
+				if let Some(para_id) = ProjectsDetails::<T>::get(project_id).and_then(|details| details.parachain_id) {
+					MigrationQueriesPerParachain::<T>::mutate(para_id, |queries| {
+						*queries = Some(queries.unwrap_or_default().saturating_add(1))
+					});
+				}
+				Some((project_id, participants, timeout_block))
+			});
+			// Nothing was scheduled before this migration
+			NextMigrationTimeoutBlock::<T>::put(now);
+			NextMigrationRetryBlock::<T>::put(now);
+			items += 2;
+
+			let mut participation_reads = 0u64;
+			for (project_id, participant, (status, _)) in UserMigrations::<T>::iter() {
+				// The migrations, and the participations left to settle
+				participation_reads += 4;
+				let pending = matches!(status, MigrationStatus::NotStarted | MigrationStatus::Failed) &&
+					Pallet::<T>::user_has_no_participations(project_id, participant.clone());
+				if pending {
+					items += 1;
+					PendingMigrations::<T>::insert(project_id, participant, ());
+				}
+			}
+
+			T::DbWeight::get().reads_writes(items.saturating_add(participation_reads), items)
+		}
+	}
+
+	pub type MigrationToV5<T> = frame_support::migrations::VersionedMigration<
+		4,
+		5,
+		UncheckedMigrationToV5<T>,
+		crate::Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
@@ -249,6 +249,29 @@ mod create_project_extrinsic {
 				));
 			});
 		}
+		#[test]
+		fn custom_phase_durations() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let mut project_metadata = default_project_metadata(ISSUER_1);
+			let phase_durations = PhaseDurations {
+				evaluation: 15u64,
+				auction_opening: 6u64,
+				auction_closing: 7u64,
+				community_funding: 8u64,
+				remainder_funding: 9u64,
+				manual_acceptance: 11u64,
+				success_to_settlement: 12u64,
+			};
+			project_metadata.phase_durations = Some(phase_durations);
+
+			let project_id = inst.create_new_project(project_metadata.clone(), ISSUER_1);
+			assert_eq!(inst.get_project_metadata(project_id).phase_durations, Some(phase_durations));
+
+			let now = inst.current_block();
+			inst.start_evaluation(project_id, ISSUER_1).unwrap();
+			let evaluation_end = inst.get_project_details(project_id).phase_transition_points.evaluation.end;
+			assert_eq!(evaluation_end, Some(now + phase_durations.evaluation - 1));
+		}
 	}
 
 	#[cfg(test)]
@@ -777,6 +800,92 @@ mod create_project_extrinsic {
 				);
 			});
 		}
+
+		#[test]
+		fn phase_durations_out_of_bounds() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let min_durations = <TestRuntime as Config>::MinPhaseDurations::get();
+			let max_durations = <TestRuntime as Config>::MaxPhaseDurations::get();
+			inst.mint_plmc_to(vec![
+				UserToPLMCBalance::new(ISSUER_1, 10_000_000 * PLMC),
+				UserToPLMCBalance::new(ISSUER_2, 10_000_000 * PLMC),
+				UserToPLMCBalance::new(ISSUER_3, 10_000_000 * PLMC),
+			]);
+
+			let mut assert_durations =
+				|issuer: AccountId, phase_durations: PhaseDurations<BlockNumber>, should_fail: bool| {
+					let mut project_metadata = default_project_metadata(issuer);
+					project_metadata.phase_durations = Some(phase_durations);
+					let jwt = get_mock_jwt_with_cid(
+						issuer,
+						InvestorType::Institutional,
+						generate_did_from_account(issuer),
+						project_metadata.clone().policy_ipfs_cid.unwrap(),
+					);
+					inst.execute(|| {
+						if should_fail {
+							assert_noop!(
+								Pallet::<TestRuntime>::create_project(
+									RuntimeOrigin::signed(issuer),
+									jwt,
+									project_metadata
+								),
+								Error::<TestRuntime>::PhaseDurationsOutOfBounds
+							);
+						} else {
+							assert_ok!(Pallet::<TestRuntime>::create_project(
+								RuntimeOrigin::signed(issuer),
+								jwt,
+								project_metadata
+							));
+						}
+					});
+				};
+
+			assert_durations(ISSUER_1, min_durations, false);
+			assert_durations(ISSUER_2, max_durations, false);
+			let too_short = PhaseDurations { evaluation: min_durations.evaluation - 1, ..min_durations };
+			assert_durations(ISSUER_3, too_short, true);
+			let too_long = PhaseDurations { community_funding: max_durations.community_funding + 1, ..max_durations };
+			assert_durations(ISSUER_3, too_long, true);
+		}
+
+		#[test]
+		fn invalid_funding_caps() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let mut project_metadata = default_project_metadata(ISSUER_1);
+			let funding_target =
+				project_metadata.minimum_price.checked_mul_int(project_metadata.total_allocation_size).unwrap();
+			inst.mint_plmc_to(default_plmc_balances());
+			let jwt = get_mock_jwt_with_cid(
+				ISSUER_1,
+				InvestorType::Institutional,
+				generate_did_from_account(ISSUER_1),
+				project_metadata.clone().policy_ipfs_cid.unwrap(),
+			);
+
+			let invalid_caps = vec![
+				// Soft cap of zero
+				FundingCaps { soft_cap: 0, hard_cap: funding_target },
+				// Soft cap above the funding target
+				FundingCaps { soft_cap: funding_target + 1, hard_cap: funding_target * 2 },
+				// Soft cap above the hard cap
+				FundingCaps { soft_cap: funding_target / 2, hard_cap: funding_target / 4 },
+			];
+			for funding_caps in invalid_caps {
+				project_metadata.funding_caps = Some(funding_caps);
+				inst.execute(|| {
+					assert_noop!(
+						Pallet::<TestRuntime>::create_project(
+							RuntimeOrigin::signed(ISSUER_1),
+							jwt.clone(),
+							project_metadata.clone()
+						),
+						Error::<TestRuntime>::FundingCapsError
+					);
+				});
+			}
+		}
 	}
 }
 
@@ -874,6 +983,9 @@ mod edit_project_extrinsic {
 
 				funding_destination_account: ISSUER_2,
 				policy_ipfs_cid: Some(new_policy_hash),
+				phase_durations: None,
+				scheduled_starts: Default::default(),
+				funding_caps: None,
 			};
 
 			// No fields changed
@@ -57,6 +57,8 @@ mod round_flow {
 			PRICE_MAP.with_borrow_mut(|map| map.insert(PLMC_FOREIGN_ID, old_price / 2.into()));
 
 			inst.start_auction(project_id, ISSUER_1).unwrap();
+			// The halved price trips the circuit breaker, so wait for it to reset before evaluating again.
+			inst.advance_time(<TestRuntime as Config>::PriceCircuitBreakerCooldown::get()).unwrap();
 
 			// Increasing the price before the end doesn't make a project under the threshold succeed.
 			let evaluations = vec![(EVALUATOR_1, target_evaluation_usd / 2).into()];
@@ -333,6 +335,8 @@ mod start_evaluation_extrinsic {
 					project_to_polimec: ChannelStatus::Closed,
 					polimec_to_project: ChannelStatus::Closed,
 				},
+				migration_type: MigrationType::Pallet,
+				receiver_call_indices: None,
 			};
 			assert_ok!(inst.execute(|| PolimecFunding::start_evaluation(
 				RuntimeOrigin::signed(issuer),
@@ -342,6 +346,32 @@ mod start_evaluation_extrinsic {
 
 			assert_eq!(inst.get_project_details(project_id), expected_details);
 		}
+
+		#[test]
+		fn scheduled_evaluation_start() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let issuer = ISSUER_1;
+			let mut project_metadata = default_project_metadata(issuer);
+			let now = inst.current_block();
+			project_metadata.scheduled_starts.evaluation = Some(now + 5);
+
+			let project_id = inst.create_new_project(project_metadata.clone(), issuer);
+
+			// The issuer postpones the evaluation before it starts
+			project_metadata.scheduled_starts.evaluation = Some(now + 10);
+			assert_ok!(inst.execute(|| PolimecFunding::do_edit_project(issuer, project_id, project_metadata.clone())));
+
+			// The outdated schedule is skipped
+			inst.advance_time(5).unwrap();
+			assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::Application);
+
+			inst.advance_time(4).unwrap();
+			assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::Application);
+			inst.advance_time(1).unwrap();
+			let project_details = inst.get_project_details(project_id);
+			assert_eq!(project_details.status, ProjectStatus::EvaluationRound);
+			assert_eq!(project_details.phase_transition_points.evaluation.start(), Some(now + 10));
+		}
 	}
 
 	#[cfg(test)]
@@ -349,6 +379,24 @@ mod start_evaluation_extrinsic {
 		use super::*;
 		use polimec_common_test_utils::get_mock_jwt;
 
+		#[test]
+		fn scheduled_evaluation_start_in_the_past() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let issuer = ISSUER_1;
+			let mut project_metadata = default_project_metadata(issuer);
+			inst.advance_time(10).unwrap();
+			let now = inst.current_block();
+			project_metadata.scheduled_starts.evaluation = Some(now);
+
+			inst.mint_plmc_to(default_plmc_balances());
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::do_create_project(&issuer, project_metadata, generate_did_from_account(issuer)),
+					Error::<TestRuntime>::InvalidPhaseSchedule
+				);
+			});
+		}
+
 		#[test]
 		fn non_institutional_jwt() {
 			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
@@ -601,6 +649,12 @@ mod evaluate_extrinsic {
 			)));
 
 			inst.execute(|| {
+				let plmc_usd_price = <TestRuntime as Config>::BondingPriceProvider::get_decimals_aware_price(
+					PLMC_FOREIGN_ID,
+					USD_DECIMALS,
+					PLMC_DECIMALS,
+				)
+				.unwrap();
 				let evaluations = Evaluations::<TestRuntime>::iter_prefix_values((project_id,)).collect_vec();
 				assert_eq!(evaluations.len(), 1);
 				let stored_evaluation = &evaluations[0];
@@ -614,6 +668,7 @@ mod evaluate_extrinsic {
 					early_usd_amount: evaluation.usd_amount,
 					late_usd_amount: 0,
 					when: 1,
+					plmc_usd_price,
 				};
 				assert_eq!(stored_evaluation, &expected_evaluation_item);
 			});
@@ -649,6 +704,58 @@ mod evaluate_extrinsic {
 			});
 		}
 
+		#[test]
+		fn plmc_price_feed_paused() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let issuer = ISSUER_1;
+			let project_metadata = default_project_metadata(issuer);
+			let evaluations = vec![UserToUSDBalance::new(EVALUATOR_1, 500 * USD_UNIT)];
+			let evaluation_plmc = inst.calculate_evaluation_plmc_spent(evaluations.clone());
+			inst.mint_plmc_to(evaluation_plmc.clone());
+			inst.mint_plmc_to(evaluation_plmc.accounts().existential_deposits());
+
+			let project_id = inst.create_evaluating_project(project_metadata.clone(), issuer);
+
+			let old_price = <TestRuntime as Config>::PriceProvider::get_price(PLMC_FOREIGN_ID).unwrap();
+			PRICE_MAP.with_borrow_mut(|map| map.insert(PLMC_FOREIGN_ID, old_price * FixedU128::from_rational(11, 10)));
+			inst.advance_time(1).unwrap();
+			inst.execute(|| {
+				System::assert_has_event(RuntimeEvent::PolimecFunding(Event::PriceCircuitBreakerTripped {
+					asset_id: PLMC_FOREIGN_ID,
+					issue: PriceFeedIssue::ExcessiveDeviation,
+				}));
+			});
+
+			let evaluate = |inst: &mut MockInstantiator, project_id: ProjectId| {
+				inst.execute(|| {
+					PolimecFunding::evaluate(
+						RuntimeOrigin::signed(EVALUATOR_1),
+						get_mock_jwt_with_cid(
+							EVALUATOR_1,
+							InvestorType::Retail,
+							generate_did_from_account(EVALUATOR_1),
+							project_metadata.clone().policy_ipfs_cid.unwrap(),
+						),
+						project_id,
+						500 * USD_UNIT,
+					)
+				})
+			};
+			assert_err!(evaluate(&mut inst, project_id), Error::<TestRuntime>::PriceFeedPaused);
+
+			// The new price holds steady, so the feed is trusted again after the cooldown. By then the first
+			// evaluation round is over, so we try again on a new project.
+			inst.advance_time(<TestRuntime as Config>::PriceCircuitBreakerCooldown::get()).unwrap();
+			inst.execute(|| {
+				assert!(!PausedPriceFeeds::<TestRuntime>::contains_key(PLMC_FOREIGN_ID));
+				System::assert_has_event(RuntimeEvent::PolimecFunding(Event::PriceCircuitBreakerReset {
+					asset_id: PLMC_FOREIGN_ID,
+				}));
+			});
+			let project_id = inst.create_evaluating_project(project_metadata.clone(), ISSUER_2);
+			assert_ok!(evaluate(&mut inst, project_id));
+		}
+
 		#[test]
 		fn insufficient_plmc_for_desired_evaluation() {
 			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
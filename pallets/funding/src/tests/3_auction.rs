@@ -84,6 +84,9 @@ mod round_flow {
 				participation_currencies: vec![AcceptedFundingAsset::USDT].try_into().unwrap(),
 				funding_destination_account: ISSUER_1,
 				policy_ipfs_cid: Some(metadata_hash),
+				phase_durations: None,
+				scheduled_starts: Default::default(),
+				funding_caps: None,
 			};
 
 			// overfund with plmc
@@ -579,6 +582,7 @@ mod round_flow {
 					AcceptedFundingAsset::USDT => usdt_price,
 					AcceptedFundingAsset::USDC => usdc_price,
 					AcceptedFundingAsset::DOT => dot_price,
+					AcceptedFundingAsset::PLMC => usable_plmc_price,
 				};
 
 				let mut project_metadata = default_project_metadata.clone();
@@ -702,6 +706,72 @@ mod round_flow {
 			let higher_than_wap_bids = all_bids.iter().filter(|bid| bid.original_ct_usd_price > wap).collect_vec();
 			assert_eq!(higher_than_wap_bids.len(), (max_bids_per_project - 1u32) as usize);
 		}
+
+		#[test]
+		fn partial_refund_of_multiplied_bid_does_not_release_stake() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let issuer = ISSUER_1;
+			let project_metadata = default_project_metadata(issuer);
+			let evaluations = default_evaluations();
+
+			// Same buckets as in `bids_get_rejected_and_refunded_part_one`. Both bids of BIDDER_3 are above the wap.
+			let filler_bids = vec![
+				BidParams::new(BIDDER_1, 5000 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT),
+				BidParams::new(BIDDER_2, 40_000 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT),
+				BidParams::new(BIDDER_1, 10_000 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT),
+			];
+			let staked_bid = BidParams::new(BIDDER_3, 5000 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT);
+			let multiplied_bid = BidParams::new(BIDDER_3, 1000 * CT_UNIT, 3u8, AcceptedFundingAsset::USDT);
+			let last_bid = BidParams::new(BIDDER_4, 2000 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT);
+			let bids = vec![filler_bids.clone(), vec![staked_bid.clone(), multiplied_bid.clone(), last_bid.clone()]]
+				.into_iter()
+				.flatten()
+				.collect_vec();
+
+			let project_id = inst.create_auctioning_project(project_metadata.clone(), issuer, evaluations);
+
+			let plmc_fundings = inst.calculate_auction_plmc_charged_from_all_bids_made_or_with_bucket(
+				&bids,
+				project_metadata.clone(),
+				None,
+			);
+			let usdt_fundings = inst.calculate_auction_funding_asset_charged_from_all_bids_made_or_with_bucket(
+				&bids,
+				project_metadata.clone(),
+				None,
+			);
+			let other_plmc_fundings =
+				plmc_fundings.clone().into_iter().filter(|funding| funding.account != BIDDER_3).collect_vec();
+			inst.mint_plmc_to(other_plmc_fundings);
+			inst.mint_plmc_to(plmc_fundings.accounts().existential_deposits());
+			inst.mint_foreign_asset_to(usdt_fundings);
+			inst.bid_for_users(project_id, filler_bids).unwrap();
+
+			// The bond with a multiplier of 1 comes from the stake, and the other one from the free balance
+			MockStakedPlmc::set_stake(BIDDER_3, 100_000 * PLMC);
+			inst.bid_for_users(project_id, vec![staked_bid]).unwrap();
+			inst.mint_plmc_to(vec![UserToPLMCBalance::new(BIDDER_3, 100_000 * PLMC)]);
+			inst.bid_for_users(project_id, vec![multiplied_bid, last_bid]).unwrap();
+
+			inst.start_community_funding(project_id).unwrap();
+
+			let bidder_3_bids =
+				inst.get_bids(project_id).into_iter().filter(|bid| bid.bidder == BIDDER_3).collect_vec();
+			let staked = bidder_3_bids.iter().find(|bid| bid.multiplier.into() == 1u8).unwrap();
+			let multiplied = bidder_3_bids.iter().find(|bid| bid.multiplier.into() == 3u8).unwrap();
+			assert!(multiplied.final_ct_usd_price < multiplied.original_ct_usd_price);
+
+			// Only the refund of the bid with a multiplier of 1 released stake
+			assert_eq!(MockStakedPlmc::stake_in_use(&BIDDER_3), staked.plmc_bond);
+			assert_eq!(
+				inst.execute(|| StakedParticipationBonds::<TestRuntime>::get(BIDDER_3, project_id)),
+				staked.plmc_bond
+			);
+			inst.do_reserved_plmc_assertions(
+				vec![UserToPLMCBalance::new(BIDDER_3, multiplied.plmc_bond)],
+				HoldReason::Participation(project_id).into(),
+			);
+		}
 	}
 
 	#[cfg(test)]
@@ -801,12 +871,137 @@ mod start_auction_extrinsic {
 				});
 			}
 		}
+
+		#[test]
+		fn scheduled_auction_start() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = inst.create_evaluating_project(default_project_metadata(ISSUER_1), ISSUER_1);
+			let evaluations = default_evaluations();
+			let required_plmc = inst.calculate_evaluation_plmc_spent(evaluations.clone());
+			let ed_plmc = required_plmc.accounts().existential_deposits();
+			inst.mint_plmc_to(required_plmc);
+			inst.mint_plmc_to(ed_plmc);
+			inst.evaluate_for_users(project_id, evaluations).unwrap();
+
+			let evaluation_end = inst.get_project_details(project_id).phase_transition_points.evaluation.end().unwrap();
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::do_schedule_auction_start(ISSUER_1, project_id, Some(evaluation_end + 4)));
+			});
+			assert_eq!(inst.get_project_metadata(project_id).scheduled_starts.auction, Some(evaluation_end + 4));
+
+			let now = inst.current_block();
+			inst.advance_time(evaluation_end - now + 1).unwrap();
+			assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::AuctionInitializePeriod);
+
+			// The issuer postpones the auction while waiting for it
+			inst.execute(|| {
+				assert_ok!(PolimecFunding::do_schedule_auction_start(ISSUER_1, project_id, Some(evaluation_end + 6)));
+			});
+
+			// The outdated schedule is skipped
+			inst.advance_time(4).unwrap();
+			assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::AuctionInitializePeriod);
+
+			inst.advance_time(1).unwrap();
+			let project_details = inst.get_project_details(project_id);
+			assert_eq!(project_details.status, ProjectStatus::AuctionOpening);
+			assert_eq!(project_details.phase_transition_points.auction_opening.start(), Some(evaluation_end + 6));
+		}
+
+		#[test]
+		fn scheduled_auction_start_waits_if_evaluation_started_early() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let mut project_metadata = default_project_metadata(ISSUER_1);
+			let now = inst.current_block();
+			let evaluation_duration = <TestRuntime as Config>::EvaluationDuration::get();
+			let initialize_period_duration = <TestRuntime as Config>::AuctionInitializePeriodDuration::get();
+			// The latest auction start allowed for an evaluation starting in 5 blocks
+			let auction_start = now + 5 + evaluation_duration + initialize_period_duration;
+			project_metadata.scheduled_starts.evaluation = Some(now + 5);
+			project_metadata.scheduled_starts.auction = Some(auction_start);
+			let project_id = inst.create_new_project(project_metadata, ISSUER_1);
+
+			// The issuer starts the evaluation right away instead
+			inst.start_evaluation(project_id, ISSUER_1).unwrap();
+			let evaluations = default_evaluations();
+			let required_plmc = inst.calculate_evaluation_plmc_spent(evaluations.clone());
+			let ed_plmc = required_plmc.accounts().existential_deposits();
+			inst.mint_plmc_to(required_plmc);
+			inst.mint_plmc_to(ed_plmc);
+			inst.evaluate_for_users(project_id, evaluations).unwrap();
+
+			let evaluation_end = inst.get_project_details(project_id).phase_transition_points.evaluation.end().unwrap();
+			let now = inst.current_block();
+			inst.advance_time(evaluation_end - now + 1).unwrap();
+			let transition_points = inst.get_project_details(project_id).phase_transition_points;
+			assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::AuctionInitializePeriod);
+			assert_eq!(transition_points.auction_initialize_period.end(), Some(auction_start - 1));
+
+			// The project waits in the initialize period until the scheduled block
+			let now = inst.current_block();
+			inst.advance_time(auction_start - now - 1).unwrap();
+			assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::AuctionInitializePeriod);
+
+			inst.advance_time(1).unwrap();
+			let project_details = inst.get_project_details(project_id);
+			assert_eq!(project_details.status, ProjectStatus::AuctionOpening);
+			assert_eq!(project_details.phase_transition_points.auction_opening.start(), Some(auction_start));
+		}
 	}
 
 	#[cfg(test)]
 	mod failure {
 		use super::*;
 
+		#[test]
+		fn cannot_schedule_auction_start_during_evaluation() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_id = inst.create_evaluating_project(default_project_metadata(ISSUER_1), ISSUER_1);
+			let evaluation_end = inst.get_project_details(project_id).phase_transition_points.evaluation.end().unwrap();
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::do_schedule_auction_start(ISSUER_1, project_id, Some(evaluation_end)),
+					Error::<TestRuntime>::InvalidPhaseSchedule
+				);
+				assert_noop!(
+					PolimecFunding::do_schedule_auction_start(ISSUER_2, project_id, Some(evaluation_end + 5)),
+					Error::<TestRuntime>::NotIssuer
+				);
+			});
+		}
+
+		#[test]
+		fn cannot_schedule_auction_start_after_the_initialize_period() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let initialize_period_duration = <TestRuntime as Config>::AuctionInitializePeriodDuration::get();
+
+			// At creation, the initialize period follows the scheduled evaluation
+			let mut project_metadata = default_project_metadata(ISSUER_1);
+			let now = inst.current_block();
+			let evaluation_end = now + 5 + <TestRuntime as Config>::EvaluationDuration::get() - 1;
+			project_metadata.scheduled_starts.evaluation = Some(now + 5);
+			project_metadata.scheduled_starts.auction = Some(evaluation_end + initialize_period_duration + 2);
+			inst.mint_plmc_to(default_plmc_balances());
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::do_create_project(&ISSUER_1, project_metadata, generate_did_from_account(ISSUER_1)),
+					Error::<TestRuntime>::InvalidPhaseSchedule
+				);
+			});
+
+			// During the evaluation, it follows the evaluation end
+			let project_id = inst.create_evaluating_project(default_project_metadata(ISSUER_2), ISSUER_2);
+			let evaluation_end = inst.get_project_details(project_id).phase_transition_points.evaluation.end().unwrap();
+			let latest_auction_start = evaluation_end + initialize_period_duration + 1;
+			inst.execute(|| {
+				assert_noop!(
+					PolimecFunding::do_schedule_auction_start(ISSUER_2, project_id, Some(latest_auction_start + 1)),
+					Error::<TestRuntime>::InvalidPhaseSchedule
+				);
+				assert_ok!(PolimecFunding::do_schedule_auction_start(ISSUER_2, project_id, Some(latest_auction_start)));
+			});
+		}
+
 		#[test]
 		fn cannot_start_auction_manually_before_evaluation_finishes() {
 			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
@@ -915,6 +1110,15 @@ mod bid_extrinsic {
 				inst.execute(|| Bids::<TestRuntime>::iter_prefix_values((project_id, evaluator_bidder)).collect_vec());
 			assert_eq!(bid_items.len(), 1);
 			assert_eq!(bid_items[0].plmc_bond, necessary_plmc_for_bid);
+			let plmc_usd_price = inst.execute(|| {
+				<TestRuntime as Config>::BondingPriceProvider::get_decimals_aware_price(
+					PLMC_FOREIGN_ID,
+					USD_DECIMALS,
+					PLMC_DECIMALS,
+				)
+				.unwrap()
+			});
+			assert_eq!(bid_items[0].plmc_usd_price, plmc_usd_price);
 
 			inst.do_reserved_plmc_assertions(
 				vec![UserToPLMCBalance::new(evaluator_bidder, necessary_plmc_for_bid)],
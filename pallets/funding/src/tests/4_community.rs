@@ -216,6 +216,7 @@ mod round_flow {
 					AcceptedFundingAsset::USDT => usdt_price,
 					AcceptedFundingAsset::USDC => usdc_price,
 					AcceptedFundingAsset::DOT => dot_price,
+					AcceptedFundingAsset::PLMC => usable_plmc_price,
 				};
 
 				let mut project_metadata = default_project_metadata.clone();
@@ -538,6 +539,142 @@ mod community_contribute_extrinsic {
 			));
 		}
 
+		#[test]
+		fn contribute_with_plmc() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let mut project_metadata = default_project_metadata(ISSUER_1);
+			project_metadata.participation_currencies =
+				vec![AcceptedFundingAsset::USDT, AcceptedFundingAsset::PLMC].try_into().unwrap();
+			let bids = vec![
+				BidParams::new_with_defaults(BIDDER_1, 40_000 * CT_UNIT),
+				BidParams::new_with_defaults(BIDDER_2, 10_000 * CT_UNIT),
+			];
+			let project_id = inst.create_community_contributing_project(
+				project_metadata.clone(),
+				ISSUER_1,
+				default_evaluations(),
+				bids,
+			);
+			const BOB: AccountId = 808;
+
+			let remaining_ct = inst.get_project_details(project_id).remaining_contribution_tokens;
+			let ct_price = inst.get_project_details(project_id).weighted_average_price.unwrap();
+			let contributions = vec![ContributionParams::new(BOB, remaining_ct, 1u8, AcceptedFundingAsset::PLMC)];
+
+			// The participation bond and the payment are both made in PLMC
+			let plmc_bonds = inst.calculate_contributed_plmc_spent(contributions.clone(), ct_price);
+			let plmc_existential_deposits = plmc_bonds.accounts().existential_deposits();
+			let plmc_payment =
+				inst.calculate_contributed_funding_asset_spent(contributions.clone(), ct_price)[0].asset_amount;
+			inst.mint_plmc_to(plmc_bonds.clone());
+			inst.mint_plmc_to(plmc_existential_deposits.clone());
+			inst.mint_plmc_to(vec![UserToPLMCBalance::new(BOB, plmc_payment)]);
+
+			let project_pot = inst.execute(|| PolimecFunding::fund_account_id(project_id));
+			let prev_pot_balance = inst.get_free_plmc_balances_for(vec![project_pot])[0].plmc_amount;
+
+			assert_ok!(inst.contribute_for_users(project_id, contributions));
+			inst.advance_time(2u64).unwrap();
+			assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::FundingSuccessful);
+
+			// Only the bond is held, the payment was transferred to the project pot
+			inst.do_free_plmc_assertions(plmc_existential_deposits);
+			inst.do_reserved_plmc_assertions(plmc_bonds, HoldReason::Participation(project_id).into());
+			assert_eq!(
+				inst.get_free_plmc_balances_for(vec![project_pot])[0].plmc_amount,
+				prev_pot_balance + plmc_payment
+			);
+
+			// On settlement, the PLMC is paid out to the issuer like any other funding asset
+			let bids = inst.get_bids(project_id);
+			let contributions = inst.get_contributions(project_id);
+			let settlement_execution = inst.get_update_block(project_id, &UpdateType::StartSettlement).unwrap();
+			inst.jump_to_block(settlement_execution);
+			inst.settle_project(project_id).unwrap();
+			inst.assert_total_funding_paid_out(project_id, bids, contributions);
+		}
+
+		#[test]
+		fn contribute_with_staked_plmc() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id = inst.create_community_contributing_project(
+				project_metadata.clone(),
+				ISSUER_1,
+				default_evaluations(),
+				default_bids(),
+			);
+			let contribution = ContributionParams::new(BUYER_1, 1000 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT);
+			let wap = inst.get_project_details(project_id).weighted_average_price.unwrap();
+
+			// Only a third of the bond is free, the rest is staked
+			let plmc_bond = inst.calculate_contributed_plmc_spent(vec![contribution.clone()], wap)[0].plmc_amount;
+			let free_plmc = plmc_bond / 3;
+			let staked_plmc = plmc_bond - free_plmc;
+			let ed = inst.get_ed();
+			let usdt_payment = inst.calculate_contributed_funding_asset_spent(vec![contribution.clone()], wap);
+			inst.mint_plmc_to(vec![UserToPLMCBalance::new(BUYER_1, free_plmc + ed)]);
+			inst.mint_foreign_asset_to(usdt_payment);
+
+			assert_err!(
+				inst.contribute_for_users(project_id, vec![contribution.clone()]),
+				Error::<TestRuntime>::ParticipantNotEnoughFunds
+			);
+
+			MockStakedPlmc::set_stake(BUYER_1, staked_plmc + 100 * PLMC);
+			assert_ok!(inst.contribute_for_users(project_id, vec![contribution.clone()]));
+
+			// Only the missing part of the bond is taken from the stake, and it stays staked
+			assert_eq!(MockStakedPlmc::stake_in_use(&BUYER_1), staked_plmc);
+			assert_eq!(MockStakedPlmc::usable_stake(&BUYER_1), 100 * PLMC);
+			assert_eq!(
+				inst.execute(|| StakedParticipationBonds::<TestRuntime>::get(BUYER_1, project_id)),
+				staked_plmc
+			);
+			inst.do_reserved_plmc_assertions(
+				vec![UserToPLMCBalance::new(BUYER_1, free_plmc)],
+				HoldReason::Participation(project_id).into(),
+			);
+			inst.do_free_plmc_assertions(vec![UserToPLMCBalance::new(BUYER_1, ed)]);
+		}
+
+		#[test]
+		fn staked_plmc_is_held_again_if_it_stops_being_staked() {
+			let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+			let project_metadata = default_project_metadata(ISSUER_1);
+			let project_id = inst.create_community_contributing_project(
+				project_metadata.clone(),
+				ISSUER_1,
+				default_evaluations(),
+				default_bids(),
+			);
+			let contribution = ContributionParams::new(BUYER_1, 1000 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT);
+			let wap = inst.get_project_details(project_id).weighted_average_price.unwrap();
+			let plmc_bond = inst.calculate_contributed_plmc_spent(vec![contribution.clone()], wap)[0].plmc_amount;
+			let ed = inst.get_ed();
+			let usdt_payment = inst.calculate_contributed_funding_asset_spent(vec![contribution.clone()], wap);
+			inst.mint_plmc_to(vec![UserToPLMCBalance::new(BUYER_1, ed)]);
+			inst.mint_foreign_asset_to(usdt_payment);
+			MockStakedPlmc::set_stake(BUYER_1, plmc_bond);
+			assert_ok!(inst.contribute_for_users(project_id, vec![contribution.clone()]));
+
+			// The staking side released part of the stake in use to the free balance of the participant
+			let freed = plmc_bond / 4;
+			MockStakedPlmc::release_stake(&BUYER_1, freed);
+			inst.mint_plmc_to(vec![UserToPLMCBalance::new(BUYER_1, freed)]);
+			inst.execute(|| PolimecFunding::hold_freed_staked_bonds(&BUYER_1, freed));
+
+			assert_eq!(
+				inst.execute(|| StakedParticipationBonds::<TestRuntime>::get(BUYER_1, project_id)),
+				plmc_bond - freed
+			);
+			inst.do_reserved_plmc_assertions(
+				vec![UserToPLMCBalance::new(BUYER_1, freed)],
+				HoldReason::Participation(project_id).into(),
+			);
+			inst.do_free_plmc_assertions(vec![UserToPLMCBalance::new(BUYER_1, ed)]);
+		}
+
 		fn test_contribution_setup(
 			inst: &mut MockInstantiator,
 			project_id: ProjectId,
@@ -225,6 +225,7 @@ mod round_flow {
 					AcceptedFundingAsset::USDT => usdt_price,
 					AcceptedFundingAsset::USDC => usdc_price,
 					AcceptedFundingAsset::DOT => dot_price,
+					AcceptedFundingAsset::PLMC => usable_plmc_price,
 				};
 
 				let mut project_metadata = default_project_metadata.clone();
@@ -1108,6 +1109,9 @@ mod remaining_contribute_extrinsic {
 				participation_currencies: vec![AcceptedFundingAsset::USDT].try_into().unwrap(),
 				funding_destination_account: ISSUER_1,
 				policy_ipfs_cid: Some(ipfs_hash()),
+				phase_durations: None,
+				scheduled_starts: Default::default(),
+				funding_caps: None,
 			};
 
 			let project_id = inst.create_remainder_contributing_project(
@@ -1221,6 +1225,9 @@ mod remaining_contribute_extrinsic {
 				participation_currencies: vec![AcceptedFundingAsset::USDT].try_into().unwrap(),
 				funding_destination_account: ISSUER_1,
 				policy_ipfs_cid: Some(ipfs_hash()),
+				phase_durations: None,
+				scheduled_starts: Default::default(),
+				funding_caps: None,
 			};
 
 			let project_id = inst.create_remainder_contributing_project(
@@ -28,6 +28,88 @@ fn manual_rejection_percentage_between_34_89() {
 	}
 }
 
+#[test]
+fn soft_cap_decides_outcome() {
+	let outcomes = vec![
+		(15, ProjectStatus::FundingFailed),
+		(30, ProjectStatus::AwaitingProjectDecision),
+		(55, ProjectStatus::FundingSuccessful),
+	];
+	for (funding_percent, expected_status) in outcomes {
+		let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+		let mut project_metadata = default_project_metadata(ISSUER_1);
+		let min_price = project_metadata.minimum_price;
+		let funding_target = min_price.checked_mul_int(project_metadata.total_allocation_size).unwrap();
+		// With a soft cap of 45%, a project fails at 16.5% of the target, instead of the default 33%.
+		project_metadata.funding_caps =
+			Some(FundingCaps { soft_cap: Perquintill::from_percent(45) * funding_target, hard_cap: funding_target });
+
+		let funded_usd = Perquintill::from_percent(funding_percent) * funding_target;
+		let bids = inst.generate_bids_from_total_usd(
+			Percent::from_percent(50u8) * funded_usd,
+			min_price,
+			default_weights(),
+			default_bidders(),
+			default_multipliers(),
+		);
+		let contributions = inst.generate_contributions_from_total_usd(
+			Percent::from_percent(50u8) * funded_usd,
+			min_price,
+			default_weights(),
+			default_community_contributors(),
+			default_multipliers(),
+		);
+		let project_id = inst.create_finished_project(
+			project_metadata,
+			ISSUER_1,
+			default_evaluations(),
+			bids,
+			contributions,
+			vec![],
+		);
+		assert_eq!(inst.get_project_details(project_id).status, expected_status);
+	}
+}
+
+#[test]
+fn hard_cap_ends_funding_early() {
+	let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+	let mut project_metadata = default_project_metadata(ISSUER_1);
+	let hard_cap = 1_000_000 * USD_UNIT;
+	project_metadata.funding_caps = Some(FundingCaps { soft_cap: 800_000 * USD_UNIT, hard_cap });
+	let bids = vec![
+		BidParams::new_with_defaults(BIDDER_1, 40_000 * CT_UNIT),
+		BidParams::new_with_defaults(BIDDER_2, 10_000 * CT_UNIT),
+	];
+	let project_id =
+		inst.create_community_contributing_project(project_metadata.clone(), ISSUER_1, default_evaluations(), bids);
+	let project_details = inst.get_project_details(project_id);
+	let ct_price = project_details.weighted_average_price.unwrap();
+	let tokens_until_hard_cap =
+		inst.execute(|| PolimecFunding::tokens_until_hard_cap(&project_metadata, &project_details)).unwrap().unwrap();
+	assert!(tokens_until_hard_cap < project_details.remaining_contribution_tokens);
+
+	// The contribution is cut down to the tokens left until the hard cap
+	let contributions =
+		vec![ContributionParams::new(BUYER_1, tokens_until_hard_cap + 1000 * CT_UNIT, 1u8, AcceptedFundingAsset::USDT)];
+	let plmc_bonds = inst.calculate_contributed_plmc_spent(contributions.clone(), ct_price);
+	inst.mint_plmc_to(plmc_bonds.clone());
+	inst.mint_plmc_to(plmc_bonds.accounts().existential_deposits());
+	let usdt_payments = inst.calculate_contributed_funding_asset_spent(contributions.clone(), ct_price);
+	inst.mint_foreign_asset_to(usdt_payments);
+	assert_ok!(inst.contribute_for_users(project_id, contributions));
+
+	let contribution = inst.get_contributions(project_id).pop().unwrap();
+	assert_eq!(contribution.ct_amount, tokens_until_hard_cap);
+	let project_details = inst.get_project_details(project_id);
+	assert!(project_details.remaining_contribution_tokens > Zero::zero());
+	assert!(inst.execute(|| PolimecFunding::is_hard_cap_reached(&project_metadata, &project_details)));
+
+	// The funding ends without waiting for the end of the community round
+	inst.advance_time(2u64).unwrap();
+	assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::FundingSuccessful);
+}
+
 #[test]
 fn automatic_acceptance_on_manual_decision_after_time_delta() {
 	let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
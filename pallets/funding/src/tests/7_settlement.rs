@@ -202,6 +202,71 @@ fn evaluator_slashed_if_between_33_and_75() {
 	}
 }
 
+/// Test that the evaluator slash only takes the evaluation bond, and never the stake used as participation bond.
+#[test]
+fn evaluator_slash_does_not_take_stake_in_use() {
+	let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+	let project_metadata = default_project_metadata(ISSUER_1);
+	let min_price = project_metadata.minimum_price;
+	let half_funding_usd = Perquintill::from_percent(50) *
+		(project_metadata.minimum_price.checked_mul_int(project_metadata.total_allocation_size).unwrap());
+	let bids = inst.generate_bids_from_total_usd(
+		Percent::from_percent(50u8) * half_funding_usd,
+		min_price,
+		default_weights(),
+		default_bidders(),
+		default_multipliers(),
+	);
+	let project_id =
+		inst.create_community_contributing_project(project_metadata.clone(), ISSUER_1, default_evaluations(), bids);
+
+	// The evaluator contributes with their evaluation bond, minus the slash deposit, and their stake
+	let evaluation = inst.get_evaluations(project_id).into_iter().find(|e| e.evaluator == EVALUATOR_1).unwrap();
+	let slash_deposit = <TestRuntime as Config>::EvaluatorSlash::get() * evaluation.original_plmc_bond;
+	let wap = inst.get_project_details(project_id).weighted_average_price.unwrap();
+	let ct_amount = wap.reciprocal().unwrap().saturating_mul_int(Percent::from_percent(50u8) * half_funding_usd);
+	let contribution = ContributionParams::new(EVALUATOR_1, ct_amount, 1u8, AcceptedFundingAsset::USDT);
+	let plmc_bond = inst.calculate_contributed_plmc_spent(vec![contribution.clone()], wap)[0].plmc_amount;
+	let from_stake = plmc_bond - (evaluation.current_plmc_bond - slash_deposit);
+	let usdt_payment = inst.calculate_contributed_funding_asset_spent(vec![contribution.clone()], wap);
+	inst.mint_foreign_asset_to(usdt_payment);
+	MockStakedPlmc::set_stake(EVALUATOR_1, plmc_bond);
+	assert_ok!(inst.contribute_for_users(project_id, vec![contribution]));
+	assert_eq!(MockStakedPlmc::stake_in_use(&EVALUATOR_1), from_stake);
+
+	// The project is only half funded and the issuer rejects it, so evaluators get slashed
+	inst.finish_funding(project_id).unwrap();
+	assert_eq!(inst.get_project_details(project_id).status, ProjectStatus::AwaitingProjectDecision);
+	inst.execute(|| {
+		PolimecFunding::do_decide_project_outcome(ISSUER_1, project_id, FundingOutcomeDecision::RejectFunding)
+	})
+	.unwrap();
+	let decision_block = inst
+		.get_update_block(project_id, &UpdateType::ProjectDecision(FundingOutcomeDecision::RejectFunding))
+		.unwrap();
+	inst.jump_to_block(decision_block);
+	let settlement_block = inst.get_update_block(project_id, &UpdateType::StartSettlement).unwrap();
+	inst.jump_to_block(settlement_block);
+
+	let treasury = <TestRuntime as Config>::ProtocolGrowthTreasury::get();
+	let prev_treasury_balance = inst.get_free_plmc_balances_for(vec![treasury])[0].plmc_amount;
+	inst.settle_project(project_id).unwrap();
+
+	// The slash came from the evaluation bond, and the whole stake is usable again
+	assert_eq!(inst.get_free_plmc_balances_for(vec![treasury])[0].plmc_amount, prev_treasury_balance + slash_deposit);
+	assert_eq!(MockStakedPlmc::stake_in_use(&EVALUATOR_1), 0);
+	assert_eq!(MockStakedPlmc::usable_stake(&EVALUATOR_1), plmc_bond);
+	assert_eq!(inst.execute(|| StakedParticipationBonds::<TestRuntime>::get(EVALUATOR_1, project_id)), 0);
+	inst.do_reserved_plmc_assertions(
+		vec![UserToPLMCBalance::new(EVALUATOR_1, 0)],
+		HoldReason::Participation(project_id).into(),
+	);
+	inst.do_reserved_plmc_assertions(
+		vec![UserToPLMCBalance::new(EVALUATOR_1, 0)],
+		HoldReason::Evaluation(project_id).into(),
+	);
+}
+
 // Test that the evaluators PLMC bond is not slashed if the project is between 76 and 89
 // percent funded independent of the project outcome.
 #[test]
@@ -1,5 +1,9 @@
 use super::*;
-use frame_support::assert_err;
+use frame_support::{
+	assert_err,
+	traits::{fungibles, tokens::Preservation},
+	weights::Weight,
+};
 
 #[test]
 fn para_id_for_project_can_be_set_by_issuer() {
@@ -55,3 +59,653 @@ fn para_id_for_project_cannot_be_set_by_anyone_but_issuer() {
 	let project_details = inst.get_project_details(project_id);
 	assert_eq!(project_details.parachain_id, None);
 }
+
+#[test]
+fn timed_out_migrations_are_failed_and_retried_with_backoff() {
+	let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+	let project_id = 0;
+	let query_id = 1;
+	let timeout: u64 = <TestRuntime as Config>::MigrationQueryTimeout::get();
+	let backoff: u64 = <TestRuntime as Config>::MigrationRetryBackoff::get();
+	let max_retries: u32 = <TestRuntime as Config>::MaxMigrationRetries::get();
+
+	inst.execute(|| {
+		System::set_block_number(1);
+		let sent_block = System::block_number();
+		for participant in [BUYER_1, BUYER_2] {
+			UserMigrations::<TestRuntime>::insert(
+				project_id,
+				participant,
+				(MigrationStatus::Sent(query_id), BoundedVec::default()),
+			);
+		}
+		ActiveMigrationQueue::<TestRuntime>::insert(
+			query_id,
+			(project_id, BoundedVec::try_from(vec![BUYER_1, BUYER_2]).unwrap(), sent_block + timeout),
+		);
+		MigrationTimeouts::<TestRuntime>::insert(sent_block + timeout, query_id, ());
+
+		// The query has not timed out yet
+		System::set_block_number(sent_block + timeout);
+		PolimecFunding::do_process_migration_timeouts(System::block_number(), Weight::MAX);
+		assert!(ActiveMigrationQueue::<TestRuntime>::get(query_id).is_some());
+
+		System::set_block_number(sent_block + timeout + 1);
+		let now = System::block_number();
+		PolimecFunding::do_process_migration_timeouts(now, Weight::MAX);
+		assert!(ActiveMigrationQueue::<TestRuntime>::get(query_id).is_none());
+		assert!(!MigrationTimeouts::<TestRuntime>::contains_key(sent_block + timeout, query_id));
+		assert_eq!(NextMigrationTimeoutBlock::<TestRuntime>::get(), now);
+		System::assert_has_event(RuntimeEvent::PolimecFunding(Event::MigrationQueryTimedOut { project_id, query_id }));
+		for participant in [BUYER_1, BUYER_2] {
+			let (status, _) = UserMigrations::<TestRuntime>::get(project_id, participant).unwrap();
+			assert_eq!(status, MigrationStatus::Failed);
+			assert_eq!(MigrationRetries::<TestRuntime>::get(project_id, participant), Some((1, now + backoff)));
+			assert!(MigrationRetrySchedule::<TestRuntime>::contains_key(now + backoff, (project_id, participant)));
+		}
+
+		// Without an open channel, due retries are checked again after the backoff
+		PolimecFunding::do_process_migration_retries(now + backoff, Weight::MAX);
+		assert_eq!(NextMigrationRetryBlock::<TestRuntime>::get(), now + backoff + 1);
+		assert!(!MigrationRetrySchedule::<TestRuntime>::contains_key(now + backoff, (project_id, BUYER_2)));
+		assert!(MigrationRetrySchedule::<TestRuntime>::contains_key(now + 2 * backoff, (project_id, BUYER_2)));
+		assert_eq!(MigrationRetries::<TestRuntime>::get(project_id, BUYER_2), Some((1, now + 2 * backoff)));
+
+		// Every new failure doubles the backoff, until the retries are exhausted
+		for retry in 2..=max_retries {
+			PolimecFunding::schedule_migration_retry(project_id, BUYER_1);
+			let expected_block = now + backoff * 2u64.pow(retry - 1);
+			assert_eq!(MigrationRetries::<TestRuntime>::get(project_id, BUYER_1), Some((retry, expected_block)));
+		}
+		PolimecFunding::schedule_migration_retry(project_id, BUYER_1);
+		assert_eq!(MigrationRetries::<TestRuntime>::get(project_id, BUYER_1), None);
+		assert_eq!(MigrationRetrySchedule::<TestRuntime>::iter_keys().filter(|(_, (_, p))| *p == BUYER_1).count(), 0);
+		System::assert_last_event(RuntimeEvent::PolimecFunding(Event::MigrationRetriesExhausted {
+			project_id,
+			account: BUYER_1,
+		}));
+	});
+}
+
+#[test]
+fn offchain_migration_publishes_merkle_root_and_accepts_issuer_attestations() {
+	let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+	let project_id = inst.create_finished_project(
+		default_project_metadata(ISSUER_1),
+		ISSUER_1,
+		default_evaluations(),
+		default_bids(),
+		default_community_buys(),
+		default_remainder_buys(),
+	);
+	inst.advance_time(<TestRuntime as Config>::SuccessToSettlementTime::get()).unwrap();
+	inst.settle_project(project_id).unwrap();
+
+	inst.execute(|| {
+		let migrations_count = UserMigrations::<TestRuntime>::iter_prefix_values(project_id)
+			.map(|(_, migrations)| migrations.len() as u32)
+			.sum::<u32>();
+		assert_err!(
+			PolimecFunding::do_start_offchain_migration(&BUYER_1, project_id, migrations_count),
+			Error::<TestRuntime>::NotIssuer
+		);
+		// The issuer pays for building the tree over every migration
+		assert_err!(
+			PolimecFunding::do_start_offchain_migration(&ISSUER_1, project_id, migrations_count - 1),
+			Error::<TestRuntime>::MigrationsCountTooLow
+		);
+		assert_ok!(PolimecFunding::do_start_offchain_migration(&ISSUER_1, project_id, migrations_count));
+		assert_err!(
+			PolimecFunding::do_start_offchain_migration(&ISSUER_1, project_id, migrations_count),
+			Error::<TestRuntime>::WrongMigrationType
+		);
+	});
+	assert_eq!(inst.get_project_details(project_id).migration_type, MigrationType::Offchain);
+
+	inst.execute(|| {
+		let merkle_root = MigrationMerkleRoots::<TestRuntime>::get(project_id).unwrap();
+		System::assert_has_event(RuntimeEvent::PolimecFunding(Event::OffchainMigrationStarted {
+			project_id,
+			merkle_root,
+		}));
+
+		// Every migration can be proven against the published root
+		let leaves = UserMigrations::<TestRuntime>::iter_prefix_values(project_id)
+			.flat_map(|(_, migrations)| migrations.into_inner())
+			.map(|migration| PolimecFunding::migration_merkle_leaf(&migration))
+			.collect_vec();
+		for leaf in leaves.iter() {
+			let proof = PolimecFunding::merkle_proof(leaves.clone(), *leaf).unwrap();
+			assert!(PolimecFunding::verify_merkle_proof(merkle_root, *leaf, &proof));
+		}
+
+		// Participants get the proofs of their own migrations from the runtime API
+		let (_, buyer_migrations) = UserMigrations::<TestRuntime>::get(project_id, BUYER_1).unwrap();
+		let buyer_proofs = PolimecFunding::offchain_migration_proofs(project_id, BUYER_1);
+		let proven_migrations = buyer_proofs.iter().map(|(migration, _)| migration.clone()).collect_vec();
+		assert_eq!(proven_migrations, buyer_migrations.to_vec());
+		for (migration, proof) in buyer_proofs {
+			let leaf = PolimecFunding::migration_merkle_leaf(&migration);
+			assert!(PolimecFunding::verify_merkle_proof(merkle_root, leaf, &proof));
+		}
+
+		// Migrations are not sent over XCM anymore, but attested by the issuer
+		assert_err!(
+			PolimecFunding::do_migrate_one_participant(project_id, BUYER_1),
+			Error::<TestRuntime>::WrongMigrationType
+		);
+		assert_err!(
+			PolimecFunding::do_confirm_offchain_migration(&BUYER_2, project_id, BUYER_1),
+			Error::<TestRuntime>::NotIssuer
+		);
+		// The issuer can only burn the CTs of participants who claimed them on the destination chain
+		assert_err!(
+			PolimecFunding::do_confirm_offchain_migration(&ISSUER_1, project_id, BUYER_1),
+			Error::<TestRuntime>::OffchainMigrationNotConsented
+		);
+		assert_ok!(PolimecFunding::do_consent_offchain_migration(BUYER_1, project_id));
+		System::assert_last_event(RuntimeEvent::PolimecFunding(Event::OffchainMigrationConsented {
+			project_id,
+			account: BUYER_1,
+		}));
+
+		let supply_before = ContributionTokens::total_supply(project_id);
+		let buyer_cts = ContributionTokens::balance(project_id, BUYER_1);
+		assert_ok!(PolimecFunding::do_try_state());
+		assert_ok!(PolimecFunding::do_confirm_offchain_migration(&ISSUER_1, project_id, BUYER_1));
+		assert!(!OffchainMigrationConsents::<TestRuntime>::contains_key(project_id, BUYER_1));
+		let (status, migrations) = UserMigrations::<TestRuntime>::get(project_id, BUYER_1).unwrap();
+		assert_eq!(status, MigrationStatus::Confirmed);
+
+		// The confirmed CTs are burned on Polimec
+		let migrated_cts = Migrations::from(migrations.into_inner()).total_ct_amount();
+		assert_eq!(migrated_cts, buyer_cts);
+		assert_eq!(ContributionTokens::balance(project_id, BUYER_1), 0);
+		assert_eq!(ContributionTokens::total_supply(project_id), supply_before - migrated_cts);
+		assert_eq!(MigratedContributionTokens::<TestRuntime>::get(project_id), migrated_cts);
+		assert_ok!(PolimecFunding::do_try_state());
+		assert_err!(
+			PolimecFunding::do_consent_offchain_migration(BUYER_1, project_id),
+			Error::<TestRuntime>::NotAllowed
+		);
+		assert_err!(
+			PolimecFunding::do_confirm_offchain_migration(&ISSUER_1, project_id, BUYER_1),
+			Error::<TestRuntime>::OffchainMigrationNotConsented
+		);
+	});
+}
+
+#[test]
+fn confirmed_migration_burns_only_the_cts_still_held() {
+	let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+	let project_id = inst.create_finished_project(
+		default_project_metadata(ISSUER_1),
+		ISSUER_1,
+		default_evaluations(),
+		default_bids(),
+		default_community_buys(),
+		default_remainder_buys(),
+	);
+	inst.advance_time(<TestRuntime as Config>::SuccessToSettlementTime::get()).unwrap();
+	inst.settle_project(project_id).unwrap();
+
+	inst.execute(|| {
+		let migrations_count = UserMigrations::<TestRuntime>::iter_prefix_values(project_id)
+			.map(|(_, migrations)| migrations.len() as u32)
+			.sum::<u32>();
+		assert_ok!(PolimecFunding::do_start_offchain_migration(&ISSUER_1, project_id, migrations_count));
+		assert_ok!(PolimecFunding::do_consent_offchain_migration(BUYER_1, project_id));
+
+		// The participant moves some of the CTs away before the issuer confirms the migration
+		let buyer_cts = ContributionTokens::balance(project_id, BUYER_1);
+		let moved_cts = buyer_cts / 2;
+		assert_ok!(<ContributionTokens as fungibles::Mutate<_>>::transfer(
+			project_id,
+			&BUYER_1,
+			&BUYER_2,
+			moved_cts,
+			Preservation::Expendable,
+		));
+		let supply_before = ContributionTokens::total_supply(project_id);
+
+		assert_ok!(PolimecFunding::do_confirm_offchain_migration(&ISSUER_1, project_id, BUYER_1));
+		let (status, _) = UserMigrations::<TestRuntime>::get(project_id, BUYER_1).unwrap();
+		assert_eq!(status, MigrationStatus::Confirmed);
+		System::assert_has_event(RuntimeEvent::PolimecFunding(Event::MigratedContributionTokensNotBurned {
+			project_id,
+			account: BUYER_1,
+			shortfall: moved_cts,
+		}));
+
+		// Only the CTs left are burned, and the rest is recorded
+		let burned_cts = buyer_cts - moved_cts;
+		assert_eq!(ContributionTokens::balance(project_id, BUYER_1), 0);
+		assert_eq!(ContributionTokens::total_supply(project_id), supply_before - burned_cts);
+		assert_eq!(MigratedContributionTokens::<TestRuntime>::get(project_id), burned_cts);
+		assert_eq!(UnburnedMigratedContributionTokens::<TestRuntime>::get(project_id, BUYER_1), moved_cts);
+		assert_ok!(PolimecFunding::do_try_state());
+	});
+}
+
+#[test]
+fn receiver_can_be_installed_at_any_pallet_index() {
+	let receiver_info = |index: u32, name: &str| {
+		xcm::v3::PalletInfo::new(index, name.into(), "polimec_receiver".into(), 0, 2, 0).unwrap()
+	};
+
+	assert_eq!(
+		PolimecFunding::receiver_call_indices(&receiver_info(51, "PolimecReceiver")),
+		Some(ReceiverCallIndices { pallet: 51, call: 2 })
+	);
+	assert_eq!(
+		PolimecFunding::receiver_call_indices(&receiver_info(80, "PolimecReceiver")),
+		Some(ReceiverCallIndices { pallet: 80, call: 2 })
+	);
+	assert_eq!(PolimecFunding::receiver_call_indices(&receiver_info(80, "OtherPallet")), None);
+	assert_eq!(PolimecFunding::receiver_call_indices(&receiver_info(256, "PolimecReceiver")), None);
+}
+
+#[test]
+fn migration_execution_fee_can_only_be_set_by_issuer() {
+	let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+	let project_id = inst.create_finished_project(
+		default_project_metadata(ISSUER_1),
+		ISSUER_1,
+		default_evaluations(),
+		default_bids(),
+		default_community_buys(),
+		default_remainder_buys(),
+	);
+
+	inst.execute(|| {
+		assert_noop!(
+			PolimecFunding::do_set_migration_execution_fee(&BUYER_1, project_id, Some(10_000_000_000)),
+			Error::<TestRuntime>::NotIssuer
+		);
+		assert_ok!(PolimecFunding::do_set_migration_execution_fee(&ISSUER_1, project_id, Some(10_000_000_000)));
+		assert_eq!(MigrationExecutionFees::<TestRuntime>::get(project_id), Some(10_000_000_000));
+		System::assert_last_event(RuntimeEvent::PolimecFunding(Event::MigrationExecutionFeeSet {
+			project_id,
+			fee: Some(10_000_000_000),
+		}));
+
+		assert_ok!(PolimecFunding::do_set_migration_execution_fee(&ISSUER_1, project_id, None));
+		assert_eq!(MigrationExecutionFees::<TestRuntime>::get(project_id), None);
+	});
+}
+
+#[test]
+fn migration_message_uses_benchmarked_weight_and_optional_fee() {
+	use polimec_common::migration_types::{Migration, MigrationOrigin, Migrations, ParticipationType};
+	use polimec_receiver::weights::WeightInfo as _;
+	use xcm::v3::prelude::*;
+
+	let call_indices = ReceiverCallIndices { pallet: 51, call: 2 };
+	let migrations = (0..10u32)
+		.map(|id| {
+			Migration::new(
+				MigrationOrigin { user: [id as u8; 32], id, participation_type: ParticipationType::Contribution },
+				(100 * CT_UNIT, 0u64).into(),
+			)
+		})
+		.collect::<Migrations>();
+	let expected_weight = ReceiverWeightInfo::<TestRuntime>::execute_migrations(10);
+
+	let unpaid = PolimecFunding::construct_migration_xcm_message(call_indices, migrations.clone(), 0, None);
+	assert!(matches!(unpaid.0.first(), Some(UnpaidExecution { .. })));
+	assert!(unpaid.0.iter().any(|instruction| matches!(
+		instruction,
+		Transact { require_weight_at_most, .. } if *require_weight_at_most == expected_weight
+	)));
+
+	let paid = PolimecFunding::construct_migration_xcm_message(call_indices, migrations, 0, Some(10_000_000_000));
+	assert!(matches!(paid.0.first(), Some(WithdrawAsset(_))));
+	assert!(!paid.0.iter().any(|instruction| matches!(instruction, UnpaidExecution { .. })));
+	assert!(matches!(paid.0.last(), Some(DepositAsset { .. })));
+}
+
+#[test]
+fn destination_vesting_is_copied_into_migrations_at_settlement() {
+	let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+	let project_id = inst.create_finished_project(
+		default_project_metadata(ISSUER_1),
+		ISSUER_1,
+		default_evaluations(),
+		default_bids(),
+		default_community_buys(),
+		default_remainder_buys(),
+	);
+	let vesting = DestinationVesting { start_block: Some(1_000), cliff: Some(500) };
+
+	inst.execute(|| {
+		assert_noop!(
+			PolimecFunding::do_set_destination_vesting(&BUYER_1, project_id, vesting),
+			Error::<TestRuntime>::NotIssuer
+		);
+		assert_ok!(PolimecFunding::do_set_destination_vesting(&ISSUER_1, project_id, vesting));
+		System::assert_last_event(RuntimeEvent::PolimecFunding(Event::DestinationVestingSet { project_id, vesting }));
+	});
+
+	inst.advance_time(<TestRuntime as Config>::SuccessToSettlementTime::get()).unwrap();
+	inst.settle_project(project_id).unwrap();
+
+	inst.execute(|| {
+		// Migrations are created with the vesting, so it cannot change anymore
+		assert_noop!(
+			PolimecFunding::do_set_destination_vesting(&ISSUER_1, project_id, DestinationVesting::default()),
+			Error::<TestRuntime>::NotAllowed
+		);
+		let infos = UserMigrations::<TestRuntime>::iter_prefix_values(project_id)
+			.flat_map(|(_, migrations)| migrations.into_inner())
+			.map(|migration| migration.info)
+			.collect_vec();
+		assert!(!infos.is_empty());
+		assert!(infos.iter().all(|info| info.start_block == Some(1_000) && info.cliff == Some(500)));
+	});
+}
+
+#[test]
+fn legacy_migrations_are_sent_in_their_own_format() {
+	use parity_scale_codec::{Decode, Encode};
+	use polimec_common::migration_types::{
+		LegacyMigration, LegacyMigrationInfo, Migration, MigrationInfo, MigrationOrigin, Migrations, ParticipationType,
+	};
+	use xcm::v3::prelude::*;
+
+	let legacy_migrations = (0..3u32)
+		.map(|id| LegacyMigration {
+			origin: MigrationOrigin { user: [id as u8; 32], id, participation_type: ParticipationType::Bid },
+			info: LegacyMigrationInfo { contribution_token_amount: 100 * CT_UNIT, vesting_time: 1_000 },
+		})
+		.collect_vec();
+
+	// A sequence of legacy infos round-trips, and converts to infos without vesting start nor cliff
+	let encoded = legacy_migrations.encode();
+	let decoded = Vec::<LegacyMigration>::decode(&mut &encoded[..]).unwrap();
+	assert_eq!(decoded, legacy_migrations);
+	let migrations = decoded.into_iter().map(Migration::from).collect_vec();
+	assert!(migrations.iter().all(|migration| migration.info == MigrationInfo::from((100 * CT_UNIT, 1_000u64))));
+	// The two formats are not interchangeable, which is why each one has its own receiver call
+	assert!(Vec::<Migration>::decode(&mut &encoded[..]).map_or(true, |new| new != migrations));
+
+	let new_info = MigrationInfo { start_block: Some(10), cliff: Some(20), ..MigrationInfo::from((CT_UNIT, 1u64)) };
+	assert_eq!(MigrationInfo::decode(&mut &new_info.encode()[..]).unwrap(), new_info);
+
+	// Receivers installed before `execute_scheduled_migrations` existed get the legacy format
+	let encoded_payload = |call: u8| {
+		let call_indices = ReceiverCallIndices { pallet: 51, call };
+		let migrations = Migrations::from(migrations.clone());
+		PolimecFunding::construct_migration_xcm_message(call_indices, migrations, 0, None)
+			.0
+			.into_iter()
+			.find_map(|instruction| match instruction {
+				Transact { call, .. } => Some(call.into_encoded()),
+				_ => None,
+			})
+			.unwrap()
+	};
+	assert_eq!(encoded_payload(0), [vec![51u8, 0u8], encoded].concat());
+	assert_eq!(encoded_payload(2), [vec![51u8, 2u8], Migrations::from(migrations.clone()).encode()].concat());
+}
+
+#[test]
+fn only_migrations_reported_as_failed_are_marked_failed() {
+	use xcm::v3::prelude::*;
+
+	let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+	let project_id = inst.create_finished_project(
+		default_project_metadata(ISSUER_1),
+		ISSUER_1,
+		default_evaluations(),
+		default_bids(),
+		default_community_buys(),
+		default_remainder_buys(),
+	);
+	inst.advance_time(<TestRuntime as Config>::SuccessToSettlementTime::get()).unwrap();
+	inst.settle_project(project_id).unwrap();
+
+	let para_id = ParaId::from(2006u32);
+	let project_location = MultiLocation { parents: 1, interior: X1(Parachain(2006)) };
+	let query_id = 1;
+	inst.execute(|| {
+		assert_ok!(PolimecFunding::do_set_para_id_for_project(&ISSUER_1, project_id, para_id));
+		let failed_origin = |account: AccountId| {
+			let (_, migrations) = UserMigrations::<TestRuntime>::get(project_id, account).unwrap();
+			migrations[0].origin.clone()
+		};
+
+		// Reports are only accepted from chains with migrations in flight
+		assert_noop!(
+			PolimecFunding::do_report_failed_migrations(project_location, vec![failed_origin(BUYER_1)]),
+			Error::<TestRuntime>::NoActiveMigrationsFound
+		);
+
+		for participant in [BUYER_1, BUYER_2] {
+			UserMigrations::<TestRuntime>::mutate(project_id, participant, |maybe_migrations| {
+				maybe_migrations.as_mut().unwrap().0 = MigrationStatus::Sent(query_id);
+			});
+		}
+		ActiveMigrationQueue::<TestRuntime>::insert(
+			query_id,
+			(project_id, BoundedVec::try_from(vec![BUYER_1, BUYER_2]).unwrap(), System::block_number() + 10),
+		);
+		MigrationQueriesPerParachain::<TestRuntime>::insert(para_id, 1);
+		assert_ok!(PolimecFunding::do_report_failed_migrations(project_location, vec![failed_origin(BUYER_1)]));
+
+		// The receiver call itself succeeded, but BUYER_1's migration could not be executed
+		assert_ok!(PolimecFunding::do_confirm_migrations(
+			project_location,
+			query_id,
+			Response::DispatchResult(MaybeErrorCode::Success)
+		));
+		let (buyer_1_status, _) = UserMigrations::<TestRuntime>::get(project_id, BUYER_1).unwrap();
+		let (buyer_2_status, _) = UserMigrations::<TestRuntime>::get(project_id, BUYER_2).unwrap();
+		assert_eq!(buyer_1_status, MigrationStatus::Failed);
+		assert_eq!(buyer_2_status, MigrationStatus::Confirmed);
+		assert!(MigrationRetries::<TestRuntime>::contains_key(project_id, BUYER_1));
+		assert_eq!(FailedMigrationReports::<TestRuntime>::iter().count(), 0);
+		assert!(!MigrationQueriesPerParachain::<TestRuntime>::contains_key(para_id));
+	});
+}
+
+#[test]
+fn closed_channel_pauses_migrations_until_requested_again() {
+	use xcm::v3::prelude::*;
+
+	let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+	let project_id = inst.create_finished_project(
+		default_project_metadata(ISSUER_1),
+		ISSUER_1,
+		default_evaluations(),
+		default_bids(),
+		default_community_buys(),
+		default_remainder_buys(),
+	);
+	inst.advance_time(<TestRuntime as Config>::SuccessToSettlementTime::get()).unwrap();
+	inst.settle_project(project_id).unwrap();
+
+	let para_id = ParaId::from(2006u32);
+	let polimec_para_id = 3344u32;
+	let open = HRMPChannelStatus { project_to_polimec: ChannelStatus::Open, polimec_to_project: ChannelStatus::Open };
+	inst.execute(|| {
+		assert_ok!(PolimecFunding::do_set_para_id_for_project(&ISSUER_1, project_id, para_id));
+		ProjectsDetails::<TestRuntime>::mutate(project_id, |details| {
+			let details = details.as_mut().unwrap();
+			details.hrmp_channel_status = open;
+			details.migration_readiness_check = Some(MigrationReadinessCheck {
+				holding_check: (0, CheckOutcome::Passed),
+				pallet_check: (1, CheckOutcome::Passed),
+			});
+			details.receiver_call_indices = Some(ReceiverCallIndices { pallet: 51, call: 0 });
+		});
+		assert_noop!(
+			PolimecFunding::do_request_hrmp_channel(&ISSUER_1, project_id),
+			Error::<TestRuntime>::HrmpChannelAlreadyOpen
+		);
+
+		// The project closes the channel towards Polimec
+		assert_ok!(PolimecFunding::do_handle_channel_closing(Instruction::HrmpChannelClosing {
+			initiator: 2006,
+			sender: 2006,
+			recipient: polimec_para_id,
+		}));
+		let hrmp_channel_status =
+			HRMPChannelStatus { project_to_polimec: ChannelStatus::Closed, polimec_to_project: ChannelStatus::Open };
+		System::assert_last_event(RuntimeEvent::PolimecFunding(Event::HrmpChannelClosed {
+			project_id,
+			para_id,
+			initiator: para_id,
+			hrmp_channel_status,
+		}));
+		assert_eq!(ProjectsDetails::<TestRuntime>::get(project_id).unwrap().hrmp_channel_status, hrmp_channel_status);
+		assert_noop!(
+			PolimecFunding::do_migrate_participants_batch(project_id),
+			Error::<TestRuntime>::ChannelNotOpen
+		);
+		assert_noop!(
+			PolimecFunding::do_migrate_one_participant(project_id, BUYER_1),
+			Error::<TestRuntime>::ChannelNotOpen
+		);
+
+		// Closing the channel from Polimec lets the issuer request it again, once the last request is stale
+		assert_ok!(PolimecFunding::do_handle_channel_closing(Instruction::HrmpChannelClosing {
+			initiator: 2006,
+			sender: polimec_para_id,
+			recipient: 2006,
+		}));
+		ProjectsDetails::<TestRuntime>::mutate(project_id, |details| {
+			details.as_mut().unwrap().hrmp_channel_status.polimec_to_project = ChannelStatus::AwaitingAcceptance;
+		});
+		HrmpChannelRequests::<TestRuntime>::insert(project_id, System::block_number());
+		assert_noop!(
+			PolimecFunding::do_request_hrmp_channel(&ISSUER_1, project_id),
+			Error::<TestRuntime>::HrmpChannelRequestPending
+		);
+		assert_noop!(
+			PolimecFunding::do_request_hrmp_channel(&BUYER_1, project_id),
+			Error::<TestRuntime>::NotIssuer
+		);
+		let timeout: u64 = <TestRuntime as Config>::HrmpChannelRequestTimeout::get();
+		System::set_block_number(System::block_number() + timeout);
+		// The mock has no XCM router, so the request reaches the sending step and fails there
+		assert_noop!(PolimecFunding::do_request_hrmp_channel(&ISSUER_1, project_id), Error::<TestRuntime>::XcmFailed);
+	});
+}
+
+#[test]
+fn readiness_check_records_why_the_project_chain_failed() {
+	use xcm::v3::prelude::*;
+
+	let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+	let project_id = inst.create_finished_project(
+		default_project_metadata(ISSUER_1),
+		ISSUER_1,
+		default_evaluations(),
+		default_bids(),
+		default_community_buys(),
+		default_remainder_buys(),
+	);
+	inst.advance_time(<TestRuntime as Config>::SuccessToSettlementTime::get()).unwrap();
+	inst.settle_project(project_id).unwrap();
+
+	let project_location = MultiLocation { parents: 1, interior: X1(Parachain(2006)) };
+	let receiver_info = |major: u32, minor: u32, patch: u32| {
+		let info = PalletInfo::new(51, "PolimecReceiver".into(), "polimec_receiver".into(), major, minor, patch);
+		Response::PalletsInfo(vec![info.unwrap()].try_into().unwrap())
+	};
+	inst.execute(|| {
+		assert_ok!(PolimecFunding::do_set_para_id_for_project(&ISSUER_1, project_id, ParaId::from(2006u32)));
+		let start_check = || {
+			ProjectsDetails::<TestRuntime>::mutate(project_id, |details| {
+				details.as_mut().unwrap().migration_readiness_check = Some(MigrationReadinessCheck {
+					holding_check: (0, CheckOutcome::AwaitingResponse),
+					pallet_check: (1, CheckOutcome::AwaitingResponse),
+				});
+			});
+			MigrationReadinessReports::<TestRuntime>::insert(project_id, MigrationReadinessReport::default());
+			MigrationReadinessQueries::<TestRuntime>::insert(0, project_id);
+			MigrationReadinessQueries::<TestRuntime>::insert(1, project_id);
+		};
+
+		// The receiver is older than the one Polimec requires, and the holding account lacks CTs
+		start_check();
+		assert_ok!(PolimecFunding::do_migration_check_response(project_location, 1, receiver_info(0, 0, 9)));
+		let holdings: MultiAssets = vec![(project_location, 1u128).into()].into();
+		assert_ok!(PolimecFunding::do_migration_check_response(project_location, 0, Response::Assets(holdings)));
+		let report = MigrationReadinessReports::<TestRuntime>::get(project_id).unwrap();
+		assert_eq!(report.ct_found, Some(1));
+		assert_eq!(report.receiver_version, Some((0, 0, 9)));
+		assert_eq!(
+			report.failures.into_inner(),
+			vec![
+				ReadinessFailure::ReceiverVersionIncompatible { major: 0, minor: 0, patch: 9 },
+				ReadinessFailure::NotEnoughCTs { required: report.ct_required, found: 1 },
+			]
+		);
+
+		// Newer minor versions are accepted. The mock cannot send the token metadata check, so that is recorded.
+		start_check();
+		assert_ok!(PolimecFunding::do_migration_check_response(project_location, 1, receiver_info(0, 2, 0)));
+		let project_details = ProjectsDetails::<TestRuntime>::get(project_id).unwrap();
+		assert_eq!(project_details.migration_readiness_check.unwrap().pallet_check.1, CheckOutcome::Passed);
+		let report = MigrationReadinessReports::<TestRuntime>::get(project_id).unwrap();
+		assert_eq!(report.failures.into_inner(), vec![ReadinessFailure::TokenMetadataCheckNotSent]);
+
+		// The receiver rejects the decimals or symbol of the CT
+		MigrationReadinessReports::<TestRuntime>::mutate(project_id, |report| {
+			report.as_mut().unwrap().metadata_check = Some((5, CheckOutcome::AwaitingResponse));
+		});
+		MigrationReadinessQueries::<TestRuntime>::insert(5, project_id);
+		let mismatch = Response::DispatchResult(MaybeErrorCode::Error(Default::default()));
+		assert_ok!(PolimecFunding::do_migration_check_response(project_location, 5, mismatch));
+		let report = MigrationReadinessReports::<TestRuntime>::get(project_id).unwrap();
+		assert_eq!(report.metadata_check, Some((5, CheckOutcome::Failed)));
+		assert_eq!(report.failures.last(), Some(&ReadinessFailure::TokenMetadataMismatch));
+		assert!(!report.token_metadata_matches());
+
+		// Each response is only accepted once
+		assert!(!MigrationReadinessQueries::<TestRuntime>::contains_key(5));
+		assert_noop!(
+			PolimecFunding::do_migration_check_response(project_location, 5, Response::Null),
+			Error::<TestRuntime>::ProjectDetailsNotFound
+		);
+	});
+}
+
+#[test]
+fn only_settled_participants_are_pending_for_batch_migrations() {
+	let mut inst = MockInstantiator::new(Some(RefCell::new(new_test_ext())));
+	let project_id = inst.create_finished_project(
+		default_project_metadata(ISSUER_1),
+		ISSUER_1,
+		default_evaluations(),
+		default_bids(),
+		default_community_buys(),
+		default_remainder_buys(),
+	);
+	inst.advance_time(<TestRuntime as Config>::SuccessToSettlementTime::get()).unwrap();
+
+	// EVALUATOR_2 also contributed, so its migrations are not pending until its evaluation is settled too
+	let contribution =
+		inst.execute(|| Contributions::<TestRuntime>::iter_prefix_values((project_id, EVALUATOR_2)).next().unwrap());
+	inst.execute(|| {
+		assert_ok!(PolimecFunding::do_settle_successful_contribution(contribution, project_id));
+		assert!(UserMigrations::<TestRuntime>::contains_key(project_id, EVALUATOR_2));
+		assert!(!PendingMigrations::<TestRuntime>::contains_key(project_id, EVALUATOR_2));
+	});
+
+	inst.settle_project(project_id).unwrap();
+	inst.execute(|| {
+		let participants = UserMigrations::<TestRuntime>::iter_key_prefix(project_id).collect::<Vec<_>>();
+		assert!(!participants.is_empty());
+		for participant in participants {
+			assert!(PendingMigrations::<TestRuntime>::contains_key(project_id, participant));
+		}
+
+		// Sent migrations are not pending anymore, failed ones are again
+		assert_ok!(PolimecFunding::change_migration_status(project_id, BUYER_1, MigrationStatus::Sent(1)));
+		assert!(!PendingMigrations::<TestRuntime>::contains_key(project_id, BUYER_1));
+		assert_ok!(PolimecFunding::change_migration_status(project_id, BUYER_1, MigrationStatus::Failed));
+		assert!(PendingMigrations::<TestRuntime>::contains_key(project_id, BUYER_1));
+	});
+}
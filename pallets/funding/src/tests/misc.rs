@@ -189,6 +189,9 @@ mod helper_functions {
 			participation_currencies: vec![AcceptedFundingAsset::USDT].try_into().unwrap(),
 			funding_destination_account: ISSUER_1,
 			policy_ipfs_cid: Some(ipfs_hash()),
+			phase_durations: None,
+			scheduled_starts: Default::default(),
+			funding_caps: None,
 		};
 
 		let project_id = inst.create_community_contributing_project(
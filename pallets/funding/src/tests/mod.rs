@@ -2,7 +2,7 @@ use super::*;
 use crate::{
 	instantiator::{async_features::create_multiple_projects_at, *},
 	mock::*,
-	traits::{ProvideAssetPrice, VestingDurationCalculation},
+	traits::{ProvideAssetPrice, ProvideStakedPlmc, VestingDurationCalculation},
 	CurrencyMetadata, Error, ProjectMetadata, TicketSize,
 };
 use defaults::*;
@@ -110,6 +110,9 @@ pub mod defaults {
 			participation_currencies: vec![AcceptedFundingAsset::USDT].try_into().unwrap(),
 			funding_destination_account: issuer,
 			policy_ipfs_cid: Some(metadata_hash),
+			phase_durations: None,
+			scheduled_starts: Default::default(),
+			funding_caps: None,
 		}
 	}
 
@@ -144,6 +147,9 @@ pub mod defaults {
 			participation_currencies: vec![AcceptedFundingAsset::USDT].try_into().unwrap(),
 			funding_destination_account: ISSUER_1,
 			policy_ipfs_cid: Some(metadata_hash),
+			phase_durations: None,
+			scheduled_starts: Default::default(),
+			funding_caps: None,
 		};
 		project_metadata
 	}
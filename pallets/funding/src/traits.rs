@@ -18,10 +18,10 @@ use crate::{BalanceOf, Config, ProjectId};
 use frame_support::weights::Weight;
 use frame_system::pallet_prelude::BlockNumberFor;
 use sp_arithmetic::{
-	traits::{CheckedDiv, CheckedMul},
+	traits::{CheckedDiv, CheckedMul, Zero},
 	FixedPointNumber,
 };
-use sp_runtime::DispatchError;
+use sp_runtime::{DispatchError, DispatchResult};
 
 pub trait BondingRequirementCalculation {
 	fn calculate_bonding_requirement<T: Config>(&self, ticket_size: BalanceOf<T>) -> Result<BalanceOf<T>, ()>;
@@ -36,6 +36,12 @@ pub trait ProvideAssetPrice {
 	type Price: FixedPointNumber;
 	fn get_price(asset_id: Self::AssetId) -> Option<Self::Price>;
 
+	/// Whether the price of the asset was updated recently enough to be trusted. Providers that do not keep track of
+	/// when prices were updated consider them always fresh.
+	fn is_price_fresh(_asset_id: Self::AssetId) -> bool {
+		true
+	}
+
 	/// Prices define the relationship between USD/Asset. When to and from that asset, we need to be aware that they might
 	/// have different decimals. This function calculates the relationship having in mind the decimals. For example:
 	/// if the price is 2.5, our underlying USD unit has 6 decimals, and the asset has 8 decimals, the price will be
@@ -75,6 +81,42 @@ pub trait ProvideAssetPrice {
 	}
 }
 
+/// Lets participants use PLMC they have staked somewhere else as their participation bond, without waiting
+/// for the unbonding delay.
+///
+/// The bond is taken first from evaluation bonds, then from the free balance, and only the remaining part
+/// is taken from the stake. That part stays staked and held by the staking side, and both sides respect it
+/// in this order:
+/// 1. Stake in use cannot be unbonded until the funding pallet releases the participation bond it backs.
+/// 2. Funding slashes (e.g. the evaluator slash) only take PLMC held by the funding pallet, never stake in use.
+/// 3. If the stake in use stops being staked without the participant asking for it, the funding pallet holds
+///    the freed PLMC again under `HoldReason::Participation` (see `Pallet::hold_freed_staked_bonds`).
+///
+/// Only bonds released at settlement (a multiplier of 1) can use stake, since longer bonds are vested by
+/// `Config::Vesting` on held PLMC.
+pub trait ProvideStakedPlmc<AccountId, Balance> {
+	/// Stake of `who` that can be used as a bond.
+	fn usable_stake(who: &AccountId) -> Balance;
+
+	/// Use `amount` of the stake of `who` as a bond. It stays staked, but cannot be unbonded until released.
+	fn use_stake(who: &AccountId, amount: Balance) -> DispatchResult;
+
+	/// Release `amount` of the stake of `who` used as a bond.
+	fn release_stake(who: &AccountId, amount: Balance);
+}
+
+impl<AccountId, Balance: Zero> ProvideStakedPlmc<AccountId, Balance> for () {
+	fn usable_stake(_who: &AccountId) -> Balance {
+		Zero::zero()
+	}
+
+	fn use_stake(_who: &AccountId, _amount: Balance) -> DispatchResult {
+		Err(DispatchError::Other("No stake to use"))
+	}
+
+	fn release_stake(_who: &AccountId, _amount: Balance) {}
+}
+
 pub trait DoRemainingOperation<T: Config> {
 	fn has_remaining_operations(&self) -> bool;
 
@@ -161,6 +161,14 @@ pub mod config_types {
 	}
 	pub const PROFESSIONAL_MAX_MULTIPLIER: u8 = 10u8;
 	pub const INSTITUTIONAL_MAX_MULTIPLIER: u8 = 25u8;
+
+	/// Indices of the relay chain's HRMP pallet and calls, used to open channels with project chains.
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct HrmpCallIndices {
+		pub pallet: u8,
+		pub init_open_channel: u8,
+		pub accept_open_channel: u8,
+	}
 }
 
 pub mod storage_types {
@@ -168,7 +176,14 @@ pub mod storage_types {
 
 	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
 	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
-	pub struct ProjectMetadata<BoundedString, Balance: PartialOrd + Copy, Price: FixedPointNumber, AccountId, Cid> {
+	pub struct ProjectMetadata<
+		BoundedString,
+		Balance: PartialOrd + Copy,
+		Price: FixedPointNumber,
+		AccountId,
+		Cid,
+		BlockNumber,
+	> {
 		/// Token Metadata
 		pub token_information: CurrencyMetadata<BoundedString>,
 		/// Mainnet Token Max Supply
@@ -191,6 +206,13 @@ pub mod storage_types {
 		pub funding_destination_account: AccountId,
 		/// Additional metadata
 		pub policy_ipfs_cid: Option<Cid>,
+		/// Custom phase durations, within the bounds set by the runtime. If `None`, the runtime defaults are used.
+		pub phase_durations: Option<PhaseDurations<BlockNumber>>,
+		/// Blocks at which the evaluation and auction should start automatically.
+		pub scheduled_starts: ScheduledPhaseStarts<BlockNumber>,
+		/// Soft and hard cap of the raise in USD. If `None`, the soft cap is 90% of the funding target and there is
+		/// no hard cap.
+		pub funding_caps: Option<FundingCaps<Balance>>,
 	}
 
 	impl<
@@ -199,12 +221,14 @@ pub mod storage_types {
 			Price: FixedPointNumber,
 			AccountId,
 			Cid,
-		> ProjectMetadata<BoundedString, Balance, Price, AccountId, Cid>
+			BlockNumber,
+		> ProjectMetadata<BoundedString, Balance, Price, AccountId, Cid, BlockNumber>
 	{
 		/// Validate issuer metadata for the following checks:
 		/// - Minimum price is not zero
 		/// - Minimum bidding ticket sizes are higher than 5k USD
 		/// - Specified participation currencies are unique
+		/// - The soft cap is not above the funding target nor the hard cap
 		pub fn is_valid(&self) -> Result<(), MetadataError> {
 			if self.minimum_price == Price::zero() {
 				return Err(MetadataError::PriceTooLow);
@@ -254,6 +278,12 @@ pub mod storage_types {
 				return Err(MetadataError::FundingTargetTooHigh);
 			}
 
+			if let Some(caps) = self.funding_caps {
+				if caps.soft_cap == 0u64.into() || caps.soft_cap > target_funding || caps.soft_cap > caps.hard_cap {
+					return Err(MetadataError::FundingCapsError);
+				}
+			}
+
 			if self.token_information.decimals < 6 || self.token_information.decimals > 18 {
 				return Err(MetadataError::BadDecimals);
 			}
@@ -332,6 +362,10 @@ pub mod storage_types {
 		pub migration_readiness_check: Option<MigrationReadinessCheck>,
 		/// HRMP Channel status
 		pub hrmp_channel_status: HRMPChannelStatus,
+		/// How the Contribution Tokens are migrated to the project's own chain
+		pub migration_type: MigrationType,
+		/// Where `polimec-receiver` is installed on the project's chain, as reported by the migration readiness check
+		pub receiver_call_indices: Option<ReceiverCallIndices>,
 	}
 	/// Tells on_initialize what to do with the project
 	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -345,10 +379,11 @@ pub mod storage_types {
 		FundingEnd,
 		ProjectDecision(FundingOutcomeDecision),
 		StartSettlement,
+		EvaluationStart,
 	}
 
 	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Ord, PartialOrd)]
-	pub struct EvaluationInfo<Id, Did, ProjectId, AccountId, Balance, BlockNumber> {
+	pub struct EvaluationInfo<Id, Did, ProjectId, AccountId, Balance, BlockNumber, Price> {
 		pub id: Id,
 		pub did: Did,
 		pub project_id: ProjectId,
@@ -359,6 +394,8 @@ pub mod storage_types {
 		pub early_usd_amount: Balance,
 		pub late_usd_amount: Balance,
 		pub when: BlockNumber,
+		// PLMC price the bond was calculated with
+		pub plmc_usd_price: Price,
 	}
 
 	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
@@ -378,6 +415,8 @@ pub mod storage_types {
 		pub multiplier: Multiplier,
 		pub plmc_bond: Balance,
 		pub when: BlockNumber,
+		// PLMC price the bond was calculated with
+		pub plmc_usd_price: Price,
 	}
 
 	impl<
@@ -414,7 +453,7 @@ pub mod storage_types {
 	}
 
 	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
-	pub struct ContributionInfo<Id, Did, ProjectId, AccountId, Balance, Multiplier> {
+	pub struct ContributionInfo<Id, Did, ProjectId, AccountId, Balance, Multiplier, Price> {
 		pub id: Id,
 		pub did: Did,
 		pub project_id: ProjectId,
@@ -425,6 +464,8 @@ pub mod storage_types {
 		pub funding_asset: AcceptedFundingAsset,
 		pub funding_asset_amount: Balance,
 		pub plmc_bond: Balance,
+		// PLMC price the bond was calculated with
+		pub plmc_usd_price: Price,
 	}
 
 	/// Represents a bucket that holds a specific amount of tokens at a given price.
@@ -499,6 +540,8 @@ pub mod inner_types {
 		// The combination of decimals and price of this project is not representable within our 6 decimals USD system,
 		// and integer space of 128 bits.
 		BadTokenomics,
+		/// The soft cap is zero, or higher than the funding target or the hard cap.
+		FundingCapsError,
 	}
 
 	#[derive(Default, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
@@ -622,6 +665,11 @@ pub mod inner_types {
 		USDC,
 		#[codec(index = 2)]
 		DOT,
+		/// Polimec's native token. Only accepted if the issuer opts in by adding it to the project's
+		/// participation currencies. Note that the PLMC paid for the tokens is transferred to the project
+		/// like any other funding asset, while the participation bond is still held separately.
+		#[codec(index = 3)]
+		PLMC,
 	}
 	impl AcceptedFundingAsset {
 		pub const fn to_assethub_id(&self) -> u32 {
@@ -629,8 +677,15 @@ pub mod inner_types {
 				AcceptedFundingAsset::USDT => 1984,
 				AcceptedFundingAsset::DOT => 10,
 				AcceptedFundingAsset::USDC => 1337,
+				AcceptedFundingAsset::PLMC => crate::PLMC_FOREIGN_ID,
 			}
 		}
+
+		/// Whether the asset is the chain's native currency, and therefore handled by `NativeCurrency`
+		/// instead of `FundingCurrency`.
+		pub const fn is_native(&self) -> bool {
+			matches!(self, AcceptedFundingAsset::PLMC)
+		}
 	}
 
 	#[derive(Default, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -652,6 +707,52 @@ pub mod inner_types {
 		MigrationCompleted,
 	}
 
+	/// Length in blocks of each phase of a project. Used to compute the [`PhaseTransitionPoints`].
+	#[derive(Default, Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	pub struct PhaseDurations<BlockNumber> {
+		pub evaluation: BlockNumber,
+		pub auction_opening: BlockNumber,
+		pub auction_closing: BlockNumber,
+		pub community_funding: BlockNumber,
+		pub remainder_funding: BlockNumber,
+		pub manual_acceptance: BlockNumber,
+		pub success_to_settlement: BlockNumber,
+	}
+
+	impl<BlockNumber: PartialOrd> PhaseDurations<BlockNumber> {
+		/// Check that every duration is between the ones in `min` and `max`, both inclusive.
+		pub fn is_within(&self, min: &Self, max: &Self) -> bool {
+			let in_bounds = |value: &BlockNumber, min: &BlockNumber, max: &BlockNumber| min <= value && value <= max;
+			in_bounds(&self.evaluation, &min.evaluation, &max.evaluation) &&
+				in_bounds(&self.auction_opening, &min.auction_opening, &max.auction_opening) &&
+				in_bounds(&self.auction_closing, &min.auction_closing, &max.auction_closing) &&
+				in_bounds(&self.community_funding, &min.community_funding, &max.community_funding) &&
+				in_bounds(&self.remainder_funding, &min.remainder_funding, &max.remainder_funding) &&
+				in_bounds(&self.manual_acceptance, &min.manual_acceptance, &max.manual_acceptance) &&
+				in_bounds(&self.success_to_settlement, &min.success_to_settlement, &max.success_to_settlement)
+		}
+	}
+
+	/// Funding goals of a project in USD.
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	pub struct FundingCaps<Balance> {
+		/// Minimum amount to raise for the project to be funded without an issuer decision.
+		pub soft_cap: Balance,
+		/// No more contributions are accepted once this amount is raised.
+		pub hard_cap: Balance,
+	}
+
+	/// Blocks chosen by the issuer at which a phase should be started automatically. A `None` keeps the
+	/// default behaviour of waiting for the issuer, or the end of the `AuctionInitializePeriod`.
+	#[derive(Default, Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+	pub struct ScheduledPhaseStarts<BlockNumber> {
+		pub evaluation: Option<BlockNumber>,
+		pub auction: Option<BlockNumber>,
+	}
+
 	#[derive(Default, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
 	pub struct PhaseTransitionPoints<BlockNumber> {
 		pub application: BlockNumberPair<BlockNumber>,
@@ -828,12 +929,74 @@ pub mod inner_types {
 		Failed,
 	}
 
+	/// Why the price circuit breaker paused participations with an asset.
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum PriceFeedIssue {
+		/// The price is missing, or was not updated recently enough.
+		Stale,
+		/// The price moved more than `PriceCircuitBreakerDeviation` from one block to the next.
+		ExcessiveDeviation,
+	}
+
+	/// Why a project's chain failed a step of the migration readiness check.
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum ReadinessFailure {
+		/// Polimec's sovereign account on the project's chain holds fewer CTs than were sold.
+		NotEnoughCTs { required: u128, found: u128 },
+		/// The reported holdings do not contain the project's token.
+		UnexpectedHoldings,
+		/// The project's chain has no pallet called `polimec_receiver`.
+		ReceiverNotFound,
+		/// The receiver pallet found is not the one Polimec expects.
+		ReceiverMismatch,
+		/// The receiver has another major version than `PolimecReceiverInfo`, or is older than it.
+		ReceiverVersionIncompatible { major: u32, minor: u32, patch: u32 },
+		/// The token of the project's chain has other decimals or symbol than the project's `CurrencyMetadata`.
+		TokenMetadataMismatch,
+		/// The check of the token metadata could not be sent to the project's chain.
+		TokenMetadataCheckNotSent,
+	}
+
+	/// What the migration readiness check found on the project's chain, so the issuer can see why it failed.
+	/// Reset every time the check is started.
+	#[derive(Default, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct MigrationReadinessReport {
+		/// CTs that Polimec's sovereign account needs on the project's chain to execute all migrations.
+		pub ct_required: u128,
+		/// CTs that Polimec's sovereign account holds on the project's chain.
+		pub ct_found: Option<u128>,
+		/// Version of the `polimec_receiver` pallet found on the project's chain.
+		pub receiver_version: Option<(u32, u32, u32)>,
+		/// Query checking the token decimals and symbol, sent once a compatible receiver is found.
+		pub metadata_check: Option<(xcm::v3::QueryId, CheckOutcome)>,
+		/// Why the project's chain failed the check, in the order the responses arrived.
+		pub failures: BoundedVec<ReadinessFailure, ConstU32<8>>,
+	}
+
+	impl MigrationReadinessReport {
+		pub fn token_metadata_matches(&self) -> bool {
+			matches!(self.metadata_check, Some((_, CheckOutcome::Passed)))
+		}
+
+		pub fn record_failure(&mut self, failure: ReadinessFailure) {
+			// Every step can fail at most once per check, so this never overflows.
+			let _ = self.failures.try_push(failure);
+		}
+	}
+
 	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 	pub struct HRMPChannelStatus {
 		pub project_to_polimec: ChannelStatus,
 		pub polimec_to_project: ChannelStatus,
 	}
 
+	impl HRMPChannelStatus {
+		/// Migrations can only be sent while both directions of the channel are open.
+		pub fn is_open(&self) -> bool {
+			self.project_to_polimec == ChannelStatus::Open && self.polimec_to_project == ChannelStatus::Open
+		}
+	}
+
 	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 	pub enum ChannelStatus {
 		/// hrmp channel is closed.
@@ -844,6 +1007,32 @@ pub mod inner_types {
 		AwaitingAcceptance,
 	}
 
+	/// Indices used to encode the `execute_migrations` call of the project's `polimec-receiver` pallet.
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct ReceiverCallIndices {
+		pub pallet: u8,
+		pub call: u8,
+	}
+
+	/// Vesting of the migrated CTs on the project's chain, copied into every migration of the project at settlement.
+	#[derive(Default, Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct DestinationVesting {
+		/// Block of the project's chain from which the CTs start vesting.
+		pub start_block: Option<u64>,
+		/// Blocks after the start during which no CTs are released. What vested by then is released when it ends.
+		pub cliff: Option<u64>,
+	}
+
+	#[derive(Default, Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum MigrationType {
+		/// Migrations are sent with XCM to the `polimec-receiver` pallet of a sibling parachain.
+		#[default]
+		Pallet,
+		/// Users claim their CTs on a non-parachain destination with a proof against the Merkle root of all
+		/// migrations. The issuer attests on Polimec which users already claimed.
+		Offchain,
+	}
+
 	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 	pub struct ProjectMigrationOrigins<ProjectId, MigrationOrigins> {
 		pub project_id: ProjectId,
@@ -17,47 +17,85 @@
 //! Offchain Worker for Oracle price feed
 #![cfg_attr(not(feature = "std"), no_std)]
 use crate::{
-	traits::FetchPrice,
+	traits::{FetchPrice, OnChainPriceSource},
 	types::{
-		AssetName, AssetRequest, BitFinexFetcher, BitStampFetcher, CoinbaseFetcher, KrakenFetcher, MexcFetcher,
-		OpenCloseVolume, XTFetcher,
+		AssetName, AssetPriceSources, AssetRequest, Exchange, FeederIssue, FeederStats, FeederThresholds,
+		OpenCloseVolume, PricePayload, PriceSourceKind, Twap,
 	},
 };
-use core::ops::Rem;
-use frame_system::offchain::{AppCrypto, CreateSignedTransaction, SendSignedTransaction, Signer};
+use core::{marker::PhantomData, ops::Rem};
+use frame_support::traits::{SortedMembers, Time as TimeProvider};
+use frame_system::{
+	offchain::{
+		AppCrypto, CreateSignedTransaction, SendSignedTransaction, SendTransactionTypes, SendUnsignedTransaction,
+		SignedPayload, Signer,
+	},
+	pallet_prelude::BlockNumberFor,
+};
+use orml_traits::OnNewData;
 pub use pallet::*;
+use parity_scale_codec::Encode;
+use sp_core::offchain::StorageKind;
 use sp_runtime::{
 	traits::{Convert, Saturating, Zero},
-	FixedU128, RuntimeAppPublic,
+	FixedPointNumber, FixedU128, Percent, RuntimeAppPublic,
 };
 use sp_std::{collections::btree_map::BTreeMap, vec, vec::Vec};
 
 mod mock;
 mod tests;
 
-mod traits;
+pub mod traits;
 
 pub mod types;
 
 pub mod crypto;
 
+pub mod runtime_api;
+
 const LOG_TARGET: &str = "ocw::oracle";
 // Change values in Fetcher urls when changing this value
 pub(crate) const NUMBER_OF_CANDLES: usize = 15;
+// Sources whose price is further than this from the median of all sources are ignored (5%)
+pub(crate) const MAX_SOURCE_DEVIATION: FixedU128 = FixedU128::from_inner(50_000_000_000_000_000);
+// Assets priced with the built-in sources when no price sources are configured
+pub(crate) const DEFAULT_ASSETS: [AssetName; 4] = [AssetName::USDT, AssetName::USDC, AssetName::DOT, AssetName::PLMC];
+pub const MAX_SOURCES_PER_ASSET: u32 = 8;
+pub const MAX_SOURCE_URL_LENGTH: u32 = 256;
+// Offchain local storage key where node operators can override the price sources
+pub const PRICE_SOURCES_KEY: &[u8] = b"oracle_ocw::price_sources";
+// Offchain local storage key where node operators can set a SCALE encoded `true` to feed prices in unsigned
+// transactions instead of signed ones
+pub const SUBMIT_UNSIGNED_KEY: &[u8] = b"oracle_ocw::submit_unsigned";
+// Offchain local storage prefix of the recorded responses replayed instead of querying the exchanges
+pub const FIXTURE_KEY_PREFIX: &[u8] = b"oracle_ocw::fixture::";
+// Offchain local storage key where the node records the fixture keys it wrote, to remove them on its next start
+pub const FIXTURE_KEYS_KEY: &[u8] = b"oracle_ocw::fixture_keys";
+
+/// Offchain local storage key of the recorded candles response of `exchange` for `asset`.
+pub fn fixture_key(exchange: Exchange, asset: AssetName) -> Vec<u8> {
+	let mut key = FIXTURE_KEY_PREFIX.to_vec();
+	key.extend((exchange, asset).encode());
+	key
+}
+
+pub(crate) fn fixture(exchange: Exchange, asset: AssetName) -> Option<Vec<u8>> {
+	sp_io::offchain::local_storage_get(StorageKind::PERSISTENT, &fixture_key(exchange, asset))
+}
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use frame_support::{pallet_prelude::*, traits::Contains};
+	use frame_support::pallet_prelude::*;
 	use frame_system::{offchain::SigningTypes, pallet_prelude::*};
-	use orml_oracle::Call as OracleCall;
+	use orml_oracle::{Call as OracleCall, WeightInfo as OracleWeightInfo};
 	use sp_runtime::{
 		offchain::{
 			storage::{StorageRetrievalError, StorageValueRef},
 			storage_lock::{StorageLock, Time},
 			Duration,
 		},
-		traits::{IdentifyAccount, Zero},
+		traits::{IdentifyAccount, One, SaturatedConversion, Zero},
 	};
 
 	const LOCK_TIMEOUT_EXPIRATION: u64 = 30_000; // 30 seconds
@@ -68,20 +106,26 @@ pub mod pallet {
 	pub type GenericPublicOf<T> = <<T as Config>::AppCrypto as AppCrypto<PublicOf<T>, SignatureOf<T>>>::GenericPublic;
 	pub type RuntimeAppPublicOf<T> =
 		<<T as Config>::AppCrypto as AppCrypto<PublicOf<T>, SignatureOf<T>>>::RuntimeAppPublic;
+	pub type MomentOf<T> = <<T as orml_oracle::Config>::Time as TimeProvider>::Moment;
+	pub type TwapOf<T> = Twap<<T as orml_oracle::Config>::OracleValue, MomentOf<T>>;
+	pub type PriceSourcesOf<T> = BoundedVec<AssetPriceSources, <T as orml_oracle::Config>::MaxFeedValues>;
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
 	pub trait Config:
-		CreateSignedTransaction<OracleCall<Self>> + frame_system::Config + orml_oracle::Config<()>
+		CreateSignedTransaction<OracleCall<Self>>
+		+ SendTransactionTypes<Call<Self>>
+		+ frame_system::Config
+		+ orml_oracle::Config<()>
 	{
 		/// The overarching event type of the runtime.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		/// The cryptographic interface for the offchain worker to sign transactions.
 		type AppCrypto: AppCrypto<Self::Public, Self::Signature>;
 		/// List of members that are allowed to send transactions.
-		type Members: frame_support::traits::Contains<AccountIdOf<Self>>;
+		type Members: SortedMembers<AccountIdOf<Self>>;
 		/// Interval between price fetches in block numbers.
 		/// The interval is started at block `n % FetchInterval == 0`
 		type FetchInterval: Get<BlockNumberFor<Self>>;
@@ -94,13 +138,106 @@ pub mod pallet {
 		type FetchWindow: Get<BlockNumberFor<Self>>;
 		/// Convert AssetName and FixedU128 to OracleKey and OracleValue
 		type ConvertAssetPricePair: Convert<(AssetName, FixedU128), (Self::OracleKey, Self::OracleValue)>;
+		/// Price source combined with the exchange prices, e.g. a PLMC pool on a DEX. Use `()` for none.
+		type OnChainPriceSource: OnChainPriceSource;
+		/// Length of the window over which the time-weighted average price is calculated, in `Time` moments.
+		#[pallet::constant]
+		type TwapWindow: Get<MomentOf<Self>>;
+		/// Priority of the unsigned transactions feeding prices.
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+		/// Number of price snapshots kept for each oracle key, one per `FetchInterval`. The oldest one is overwritten
+		/// when all are taken.
+		#[pallet::constant]
+		type MaxPriceHistory: Get<u32>;
+		/// Origin allowed to change the assets and exchanges prices are fetched for, how much each source weighs,
+		/// and to hold feeders accountable. Likely governance.
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 	}
 
+	/// Rolling time-weighted average price of each oracle key. Updated whenever values are fed to the oracle.
+	#[pallet::storage]
+	pub type Twaps<T: Config> = StorageMap<_, Blake2_128Concat, T::OracleKey, TwapOf<T>>;
+
+	/// Ring buffer of the combined price of each oracle key, and the block it was combined at. At most one snapshot is
+	/// kept per fetch interval.
+	#[pallet::storage]
+	pub type PriceSnapshots<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::OracleKey,
+		Twox64Concat,
+		u32,
+		(BlockNumberFor<T>, T::OracleValue),
+	>;
+
+	/// Slot of `PriceSnapshots` the next snapshot of each oracle key is written to.
+	#[pallet::storage]
+	pub type PriceSnapshotHeads<T: Config> = StorageMap<_, Blake2_128Concat, T::OracleKey, u32, ValueQuery>;
+
+	/// Assets the OCW fetches prices for, and where from. If not set, the built-in sources are used.
+	/// Node operators can override it with a SCALE encoded `Vec<AssetPriceSources>` at `PRICE_SOURCES_KEY` in the
+	/// offchain local storage.
+	#[pallet::storage]
+	pub type PriceSources<T: Config> = StorageValue<_, PriceSourcesOf<T>>;
+
+	/// Weight of the prices of each kind of source when combining them. Sources not in the map have full weight.
+	#[pallet::storage]
+	pub type SourceWeights<T: Config> = StorageMap<_, Blake2_128Concat, PriceSourceKind, Percent>;
+
+	/// How each member has been feeding the oracle.
+	#[pallet::storage]
+	pub type FeedersStats<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, FeederStats<BlockNumberFor<T>>, ValueQuery>;
+
+	/// When feeders get a warning, and when they are suspended.
+	#[pallet::storage]
+	pub type AccountabilityThresholds<T: Config> = StorageValue<_, FeederThresholds, ValueQuery>;
+
+	/// Members that cannot feed the oracle until governance resumes them, and the block they were suspended at.
+	#[pallet::storage]
+	pub type SuspendedFeeders<T: Config> = StorageMap<_, Blake2_128Concat, AccountIdOf<T>, BlockNumberFor<T>>;
+
 	#[pallet::event]
-	pub enum Event<T: Config> {}
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The price sources were changed. `None` means the built-in sources are used.
+		PriceSourcesSet { sources: Option<PriceSourcesOf<T>> },
+		/// The weight of a kind of source was changed. `None` means full weight.
+		SourceWeightSet { source: PriceSourceKind, weight: Option<Percent> },
+		/// The thresholds to warn about and suspend feeders were changed.
+		AccountabilityThresholdsSet { thresholds: FeederThresholds },
+		/// A feeder fed a deviating value or missed a fetch interval.
+		FeederWarned { feeder: AccountIdOf<T>, issue: FeederIssue },
+		/// A feeder went over a threshold and cannot feed the oracle anymore.
+		FeederSuspended { feeder: AccountIdOf<T>, issue: FeederIssue },
+		/// A suspended feeder can feed the oracle again.
+		FeederResumed { feeder: AccountIdOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// An asset is listed more than once in the price sources.
+		DuplicateAsset,
+		/// The feeder is not suspended.
+		FeederNotSuspended,
+		/// An asset id has the oracle key of a built-in asset.
+		BuiltInAssetKey,
+		/// A Kraken source is set for an asset without a built-in Kraken market, whose response cannot be parsed.
+		UnsupportedKrakenAsset,
+	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// At the start of each fetch interval, flag the members that fed nothing during the previous one.
+		fn on_initialize(block_number: BlockNumberFor<T>) -> Weight {
+			let interval = T::FetchInterval::get();
+			if interval.is_zero() || block_number < interval || !block_number.rem(interval).is_zero() {
+				return Weight::zero();
+			}
+			Self::track_missed_windows(block_number.saturating_sub(interval))
+		}
+
 		/// Offchain Worker entry point.
 		///
 		/// By implementing `fn offchain_worker` you declare a new offchain worker.
@@ -114,21 +251,21 @@ pub mod pallet {
 			log::trace!(target: LOG_TARGET, "Running offchain worker for block: {:?}", block_number);
 			let local_keys = RuntimeAppPublicOf::<T>::all();
 			log::trace!(target: LOG_TARGET, "Num of Local keys: {:?}", local_keys.len());
-			// Check if Signing key is in the permissioned set of keys.
-			let maybe_key: Option<RuntimeAppPublicOf<T>> = local_keys.into_iter().find_map(|key| {
-				log::trace!(target: LOG_TARGET, "runtime key: {:?}", key.to_raw_vec());
-				let generic_public = GenericPublicOf::<T>::from(key);
-				let public: T::Public = generic_public.into();
-				let account = public.clone().into_account();
-				if <T as pallet::Config>::Members::contains(&account) {
-					if let Ok(generic_public) = TryInto::<GenericPublicOf<T>>::try_into(public) {
-						return Some(generic_public.into());
-					}
-				}
-				None
-			});
+			// Keep the signing keys in the permissioned set of keys.
+			let active_keys = local_keys
+				.into_iter()
+				.filter_map(|key| {
+					log::trace!(target: LOG_TARGET, "runtime key: {:?}", key.to_raw_vec());
+					let generic_public = GenericPublicOf::<T>::from(key);
+					let public: T::Public = generic_public.into();
+					let account = public.clone().into_account();
+					(<T as pallet::Config>::Members::contains(&account) &&
+						!SuspendedFeeders::<T>::contains_key(&account))
+					.then_some(public)
+				})
+				.collect::<Vec<T::Public>>();
 
-			if let Some(_authority_key) = maybe_key {
+			if !active_keys.is_empty() {
 				let mut lock = StorageLock::<Time>::with_deadline(
 					b"oracle_ocw::lock",
 					Duration::from_millis(LOCK_TIMEOUT_EXPIRATION),
@@ -144,36 +281,27 @@ pub mod pallet {
 					> = val.get();
 					let mut last_send_for_assets = match last_send_for_assets_result {
 						Ok(Some(v)) => v,
-						_ => BTreeMap::from([
-							(AssetName::USDT, Zero::zero()),
-							(AssetName::USDC, Zero::zero()),
-							(AssetName::DOT, Zero::zero()),
-							(AssetName::PLMC, Zero::zero()),
-						]),
-					};
-					// Fix for missing PLMC in last_send_for_assets for old nodes, that did not have PLMC in the list.
-					if !last_send_for_assets.contains_key(&AssetName::PLMC) {
-						last_send_for_assets.insert(AssetName::PLMC, Zero::zero());
+						_ => BTreeMap::new(),
 					};
-					let assets = last_send_for_assets
-						.iter()
-						.filter_map(|(asset_name, last_send)| {
+					// Assets never sent before, like newly configured ones, are due right away.
+					let assets = Self::price_sources()
+						.into_iter()
+						.filter(|asset_sources| {
+							let last_send =
+								last_send_for_assets.get(&asset_sources.asset).copied().unwrap_or_else(Zero::zero);
 							let window = T::FetchWindow::get();
 							let remainder = block_number.rem(T::FetchInterval::get());
-							if remainder >= BlockNumberFor::<T>::zero() &&
-								remainder < window && last_send < &block_number.saturating_sub(window)
-							{
-								return Some(*asset_name);
-							}
-							None
+							remainder >= BlockNumberFor::<T>::zero() &&
+								remainder < window && last_send < block_number.saturating_sub(window)
 						})
-						.collect::<Vec<AssetName>>();
+						.collect::<Vec<AssetPriceSources>>();
 
 					if assets.is_empty() {
 						return;
 					}
 
-					log::trace!(target: LOG_TARGET, "Transaction grace period reached for assets {:?} in block {:?}", assets, block_number);
+					let asset_names = assets.iter().map(|asset_sources| asset_sources.asset).collect::<Vec<_>>();
+					log::trace!(target: LOG_TARGET, "Transaction grace period reached for assets {:?} in block {:?}", asset_names, block_number);
 
 					let prices = Self::fetch_prices(assets);
 					if prices.is_empty() {
@@ -183,7 +311,12 @@ pub mod pallet {
 					for (asset_name, price) in prices.clone() {
 						log::trace!(target: LOG_TARGET, "Fetched price for {:?}: {}", asset_name, price);
 					}
-					let result = Self::send_signed_transaction(prices.clone());
+					let submit_unsigned: Result<Option<bool>, StorageRetrievalError> =
+						StorageValueRef::persistent(SUBMIT_UNSIGNED_KEY).get();
+					let result = match submit_unsigned {
+						Ok(Some(true)) => Self::send_unsigned_transaction(prices.clone(), active_keys, block_number),
+						_ => Self::send_signed_transaction(prices.clone(), active_keys),
+					};
 					if result.is_ok() {
 						for (asset_name, _) in prices {
 							last_send_for_assets.insert(asset_name, block_number);
@@ -192,46 +325,191 @@ pub mod pallet {
 					}
 				};
 			}
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the assets the OCW fetches prices for, and the exchange endpoints to fetch them from.
+		/// `None` goes back to the built-in sources.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::DbWeight::get().writes(1))]
+		pub fn set_price_sources(origin: OriginFor<T>, sources: Option<PriceSourcesOf<T>>) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			if let Some(sources) = &sources {
+				let mut assets = sources.iter().map(|asset_sources| asset_sources.asset).collect::<Vec<_>>();
+				assets.sort();
+				assets.dedup();
+				ensure!(assets.len() == sources.len(), Error::<T>::DuplicateAsset);
+
+				// An asset id would otherwise overwrite the oracle prices of a built-in asset, e.g. 1984 for USDT
+				let oracle_key = |asset: AssetName| T::ConvertAssetPricePair::convert((asset, FixedU128::zero())).0;
+				let built_in_keys = DEFAULT_ASSETS.map(oracle_key);
+				let key_taken = assets
+					.into_iter()
+					.any(|asset| matches!(asset, AssetName::Other(_)) && built_in_keys.contains(&oracle_key(asset)));
+				ensure!(!key_taken, Error::<T>::BuiltInAssetKey);
+
+				// Kraken keys its candles by pair name, and only the pairs of the built-in markets are parsed
+				let unsupported_kraken_asset = sources.iter().any(|asset_sources| {
+					Exchange::Kraken.default_url(asset_sources.asset).is_empty() &&
+						asset_sources.sources.iter().any(|source| source.exchange == Exchange::Kraken)
+				});
+				ensure!(!unsupported_kraken_asset, Error::<T>::UnsupportedKrakenAsset);
+			}
+
+			PriceSources::<T>::set(sources.clone());
+			Self::deposit_event(Event::PriceSourcesSet { sources });
+			Ok(())
+		}
+
+		/// Set when feeders get a warning, and whether they are suspended when going over a threshold.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::DbWeight::get().writes(1))]
+		pub fn set_accountability_thresholds(origin: OriginFor<T>, thresholds: FeederThresholds) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			AccountabilityThresholds::<T>::put(thresholds);
+			Self::deposit_event(Event::AccountabilityThresholdsSet { thresholds });
+			Ok(())
+		}
+
+		/// Let a suspended feeder feed the oracle again. Its deviating feeds and missed windows in a row start over.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(2, 2))]
+		pub fn resume_feeder(origin: OriginFor<T>, feeder: AccountIdOf<T>) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(SuspendedFeeders::<T>::contains_key(&feeder), Error::<T>::FeederNotSuspended);
+
+			SuspendedFeeders::<T>::remove(&feeder);
+			FeedersStats::<T>::mutate(&feeder, |stats| {
+				stats.consecutive_deviating_feeds = 0;
+				stats.consecutive_missed_windows = 0;
+				stats.last_resume = frame_system::Pallet::<T>::block_number();
+			});
+			Self::deposit_event(Event::FeederResumed { feeder });
+			Ok(())
+		}
+
+		/// Set how much the prices of a kind of source weigh when combined with the other ones. A zero weight
+		/// leaves the source out, `None` gives it full weight again.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::DbWeight::get().writes(1))]
+		pub fn set_source_weight(
+			origin: OriginFor<T>,
+			source: PriceSourceKind,
+			weight: Option<Percent>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			SourceWeights::<T>::set(source, weight);
+			Self::deposit_event(Event::SourceWeightSet { source, weight });
+			Ok(())
+		}
 
-			// Todo:
-			// - Fetch price information for Polimec
+		/// Feed values to the oracle on behalf of the member who signed `payload`, without paying fees. Submitted
+		/// by the OCW when node operators opt in at `SUBMIT_UNSIGNED_KEY`.
+		#[pallet::call_index(4)]
+		#[pallet::weight({
+			let values = payload.values.len() as u32;
+			<T as orml_oracle::Config>::WeightInfo::feed_values(values)
+				.saturating_add(Pallet::<T>::on_new_data_weight(values))
+		})]
+		pub fn feed_values_unsigned(
+			origin: OriginFor<T>,
+			payload: PricePayload<T>,
+			_signature: T::Signature,
+		) -> DispatchResultWithPostInfo {
+			ensure_none(origin)?;
+			let feeder = payload.public.into_account();
+			orml_oracle::Pallet::<T, ()>::feed_values(frame_system::RawOrigin::Signed(feeder).into(), payload.values)
 		}
 	}
 
 	impl<T: Config> Pallet<T> {
-		fn fetch_prices(assets: Vec<AssetName>) -> BTreeMap<AssetName, FixedU128> {
-			let fetchers = vec![
-				KrakenFetcher::get_moving_average,
-				BitFinexFetcher::get_moving_average,
-				BitStampFetcher::get_moving_average,
-				CoinbaseFetcher::get_moving_average,
-				XTFetcher::get_moving_average,
-				MexcFetcher::get_moving_average,
-			];
+		/// Weight of the `OnNewData` hook for `values` fed: the stats of the feeder, and the price snapshot and TWAP
+		/// of each key.
+		pub(crate) fn on_new_data_weight(values: u32) -> Weight {
+			T::DbWeight::get().reads_writes(6, 5).saturating_mul(values.into())
+		}
+
+		/// Price sources set by the node operator in the offchain local storage, or else by governance on chain, or
+		/// else the built-in ones.
+		pub(crate) fn price_sources() -> Vec<AssetPriceSources> {
+			let local_sources: Result<Option<Vec<AssetPriceSources>>, StorageRetrievalError> =
+				StorageValueRef::persistent(PRICE_SOURCES_KEY).get();
+			if let Ok(Some(sources)) = local_sources {
+				return sources;
+			}
+			match PriceSources::<T>::get() {
+				Some(sources) => sources.into_inner(),
+				None => DEFAULT_ASSETS.into_iter().map(AssetPriceSources::defaults).collect(),
+			}
+		}
 
+		pub(crate) fn fetch_prices(assets: Vec<AssetPriceSources>) -> BTreeMap<AssetName, FixedU128> {
 			let mut aggr_prices: BTreeMap<AssetName, Vec<(FixedU128, FixedU128)>> = BTreeMap::new();
-			for fetcher in fetchers.into_iter() {
-				let fetcher_prices = fetcher(assets.clone(), 5000);
+			for exchange in Exchange::ALL {
+				let requests = assets
+					.iter()
+					.flat_map(|asset_sources| {
+						asset_sources
+							.sources
+							.iter()
+							.filter(move |source| source.exchange == exchange)
+							.filter_map(move |source| {
+								Some((asset_sources.asset, sp_std::str::from_utf8(&source.url).ok()?))
+							})
+					})
+					.collect::<Vec<(AssetName, &str)>>();
+				if requests.is_empty() {
+					continue;
+				}
+
+				let weight = Self::source_weight(PriceSourceKind::Exchange(exchange));
+				let fetcher_prices = exchange.get_moving_average(requests, 5000);
 				for (asset_name, volume_price_sum, tot_vol) in fetcher_prices {
 					aggr_prices
 						.entry(asset_name)
-						.and_modify(|e| e.push((volume_price_sum, tot_vol)))
-						.or_insert(vec![(volume_price_sum, tot_vol)]);
+						.or_default()
+						.push((volume_price_sum.saturating_mul(weight), tot_vol.saturating_mul(weight)));
+				}
+			}
+
+			let weight = Self::source_weight(PriceSourceKind::OnChain);
+			for asset_sources in assets {
+				if let Some((price, volume)) = T::OnChainPriceSource::price(asset_sources.asset) {
+					let volume = volume.saturating_mul(weight);
+					aggr_prices.entry(asset_sources.asset).or_default().push((price.saturating_mul(volume), volume));
 				}
 			}
 
 			Self::combine_prices(aggr_prices)
 		}
 
-		fn combine_prices(prices: BTreeMap<AssetName, Vec<(FixedU128, FixedU128)>>) -> BTreeMap<AssetName, FixedU128> {
+		/// Weight of the prices of `source`, applied to their volume.
+		fn source_weight(source: PriceSourceKind) -> FixedU128 {
+			SourceWeights::<T>::get(source).unwrap_or(Percent::from_percent(100)).into()
+		}
+
+		/// Volume weighted price of each asset over all sources. A source glitching would move the price no matter
+		/// its volume, so sources too far from the median price of all sources are left out.
+		pub(crate) fn combine_prices(
+			prices: BTreeMap<AssetName, Vec<(FixedU128, FixedU128)>>,
+		) -> BTreeMap<AssetName, FixedU128> {
 			prices
 				.into_iter()
 				.filter_map(|(key, price_list)| {
-					if price_list.is_empty() {
-						return None;
-					}
-					let combined_prices =
-						price_list.into_iter().fold((FixedU128::zero(), FixedU128::zero()), |acc, (price, volume)| {
+					let price_list =
+						price_list.into_iter().filter(|(_, volume)| !volume.is_zero()).collect::<Vec<_>>();
+					let median = Self::median(price_list.iter().map(|(price, volume)| price.div(*volume)).collect())?;
+					let max_deviation = median.saturating_mul(MAX_SOURCE_DEVIATION);
+					let combined_prices = price_list
+						.into_iter()
+						.filter(|(price, volume)| {
+							let source_price = price.div(*volume);
+							source_price.max(median).saturating_sub(source_price.min(median)) <= max_deviation
+						})
+						.fold((FixedU128::zero(), FixedU128::zero()), |acc, (price, volume)| {
 							(acc.0 + price, acc.1 + volume)
 						});
 					if combined_prices.1.is_zero() {
@@ -242,14 +520,66 @@ pub mod pallet {
 				.collect::<BTreeMap<AssetName, FixedU128>>()
 		}
 
-		fn send_signed_transaction(prices: BTreeMap<AssetName, FixedU128>) -> Result<(), ()> {
-			let signer = Signer::<T, T::AppCrypto>::any_account();
+		fn median(mut prices: Vec<FixedU128>) -> Option<FixedU128> {
+			if prices.is_empty() {
+				return None;
+			}
+			prices.sort();
+			let mid = prices.len() / 2;
+			if prices.len() % 2 == 0 {
+				Some(prices[mid - 1].saturating_add(prices[mid]).div(FixedU128::from_u32(2)))
+			} else {
+				Some(prices[mid])
+			}
+		}
+
+		fn track_missed_windows(interval_start: BlockNumberFor<T>) -> Weight {
+			let thresholds = AccountabilityThresholds::<T>::get();
+			let members = T::Members::sorted_members();
+			let mut weight = T::DbWeight::get().reads(2);
+			for member in members {
+				weight.saturating_accrue(T::DbWeight::get().reads(2));
+				if SuspendedFeeders::<T>::contains_key(&member) {
+					continue;
+				}
+				let mut stats = FeedersStats::<T>::get(&member);
+				// Feeders resumed during the interval are not expected to have fed in it
+				if stats.last_feed >= interval_start || stats.last_resume >= interval_start {
+					continue;
+				}
+
+				stats.missed_windows.saturating_inc();
+				stats.consecutive_missed_windows.saturating_inc();
+				let over_threshold = stats.consecutive_missed_windows > thresholds.max_consecutive_missed_windows;
+				Self::flag_feeder(&member, FeederIssue::MissedWindow, over_threshold && thresholds.auto_suspend);
+				FeedersStats::<T>::insert(&member, stats);
+				weight.saturating_accrue(T::DbWeight::get().writes(2));
+			}
+			weight
+		}
+
+		pub(crate) fn flag_feeder(feeder: &AccountIdOf<T>, issue: FeederIssue, suspend: bool) {
+			if suspend {
+				SuspendedFeeders::<T>::insert(feeder, frame_system::Pallet::<T>::block_number());
+				Self::deposit_event(Event::FeederSuspended { feeder: feeder.clone(), issue });
+			} else {
+				Self::deposit_event(Event::FeederWarned { feeder: feeder.clone(), issue });
+			}
+		}
+
+		fn oracle_values(
+			prices: BTreeMap<AssetName, FixedU128>,
+		) -> BoundedVec<(T::OracleKey, T::OracleValue), T::MaxFeedValues> {
 			let prices = prices
 				.into_iter()
 				.map(|(asset_name, price)| T::ConvertAssetPricePair::convert((asset_name, price)))
 				.collect::<Vec<(T::OracleKey, T::OracleValue)>>();
+			BoundedVec::<_, _>::truncate_from(prices)
+		}
 
-			let call = OracleCall::<T, ()>::feed_values { values: BoundedVec::<_, _>::truncate_from(prices) };
+		fn send_signed_transaction(prices: BTreeMap<AssetName, FixedU128>, keys: Vec<T::Public>) -> Result<(), ()> {
+			let signer = Signer::<T, T::AppCrypto>::any_account().with_filter(keys);
+			let call = OracleCall::<T, ()>::feed_values { values: Self::oracle_values(prices) };
 			let result = signer.send_signed_transaction(|_account| call.clone());
 			match result {
 				Some((_, Ok(_))) => {
@@ -262,5 +592,182 @@ pub mod pallet {
 				},
 			}
 		}
+
+		/// Feed the prices from every key in `keys`, each in its own unsigned transaction. No fees are paid and no
+		/// nonces are used.
+		fn send_unsigned_transaction(
+			prices: BTreeMap<AssetName, FixedU128>,
+			keys: Vec<T::Public>,
+			block_number: BlockNumberFor<T>,
+		) -> Result<(), ()> {
+			let values = Self::oracle_values(prices);
+			let results = Signer::<T, T::AppCrypto>::all_accounts().with_filter(keys).send_unsigned_transaction(
+				|account| PricePayload { public: account.public.clone(), block_number, values: values.clone() },
+				|payload, signature| Call::feed_values_unsigned { payload, signature },
+			);
+			if results.iter().any(|(_, result)| result.is_ok()) {
+				log::trace!(target: LOG_TARGET, "offchain unsigned tx sent successfully");
+				return Ok(());
+			}
+			log::trace!(target: LOG_TARGET, "failure: offchain_unsigned_tx");
+			Err(())
+		}
+
+		/// First block of the fetch window `block_number` is in.
+		pub(crate) fn fetch_window_start(block_number: BlockNumberFor<T>) -> BlockNumberFor<T> {
+			let interval = T::FetchInterval::get();
+			if interval.is_zero() {
+				return block_number;
+			}
+			block_number.saturating_sub(block_number.rem(interval))
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		/// Prices fed without a signed transaction are only valid with a signature of an active member, for
+		/// `FetchWindow` blocks after they were fetched, and once per feeder and window.
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			let Call::feed_values_unsigned { payload, signature } = call else {
+				return InvalidTransaction::Call.into();
+			};
+			if !SignedPayload::<T>::verify::<T::AppCrypto>(payload, signature.clone()) {
+				return InvalidTransaction::BadProof.into();
+			}
+			let feeder = payload.public.clone().into_account();
+			if !T::Members::contains(&feeder) || SuspendedFeeders::<T>::contains_key(&feeder) {
+				return InvalidTransaction::BadSigner.into();
+			}
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let valid_until = payload.block_number.saturating_add(T::FetchWindow::get());
+			if payload.block_number > now {
+				return InvalidTransaction::Future.into();
+			}
+			if now > valid_until {
+				return InvalidTransaction::Stale.into();
+			}
+			let window_start = Self::fetch_window_start(payload.block_number);
+			let stats = FeedersStats::<T>::get(&feeder);
+			if stats.feeds > 0 && stats.last_feed >= window_start {
+				return InvalidTransaction::Stale.into();
+			}
+
+			ValidTransaction::with_tag_prefix("OracleOcw")
+				.priority(T::UnsignedPriority::get())
+				.and_provides((feeder, window_start))
+				.longevity(valid_until.saturating_sub(now).saturating_add(One::one()).saturated_into::<u64>())
+				.propagate(true)
+				.build()
+		}
+	}
+}
+
+impl<T: Config> Pallet<T>
+where
+	T::OracleValue: FixedPointNumber,
+{
+	/// Time-weighted average price of `key` over the last `TwapWindow`.
+	pub fn twap(key: &T::OracleKey) -> Option<T::OracleValue> {
+		Twaps::<T>::get(key).map(|twap| twap.average_at(T::Time::now(), T::TwapWindow::get()))
+	}
+
+	/// Price snapshots of `key` taken from `from` to `to`, both included, preceded by the one in effect at `from`.
+	/// Sorted by block.
+	pub fn price_history(
+		key: &T::OracleKey,
+		from: BlockNumberFor<T>,
+		to: BlockNumberFor<T>,
+	) -> Vec<(BlockNumberFor<T>, T::OracleValue)> {
+		let mut snapshots = PriceSnapshots::<T>::iter_prefix_values(key).collect::<Vec<_>>();
+		snapshots.sort_by_key(|(block, _)| *block);
+		let first = snapshots.iter().rposition(|(block, _)| *block <= from).unwrap_or(0);
+		snapshots.into_iter().skip(first).take_while(|(block, _)| *block <= to).collect()
+	}
+
+	fn record_price_snapshot(key: &T::OracleKey, price: T::OracleValue) {
+		let max_snapshots = T::MaxPriceHistory::get();
+		if max_snapshots.is_zero() {
+			return;
+		}
+		let now = frame_system::Pallet::<T>::block_number();
+		let head = PriceSnapshotHeads::<T>::get(key);
+		let last = head.checked_sub(1).unwrap_or(max_snapshots - 1);
+
+		// A later price in the same fetch interval replaces the snapshot
+		if let Some((block, _)) = PriceSnapshots::<T>::get(key, last) {
+			if Self::fetch_window_start(block) == Self::fetch_window_start(now) {
+				PriceSnapshots::<T>::insert(key, last, (now, price));
+				return;
+			}
+		}
+		PriceSnapshots::<T>::insert(key, head, (now, price));
+		PriceSnapshotHeads::<T>::insert(key, head.saturating_add(1) % max_snapshots);
+	}
+
+	/// Keep track of how far the values fed by `who` are from the combined value, if there is one yet.
+	fn record_feed(who: &AccountIdOf<T>, value: T::OracleValue, maybe_combined: Option<T::OracleValue>) {
+		let thresholds = AccountabilityThresholds::<T>::get();
+		let mut stats = FeedersStats::<T>::get(who);
+		stats.feeds.saturating_inc();
+		stats.consecutive_missed_windows = 0;
+		stats.last_feed = frame_system::Pallet::<T>::block_number();
+
+		if let Some(combined) = maybe_combined {
+			let max_deviation =
+				T::OracleValue::saturating_from_rational(thresholds.max_deviation.deconstruct(), 100u8);
+			let deviation = value.max(combined).saturating_sub(value.min(combined));
+			if deviation > combined.saturating_mul(max_deviation) {
+				stats.deviating_feeds.saturating_inc();
+				stats.consecutive_deviating_feeds.saturating_inc();
+				let over_threshold = stats.consecutive_deviating_feeds > thresholds.max_consecutive_deviating_feeds;
+				Self::flag_feeder(who, FeederIssue::DeviatingFeed, over_threshold && thresholds.auto_suspend);
+			} else {
+				stats.consecutive_deviating_feeds = 0;
+			}
+		}
+		FeedersStats::<T>::insert(who, stats);
+	}
+}
+
+impl<T: Config> OnNewData<AccountIdOf<T>, T::OracleKey, T::OracleValue> for Pallet<T>
+where
+	T::OracleValue: FixedPointNumber,
+{
+	fn on_new_data(who: &AccountIdOf<T>, key: &T::OracleKey, value: &T::OracleValue) {
+		let maybe_combined = orml_oracle::Pallet::<T, ()>::get(key).map(|combined| combined.value);
+		Self::record_feed(who, *value, maybe_combined);
+
+		// The combined value, not the one fed by `who`, goes into the average.
+		let Some(combined) = maybe_combined else { return };
+		Self::record_price_snapshot(key, combined);
+		let now = T::Time::now();
+		Twaps::<T>::mutate(key, |maybe_twap| match maybe_twap {
+			Some(twap) => twap.update(combined, now, T::TwapWindow::get()),
+			None => *maybe_twap = Some(Twap::new(combined, now)),
+		});
+	}
+}
+
+/// Oracle members that are not suspended. Meant as the `Members` of `orml_oracle`, so suspended members cannot feed.
+pub struct ActiveMembers<T>(PhantomData<T>);
+
+impl<T: Config> SortedMembers<AccountIdOf<T>> for ActiveMembers<T> {
+	fn sorted_members() -> Vec<AccountIdOf<T>> {
+		T::Members::sorted_members()
+			.into_iter()
+			.filter(|member| !SuspendedFeeders::<T>::contains_key(member))
+			.collect()
+	}
+
+	fn contains(who: &AccountIdOf<T>) -> bool {
+		T::Members::contains(who) && !SuspendedFeeders::<T>::contains_key(who)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn add(who: &AccountIdOf<T>) {
+		T::Members::add(who)
 	}
 }
@@ -17,12 +17,13 @@
 #![cfg(test)]
 
 use super::*;
-use crate as pallet_oracle_ocw;
+use crate::{self as pallet_oracle_ocw, traits::AssetHubPoolsConfig};
 
 use frame_support::{
 	construct_runtime, derive_impl, parameter_types,
-	traits::{ConstU32, ConstU64, Hooks, IsInVec, Time},
+	traits::{ConstU32, ConstU64, Hooks, SortedMembers, Time},
 };
+use frame_system::EnsureRoot;
 use parking_lot::RwLock;
 use sp_core::{
 	offchain::{
@@ -85,8 +86,8 @@ impl orml_oracle::Config for Test {
 	type CombineData = orml_oracle::DefaultCombineData<Test, ConstU32<3>, ConstU32<10>, ()>;
 	type MaxFeedValues = MaxFeedValues;
 	type MaxHasDispatchedSize = ConstU32<20>;
-	type Members = IsInVec<Members>;
-	type OnNewData = ();
+	type Members = pallet_oracle_ocw::ActiveMembers<Test>;
+	type OnNewData = OracleOcw;
 	type OracleKey = OracleKey;
 	type OracleValue = OracleValue;
 	type RootOperatorAccountId = RootOperatorAccountId;
@@ -104,6 +105,7 @@ impl Convert<(AssetName, FixedU128), (OracleKey, OracleValue)> for AssetPriceCon
 			AssetName::USDC => (1337, price),
 			AssetName::USDT => (1984, price),
 			AssetName::PLMC => (3344, price),
+			AssetName::Other(asset_id) => (asset_id.into(), price),
 		}
 	}
 }
@@ -114,14 +116,56 @@ parameter_types! {
 		get_account_id_from_seed::<crate::crypto::AuthorityId>("Bob"),
 		get_account_id_from_seed::<crate::crypto::AuthorityId>("Charlie"),
 	];
+	pub static PlmcPoolPrice: Option<(FixedU128, FixedU128)> = None;
 }
+pub struct SortedMockMembers;
+impl SortedMembers<AccountId> for SortedMockMembers {
+	fn sorted_members() -> Vec<AccountId> {
+		let mut members = Members::get();
+		members.sort();
+		members
+	}
+}
+
+pub struct MockPool;
+impl OnChainPriceSource for MockPool {
+	fn price(asset: AssetName) -> Option<(FixedU128, FixedU128)> {
+		match asset {
+			AssetName::PLMC => PlmcPoolPrice::get(),
+			_ => None,
+		}
+	}
+}
+
+pub struct MockAssetHubPools;
+impl AssetHubPoolsConfig for MockAssetHubPools {
+	const RPC_URL: &'static str = "https://asset-hub.example";
+
+	fn pool_asset(asset: AssetName) -> Option<(Vec<u8>, u32)> {
+		match asset {
+			// { parents: 1, interior: Here }
+			AssetName::DOT => Some((vec![1, 0], 10)),
+			// { parents: 0, interior: X2(PalletInstance(50), GeneralIndex(1984)) }
+			AssetName::USDT => Some((vec![0, 2, 4, 50, 5, 1, 31], 6)),
+			// { parents: 1, interior: X1(Parachain(3344)) }
+			AssetName::PLMC => Some((vec![1, 1, 0, 65, 52], 10)),
+			_ => None,
+		}
+	}
+}
+
 impl Config for Test {
+	type AdminOrigin = EnsureRoot<AccountId>;
 	type AppCrypto = crate::crypto::PolimecCrypto;
 	type ConvertAssetPricePair = AssetPriceConverter;
 	type FetchInterval = ConstU64<5u64>;
 	type FetchWindow = ConstU64<1u64>;
-	type Members = IsInVec<Members>;
+	type MaxPriceHistory = ConstU32<3>;
+	type Members = SortedMockMembers;
+	type OnChainPriceSource = MockPool;
 	type RuntimeEvent = RuntimeEvent;
+	type TwapWindow = ConstU32<100>;
+	type UnsignedPriority = ConstU64<{ u64::MAX / 2 }>;
 }
 
 impl frame_system::offchain::SigningTypes for Test {
@@ -156,7 +200,7 @@ construct_runtime!(
 	{
 		System: frame_system::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Oracle: orml_oracle::{Pallet, Storage, Call, Event<T>},
-		OracleOcw: pallet_oracle_ocw::{Pallet, Event<T>},
+		OracleOcw: pallet_oracle_ocw::{Pallet, Call, Storage, Event<T>, ValidateUnsigned},
 
 	}
 );
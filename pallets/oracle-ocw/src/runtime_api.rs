@@ -0,0 +1,27 @@
+// Polimec Blockchain – https://www.polimec.org/
+// Copyright (C) Polimec 2022. All rights reserved.
+
+// The Polimec Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Polimec Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use parity_scale_codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// Prices the oracle combined in the past, so the bonds computed with them can be recomputed.
+	pub trait OraclePriceHistoryApi<OracleKey: Codec, OracleValue: Codec, BlockNumber: Codec> {
+		/// Price snapshots of `key` taken from `from` to `to`, both included, preceded by the one in effect at
+		/// `from`. Sorted by block.
+		fn price_history(key: OracleKey, from: BlockNumber, to: BlockNumber) -> Vec<(BlockNumber, OracleValue)>;
+	}
+}
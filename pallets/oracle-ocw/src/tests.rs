@@ -18,12 +18,28 @@
 
 use crate::{
 	mock::*,
-	traits::FetchPrice,
-	types::{AssetName, BitFinexFetcher, BitStampFetcher, CoinbaseFetcher, KrakenFetcher, MexcFetcher, XTFetcher},
+	traits::{AssetHubPoolsConfig, FetchPrice, OnChainPriceSource},
+	types::{
+		AssetHubPools, AssetName, AssetPriceSources, BitFinexFetcher, BitStampFetcher, CoinbaseFetcher, Exchange,
+		FeederIssue, FeederThresholds, KrakenFetcher, MexcFetcher, PriceSource, PriceSourceKind, SourceUrl, XTFetcher,
+	},
+	fixture_key, Call, Error, Event, FeedersStats, PriceSnapshots, PriceSources, SuspendedFeeders, PRICE_SOURCES_KEY,
+	SUBMIT_UNSIGNED_KEY,
 };
-use parity_scale_codec::Decode;
+use frame_support::{assert_noop, assert_ok, traits::{OnFinalize, OnInitialize}};
+use parity_scale_codec::{Decode, Encode};
+use sp_core::{
+	hexdisplay::HexDisplay,
+	offchain::{testing, StorageKind},
+};
+use sp_runtime::DispatchError;
 use polimec_common_test_utils::do_request;
-use sp_runtime::FixedU128;
+use sp_runtime::{
+	traits::{IdentifyAccount, ValidateUnsigned},
+	transaction_validity::{InvalidTransaction, TransactionSource},
+	FixedU128, Percent,
+};
+use sp_std::collections::btree_map::BTreeMap;
 
 #[test]
 fn call_offchain_worker() {
@@ -52,6 +68,421 @@ fn call_offchain_worker() {
 	});
 }
 
+#[test]
+fn offchain_worker_feeds_unsigned_with_signed_payload() {
+	let (mut ext, offchain_state, pool_state) = new_test_ext_with_offchain_storage();
+	price_oracle_response(&mut offchain_state.write());
+	ext.execute_with(|| {
+		sp_io::offchain::local_storage_set(StorageKind::PERSISTENT, SUBMIT_UNSIGNED_KEY, &true.encode());
+		run_to_block(6);
+
+		let tx = pool_state.write().transactions.pop().unwrap();
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert!(tx.signature.is_none());
+		let RuntimeCall::OracleOcw(call) = tx.call else { panic!("Unexpected call") };
+		let Call::feed_values_unsigned { payload, signature } = call.clone() else { panic!("Unexpected call") };
+		assert_eq!(payload.block_number, 5);
+		assert_eq!(payload.values.len(), 4);
+		assert_ok!(OracleOcw::validate_unsigned(TransactionSource::External, &call));
+
+		let mut tampered_payload = payload.clone();
+		tampered_payload.block_number = 6;
+		let tampered_call = Call::feed_values_unsigned { payload: tampered_payload, signature: signature.clone() };
+		assert_eq!(
+			OracleOcw::validate_unsigned(TransactionSource::External, &tampered_call),
+			InvalidTransaction::BadProof.into()
+		);
+
+		// A feeder resumed in the window can still feed in it
+		let feeder = payload.public.clone().into_account();
+		FeedersStats::<Test>::mutate(feeder, |stats| stats.feeds = 1);
+		SuspendedFeeders::<Test>::insert(feeder, 1);
+		assert_eq!(
+			OracleOcw::validate_unsigned(TransactionSource::External, &call),
+			InvalidTransaction::BadSigner.into()
+		);
+		assert_ok!(OracleOcw::resume_feeder(RuntimeOrigin::root(), feeder));
+		assert_ok!(OracleOcw::validate_unsigned(TransactionSource::External, &call));
+
+		// Only one feed per feeder and window
+		assert_ok!(OracleOcw::feed_values_unsigned(RuntimeOrigin::none(), payload, signature));
+		assert_eq!(OracleOcw::validate_unsigned(TransactionSource::External, &call), InvalidTransaction::Stale.into());
+	});
+}
+
+#[test]
+fn combine_prices_ignores_outlier_sources() {
+	let source = |price: f64, volume: f64| (FixedU128::from_float(price * volume), FixedU128::from_float(volume));
+	let prices = BTreeMap::from([
+		// The glitching source has the most volume, but is far from the median of all sources
+		(AssetName::DOT, vec![source(6.1, 100.0), source(6.2, 100.0), source(6.15, 50.0), source(9.0, 1000.0)]),
+		// Sources without volume are not taken into account
+		(AssetName::USDT, vec![source(1.0, 10.0), (FixedU128::from_float(2.0), FixedU128::from_float(0.0))]),
+		(AssetName::USDC, vec![]),
+	]);
+
+	let combined = OracleOcw::combine_prices(prices);
+	assert_close_enough(combined[&AssetName::DOT], FixedU128::from_float(6.15));
+	assert_close_enough(combined[&AssetName::USDT], FixedU128::from_float(1.0));
+	assert!(!combined.contains_key(&AssetName::USDC));
+}
+
+#[test]
+fn twap_follows_fed_prices_over_time() {
+	let (mut ext, _, _) = new_test_ext_with_offchain_storage();
+	ext.execute_with(|| {
+		let feed = |price: f64| {
+			for member in Members::get() {
+				let values = vec![(0, FixedU128::from_float(price))].try_into().unwrap();
+				assert_ok!(Oracle::feed_values(RuntimeOrigin::signed(member), values));
+			}
+			Oracle::on_finalize(System::block_number());
+		};
+
+		Timestamp::set_timestamp(0);
+		feed(10.0);
+		assert_eq!(OracleOcw::twap(&0), Some(FixedU128::from_float(10.0)));
+
+		// A new price has no weight until time passes
+		Timestamp::set_timestamp(50);
+		feed(20.0);
+		assert_eq!(OracleOcw::twap(&0), Some(FixedU128::from_float(10.0)));
+
+		// Half of the window at 10 and half at 20
+		Timestamp::set_timestamp(100);
+		assert_close_enough(OracleOcw::twap(&0).unwrap(), FixedU128::from_float(15.0));
+
+		// The old price is out of the window
+		Timestamp::set_timestamp(1000);
+		assert_eq!(OracleOcw::twap(&0), Some(FixedU128::from_float(20.0)));
+		assert_eq!(OracleOcw::twap(&1), None);
+	});
+}
+
+fn mexc_source(url: &str) -> PriceSource {
+	PriceSource { exchange: Exchange::Mexc, url: SourceUrl::truncate_from(url.as_bytes().to_vec()) }
+}
+
+fn kraken_source(url: &str) -> PriceSource {
+	PriceSource { exchange: Exchange::Kraken, url: SourceUrl::truncate_from(url.as_bytes().to_vec()) }
+}
+
+#[test]
+fn price_sources_are_configurable() {
+	let (mut ext, _, _) = new_test_ext_with_offchain_storage();
+	ext.execute_with(|| {
+		let plmc_defaults = AssetPriceSources::defaults(AssetName::PLMC);
+		assert_eq!(plmc_defaults.sources.iter().map(|source| source.exchange).collect::<Vec<_>>(), vec![
+			Exchange::XT,
+			Exchange::Mexc
+		]);
+		assert_eq!(OracleOcw::price_sources().len(), 4);
+
+		let other = AssetPriceSources {
+			asset: AssetName::Other(42),
+			sources: vec![mexc_source("https://mexc.example/klines?symbol=OTHERUSDT")].try_into().unwrap(),
+		};
+		let sources = vec![other.clone(), other.clone()];
+		assert_noop!(
+			OracleOcw::set_price_sources(RuntimeOrigin::root(), Some(sources.try_into().unwrap())),
+			Error::<Test>::DuplicateAsset
+		);
+		let usdt_id = AssetPriceSources { asset: AssetName::Other(1984), ..other.clone() };
+		assert_noop!(
+			OracleOcw::set_price_sources(RuntimeOrigin::root(), Some(vec![usdt_id].try_into().unwrap())),
+			Error::<Test>::BuiltInAssetKey
+		);
+		// Kraken responses can only be parsed for the pairs of its built-in markets
+		let kraken = vec![kraken_source("https://kraken.example/OHLC?pair=OTHERUSD")];
+		for asset in [AssetName::Other(42), AssetName::PLMC] {
+			let unsupported = AssetPriceSources { asset, sources: kraken.clone().try_into().unwrap() };
+			assert_noop!(
+				OracleOcw::set_price_sources(RuntimeOrigin::root(), Some(vec![unsupported].try_into().unwrap())),
+				Error::<Test>::UnsupportedKrakenAsset
+			);
+		}
+		let kraken_dot = AssetPriceSources { asset: AssetName::DOT, sources: kraken.try_into().unwrap() };
+		assert_ok!(OracleOcw::set_price_sources(RuntimeOrigin::root(), Some(vec![kraken_dot].try_into().unwrap())));
+		let sources = vec![other.clone()];
+		assert_noop!(
+			OracleOcw::set_price_sources(
+				RuntimeOrigin::signed(Members::get()[0]),
+				Some(sources.clone().try_into().unwrap())
+			),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(OracleOcw::set_price_sources(RuntimeOrigin::root(), Some(sources.clone().try_into().unwrap())));
+		assert_eq!(OracleOcw::price_sources(), sources);
+
+		// The node operator's sources take precedence over the ones set on chain
+		let local_sources = vec![plmc_defaults];
+		sp_io::offchain::local_storage_set(StorageKind::PERSISTENT, PRICE_SOURCES_KEY, &local_sources.encode());
+		assert_eq!(OracleOcw::price_sources(), local_sources);
+
+		sp_io::offchain::local_storage_clear(StorageKind::PERSISTENT, PRICE_SOURCES_KEY);
+		assert_ok!(OracleOcw::set_price_sources(RuntimeOrigin::root(), None));
+		assert!(PriceSources::<Test>::get().is_none());
+		assert_eq!(OracleOcw::price_sources().len(), 4);
+	});
+}
+
+#[test]
+fn offchain_worker_fetches_configured_sources() {
+	let (mut ext, offchain_state, pool_state) = new_test_ext_with_offchain_storage();
+	let url = "https://mexc.example/klines?symbol=OTHERUSDT";
+	offchain_state.write().expect_request(testing::PendingRequest {
+		method: "GET".into(),
+		uri: url.into(),
+		response: Some(MEXC_PLMC_CORRECT.to_vec()),
+		sent: true,
+		..Default::default()
+	});
+	ext.execute_with(|| {
+		let sources = vec![AssetPriceSources {
+			asset: AssetName::Other(42),
+			sources: vec![mexc_source(url)].try_into().unwrap(),
+		}];
+		assert_ok!(OracleOcw::set_price_sources(RuntimeOrigin::root(), Some(sources.try_into().unwrap())));
+		run_to_block(6);
+
+		let tx = pool_state.write().transactions.pop().unwrap();
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		match tx.call {
+			RuntimeCall::Oracle(orml_oracle::Call::feed_values { values }) => {
+				assert_eq!(values.len(), 1);
+				assert_eq!(values[0].0, 42);
+			},
+			_ => panic!("Unexpected call"),
+		}
+	});
+}
+
+#[test]
+fn offchain_worker_replays_fixtures() {
+	// No request is expected, the testing externalities panic on any HTTP call
+	let (mut ext, _, pool_state) = new_test_ext_with_offchain_storage();
+	ext.execute_with(|| {
+		assert_eq!("mexc".parse(), Ok(Exchange::Mexc));
+		assert_eq!("42".parse(), Ok(AssetName::Other(42)));
+		assert_eq!("PLMC".parse(), Ok(AssetName::PLMC));
+
+		let xt_source = PriceSource {
+			exchange: Exchange::XT,
+			url: SourceUrl::truncate_from(b"https://xt.example/kline?symbol=other_usdt".to_vec()),
+		};
+		let sources = vec![AssetPriceSources {
+			asset: AssetName::Other(42),
+			sources: vec![xt_source, mexc_source("https://mexc.example/klines?symbol=OTHERUSDT")].try_into().unwrap(),
+		}];
+		assert_ok!(OracleOcw::set_price_sources(RuntimeOrigin::root(), Some(sources.try_into().unwrap())));
+		for (exchange, response) in [(Exchange::XT, XT_PLMC_CORRECT), (Exchange::Mexc, MEXC_PLMC_CORRECT)] {
+			let key = fixture_key(exchange, AssetName::Other(42));
+			sp_io::offchain::local_storage_set(StorageKind::PERSISTENT, &key, response);
+		}
+		run_to_block(6);
+
+		let tx = pool_state.write().transactions.pop().unwrap();
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		match tx.call {
+			RuntimeCall::Oracle(orml_oracle::Call::feed_values { values }) => {
+				assert_eq!(values.len(), 1);
+				assert_eq!(values[0].0, 42);
+				assert!(values[0].1 > FixedU128::from_float(0.41) && values[0].1 < FixedU128::from_float(0.42));
+			},
+			_ => panic!("Unexpected call"),
+		}
+	});
+}
+
+#[test]
+fn on_chain_source_is_combined_by_weight() {
+	let (mut ext, _, _) = new_test_ext_with_offchain_storage();
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		let plmc = vec![AssetPriceSources::defaults(AssetName::PLMC)];
+		for (exchange, response) in [(Exchange::XT, XT_PLMC_CORRECT), (Exchange::Mexc, MEXC_PLMC_CORRECT)] {
+			let key = fixture_key(exchange, AssetName::PLMC);
+			sp_io::offchain::local_storage_set(StorageKind::PERSISTENT, &key, response);
+		}
+		let exchanges_price = OracleOcw::fetch_prices(plmc.clone())[&AssetName::PLMC];
+
+		PlmcPoolPrice::set(Some((FixedU128::from_float(0.43), FixedU128::from_u32(10_000))));
+		assert!(OracleOcw::fetch_prices(plmc.clone())[&AssetName::PLMC] > exchanges_price);
+
+		// Only the pool is left
+		let no_weight = Some(Percent::from_percent(0));
+		for exchange in [Exchange::XT, Exchange::Mexc] {
+			assert_ok!(OracleOcw::set_source_weight(
+				RuntimeOrigin::root(),
+				PriceSourceKind::Exchange(exchange),
+				no_weight
+			));
+		}
+		System::assert_last_event(RuntimeEvent::OracleOcw(Event::SourceWeightSet {
+			source: PriceSourceKind::Exchange(Exchange::Mexc),
+			weight: no_weight,
+		}));
+		assert_close_enough(OracleOcw::fetch_prices(plmc.clone())[&AssetName::PLMC], FixedU128::from_float(0.43));
+
+		// No source is left
+		assert_ok!(OracleOcw::set_source_weight(RuntimeOrigin::root(), PriceSourceKind::OnChain, no_weight));
+		assert!(OracleOcw::fetch_prices(plmc.clone()).is_empty());
+
+		// Only the exchanges are left
+		for exchange in [Exchange::XT, Exchange::Mexc] {
+			assert_ok!(OracleOcw::set_source_weight(RuntimeOrigin::root(), PriceSourceKind::Exchange(exchange), None));
+		}
+		assert_close_enough(OracleOcw::fetch_prices(plmc)[&AssetName::PLMC], exchanges_price);
+
+		assert_noop!(
+			OracleOcw::set_source_weight(RuntimeOrigin::signed(Members::get()[0]), PriceSourceKind::OnChain, None),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+fn expect_pool_reserves(state: &mut testing::OffchainState, assets: Vec<u8>, reserves: Option<(u128, u128)>) {
+	let params = HexDisplay::from(&assets);
+	state.expect_request(testing::PendingRequest {
+		method: "POST".into(),
+		uri: MockAssetHubPools::RPC_URL.into(),
+		headers: vec![("Content-Type".into(), "application/json".into())],
+		body: format!(
+			r#"{{"id":1,"jsonrpc":"2.0","method":"state_call","params":["AssetConversionApi_get_reserves","0x{}"]}}"#,
+			params
+		)
+		.into_bytes(),
+		response: Some(
+			format!(r#"{{"jsonrpc":"2.0","result":"0x{}","id":1}}"#, HexDisplay::from(&reserves.encode())).into_bytes(),
+		),
+		sent: true,
+		..Default::default()
+	});
+}
+
+#[test]
+fn asset_hub_pools_price_assets_through_dot() {
+	let (mut ext, offchain_state, _) = new_test_ext_with_offchain_storage();
+	let location = |asset| MockAssetHubPools::pool_asset(asset).unwrap().0;
+	let (dot, usdt, plmc) = (location(AssetName::DOT), location(AssetName::USDT), location(AssetName::PLMC));
+	// 1_000 DOT against 6_000 USDT, DOT is at 6 USD
+	expect_pool_reserves(
+		&mut offchain_state.write(),
+		[dot.clone(), usdt].concat(),
+		Some((1_000 * 10u128.pow(10), 6_000 * 10u128.pow(6))),
+	);
+	// 100_000 PLMC against 700 DOT, PLMC is at 0.042 USD
+	expect_pool_reserves(
+		&mut offchain_state.write(),
+		[plmc, dot].concat(),
+		Some((100_000 * 10u128.pow(10), 700 * 10u128.pow(10))),
+	);
+	ext.execute_with(|| {
+		assert_eq!(
+			AssetHubPools::<MockAssetHubPools>::price(AssetName::PLMC),
+			Some((FixedU128::from_rational(42, 1000), FixedU128::from_u32(100_000)))
+		);
+		// DOT is only the route to USD, and USDC has no pool. Neither is requested.
+		assert_eq!(AssetHubPools::<MockAssetHubPools>::price(AssetName::DOT), None);
+		assert_eq!(AssetHubPools::<MockAssetHubPools>::price(AssetName::USDC), None);
+	});
+}
+
+#[test]
+fn price_history_is_kept_per_fetch_interval() {
+	let (mut ext, _, _) = new_test_ext_with_offchain_storage();
+	ext.execute_with(|| {
+		let price = FixedU128::from_u32;
+		let feed_round = |block: u64, value: u32| {
+			System::set_block_number(block);
+			for member in Members::get() {
+				assert_ok!(Oracle::feed_values(
+					RuntimeOrigin::signed(member),
+					vec![(0, price(value))].try_into().unwrap()
+				));
+			}
+			Oracle::on_finalize(block);
+		};
+
+		// Only the last combined price of a fetch interval is kept
+		feed_round(1, 9);
+		feed_round(3, 10);
+		assert_eq!(OracleOcw::price_history(&0, 0, 10), vec![(3, price(10))]);
+		feed_round(6, 11);
+		feed_round(11, 12);
+		assert_eq!(OracleOcw::price_history(&0, 4, 8), vec![(3, price(10)), (6, price(11))]);
+		assert_eq!(OracleOcw::price_history(&0, 6, 20), vec![(6, price(11)), (11, price(12))]);
+
+		// The oldest snapshot is overwritten
+		feed_round(16, 13);
+		assert_eq!(PriceSnapshots::<Test>::iter_prefix(0).count(), 3);
+		assert_eq!(OracleOcw::price_history(&0, 0, 20), vec![(6, price(11)), (11, price(12)), (16, price(13))]);
+		assert!(OracleOcw::price_history(&1337, 0, 10).is_empty());
+	});
+}
+
+#[test]
+fn feeders_are_held_accountable() {
+	let (mut ext, _, _) = new_test_ext_with_offchain_storage();
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		let members = Members::get();
+		let (alice, bob, charlie) = (members[0], members[1], members[2]);
+		let feed = |who: AccountId, price: f64| {
+			Oracle::feed_values(RuntimeOrigin::signed(who), vec![(0, FixedU128::from_float(price))].try_into().unwrap())
+		};
+		let thresholds = FeederThresholds {
+			max_deviation: Percent::from_percent(5),
+			max_consecutive_deviating_feeds: 1,
+			max_consecutive_missed_windows: 1,
+			auto_suspend: true,
+		};
+		assert_ok!(OracleOcw::set_accountability_thresholds(RuntimeOrigin::root(), thresholds));
+
+		// Charlie feeds a value far from the combined one twice in a row
+		for charlie_price in [10.0, 20.0, 20.0] {
+			assert_ok!(feed(alice, 10.0));
+			assert_ok!(feed(bob, 10.0));
+			assert_ok!(feed(charlie, charlie_price));
+			Oracle::on_finalize(System::block_number());
+			if charlie_price > 10.0 && !SuspendedFeeders::<Test>::contains_key(charlie) {
+				System::assert_has_event(RuntimeEvent::OracleOcw(Event::FeederWarned {
+					feeder: charlie,
+					issue: FeederIssue::DeviatingFeed,
+				}));
+			}
+		}
+		System::assert_has_event(RuntimeEvent::OracleOcw(Event::FeederSuspended {
+			feeder: charlie,
+			issue: FeederIssue::DeviatingFeed,
+		}));
+		let charlie_stats = FeedersStats::<Test>::get(charlie);
+		assert_eq!((charlie_stats.feeds, charlie_stats.deviating_feeds), (3, 2));
+		assert_noop!(feed(charlie, 10.0), orml_oracle::Error::<Test>::NoPermission);
+
+		// Bob stops feeding
+		System::set_block_number(5);
+		assert_ok!(feed(alice, 10.0));
+		OracleOcw::on_initialize(10);
+		System::assert_has_event(RuntimeEvent::OracleOcw(Event::FeederWarned {
+			feeder: bob,
+			issue: FeederIssue::MissedWindow,
+		}));
+		OracleOcw::on_initialize(15);
+		System::assert_has_event(RuntimeEvent::OracleOcw(Event::FeederSuspended {
+			feeder: bob,
+			issue: FeederIssue::MissedWindow,
+		}));
+		assert_eq!(FeedersStats::<Test>::get(bob).missed_windows, 2);
+		assert_eq!(FeedersStats::<Test>::get(alice).missed_windows, 1);
+
+		assert_ok!(OracleOcw::resume_feeder(RuntimeOrigin::root(), charlie));
+		assert_noop!(OracleOcw::resume_feeder(RuntimeOrigin::root(), charlie), Error::<Test>::FeederNotSuspended);
+		Oracle::on_finalize(System::block_number());
+		assert_ok!(feed(charlie, 10.0));
+	});
+}
+
 fn test_fetcher_against_real_api<F: FetchPrice>() {
 	for asset in vec![AssetName::DOT, AssetName::USDC, AssetName::USDT, AssetName::PLMC] {
 		let url = F::get_url(asset);
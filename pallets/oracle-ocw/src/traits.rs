@@ -23,16 +23,73 @@ use sp_runtime::{
 	FixedU128, Saturating,
 };
 
+/// Price source read from the chain state instead of an exchange API, e.g. an asset-conversion pool.
+pub trait OnChainPriceSource {
+	/// Price of `asset` and the volume backing it. `None` if there is no on-chain market for it.
+	fn price(asset: AssetName) -> Option<(FixedU128, FixedU128)>;
+}
+
+impl OnChainPriceSource for () {
+	fn price(_asset: AssetName) -> Option<(FixedU128, FixedU128)> {
+		None
+	}
+}
+
+/// Asset Hub pools read by `AssetHubPools`.
+pub trait AssetHubPoolsConfig {
+	/// URL of the Asset Hub RPC node the pool reserves are read from.
+	const RPC_URL: &'static str;
+
+	/// SCALE encoded XCM v3 location of `asset` on Asset Hub and its decimals, if it has a pool against DOT. DOT and
+	/// USDT must be given, as DOT prices are turned into USD with the DOT/USDT pool.
+	fn pool_asset(asset: AssetName) -> Option<(Vec<u8>, u32)>;
+}
+
 pub(crate) trait FetchPrice {
-	fn get_moving_average(assets: Vec<AssetName>, timeout: u64) -> Vec<(AssetName, FixedU128, FixedU128)> {
-		let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(timeout));
-		let asset_requests = assets
+	/// Exchange whose candle responses this fetcher parses.
+	const EXCHANGE: Exchange;
+
+	fn get_moving_average(requests: Vec<(AssetName, &str)>, timeout: u64) -> Vec<(AssetName, FixedU128, FixedU128)> {
+		// Recorded responses are replayed instead of querying the exchange.
+		let mut bodies = Vec::new();
+		let mut live_requests = Vec::new();
+		for (asset, url) in requests {
+			match fixture(Self::EXCHANGE, asset) {
+				Some(body) => bodies.push((asset, body)),
+				None if !url.is_empty() => live_requests.push((asset, url)),
+				None => {},
+			}
+		}
+		bodies.extend(Self::fetch_bodies(live_requests, timeout));
+
+		bodies
 			.into_iter()
-			.filter_map(|asset| {
-				let url = Self::get_url(asset);
-				if url.is_empty() {
+			.filter_map(|(asset, body)| {
+				if let Ok(body_str) = sp_std::str::from_utf8(&body) {
+					if let Some(ocv_data) = Self::parse_body(body_str) {
+						return Some((asset, ocv_data));
+					}
+				}
+				None
+			})
+			.filter_map(|(asset, ocv_data)| {
+				let (w_price_sum, total_vol) =
+					ocv_data.into_iter().fold((FixedU128::zero(), FixedU128::zero()), |(w_price_sum, vol_sum), ocv| {
+						(w_price_sum + ocv.vwp(), vol_sum.saturating_add(ocv.volume))
+					});
+				if total_vol.is_zero() {
 					return None;
 				}
+				Some((asset, w_price_sum, total_vol))
+			})
+			.collect::<Vec<(AssetName, FixedU128, FixedU128)>>()
+	}
+
+	fn fetch_bodies(requests: Vec<(AssetName, &str)>, timeout: u64) -> Vec<(AssetName, Vec<u8>)> {
+		let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(timeout));
+		let asset_requests = requests
+			.into_iter()
+			.filter_map(|(asset, url)| {
 				let request = http::Request::get(url);
 
 				if let Ok(req) = request.deadline(deadline).send() {
@@ -53,33 +110,15 @@ pub(crate) trait FetchPrice {
 					if response.code != 200 {
 						return None;
 					}
-					return Some((asset, response));
-				}
-				None
-			})
-			.filter_map(|(asset, response)| {
-				let body = response.body().collect::<Vec<u8>>();
-				if let Ok(body_str) = sp_std::str::from_utf8(&body) {
-					if let Some(ocv_data) = Self::parse_body(body_str) {
-						return Some((asset, ocv_data));
-					}
+					return Some((asset, response.body().collect::<Vec<u8>>()));
 				}
 				None
 			})
-			.filter_map(|(asset, ocv_data)| {
-				let (w_price_sum, total_vol) =
-					ocv_data.into_iter().fold((FixedU128::zero(), FixedU128::zero()), |(w_price_sum, vol_sum), ocv| {
-						(w_price_sum + ocv.vwp(), vol_sum.saturating_add(ocv.volume))
-					});
-				if total_vol.is_zero() {
-					return None;
-				}
-				Some((asset, w_price_sum, total_vol))
-			})
-			.collect::<Vec<(AssetName, FixedU128, FixedU128)>>()
+			.collect()
 	}
 
 	fn parse_body(body: &str) -> Option<Vec<OpenCloseVolume>>;
 
+	/// Built-in endpoint for the candles of an asset, empty if there is none.
 	fn get_url(name: AssetName) -> &'static str;
 }
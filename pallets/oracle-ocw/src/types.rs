@@ -14,22 +14,252 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 use super::*;
+use crate::traits::AssetHubPoolsConfig;
 use core::{ops::Mul, str::FromStr};
+use frame_support::{
+	traits::ConstU32, BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use frame_system::{offchain::SignedPayload, pallet_prelude::BlockNumberFor};
 use heapless::{LinearMap, Vec as HVec};
-use parity_scale_codec::{Decode, Encode};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use serde::Deserialize;
 use sp_core::{offchain::HttpRequestId as RequestId, RuntimeDebug};
-use sp_runtime::{FixedPointNumber, Saturating};
+use sp_runtime::{
+	offchain::{http, Duration},
+	traits::{AtLeast32Bit, CheckedDiv, SaturatedConversion},
+	FixedPointNumber, Percent, Saturating,
+};
 use sp_std::vec::Vec;
 use substrate_fixed::{traits::ToFixed, types::U100F28};
 
-#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, PartialOrd, Ord, Encode, Decode, TypeInfo)]
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, PartialOrd, Ord, Encode, Decode, MaxEncodedLen, TypeInfo)]
 pub enum AssetName {
 	USDT,
 	USDC,
 	DOT,
 	PLMC,
+	/// An asset without built-in price sources, identified by its asset id.
+	Other(u32),
+}
+
+impl FromStr for AssetName {
+	type Err = ();
+
+	/// Parses the ticker of a known asset, e.g. `DOT`, or the asset id of any other one.
+	fn from_str(name: &str) -> Result<Self, Self::Err> {
+		match name {
+			"USDT" => Ok(AssetName::USDT),
+			"USDC" => Ok(AssetName::USDC),
+			"DOT" => Ok(AssetName::DOT),
+			"PLMC" => Ok(AssetName::PLMC),
+			_ => name.parse::<u32>().map(AssetName::Other).map_err(|_| ()),
+		}
+	}
+}
+
+/// Exchanges whose candle responses the OCW can parse.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub enum Exchange {
+	/// Only the USDT, USDC and DOT pairs can be parsed, as the response is keyed by the pair name.
+	Kraken,
+	BitFinex,
+	BitStamp,
+	Coinbase,
+	XT,
+	Mexc,
+}
+
+impl Exchange {
+	pub const ALL: [Exchange; 6] =
+		[Exchange::Kraken, Exchange::BitFinex, Exchange::BitStamp, Exchange::Coinbase, Exchange::XT, Exchange::Mexc];
+
+	/// Built-in endpoint for the candles of `asset`. Empty if the exchange has no market for it.
+	pub fn default_url(&self, asset: AssetName) -> &'static str {
+		match self {
+			Exchange::Kraken => KrakenFetcher::get_url(asset),
+			Exchange::BitFinex => BitFinexFetcher::get_url(asset),
+			Exchange::BitStamp => BitStampFetcher::get_url(asset),
+			Exchange::Coinbase => CoinbaseFetcher::get_url(asset),
+			Exchange::XT => XTFetcher::get_url(asset),
+			Exchange::Mexc => MexcFetcher::get_url(asset),
+		}
+	}
+
+	pub(crate) fn get_moving_average(
+		&self,
+		requests: Vec<(AssetName, &str)>,
+		timeout: u64,
+	) -> Vec<(AssetName, FixedU128, FixedU128)> {
+		match self {
+			Exchange::Kraken => KrakenFetcher::get_moving_average(requests, timeout),
+			Exchange::BitFinex => BitFinexFetcher::get_moving_average(requests, timeout),
+			Exchange::BitStamp => BitStampFetcher::get_moving_average(requests, timeout),
+			Exchange::Coinbase => CoinbaseFetcher::get_moving_average(requests, timeout),
+			Exchange::XT => XTFetcher::get_moving_average(requests, timeout),
+			Exchange::Mexc => MexcFetcher::get_moving_average(requests, timeout),
+		}
+	}
+}
+
+impl FromStr for Exchange {
+	type Err = ();
+
+	/// Parses the lowercase name of the exchange, e.g. `kraken`.
+	fn from_str(name: &str) -> Result<Self, Self::Err> {
+		match name {
+			"kraken" => Ok(Exchange::Kraken),
+			"bitfinex" => Ok(Exchange::BitFinex),
+			"bitstamp" => Ok(Exchange::BitStamp),
+			"coinbase" => Ok(Exchange::Coinbase),
+			"xt" => Ok(Exchange::XT),
+			"mexc" => Ok(Exchange::Mexc),
+			_ => Err(()),
+		}
+	}
+}
+
+/// Kind of source a price comes from, which governance can give a weight to.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub enum PriceSourceKind {
+	Exchange(Exchange),
+	/// The `OnChainPriceSource` of the runtime.
+	OnChain,
+}
+
+pub type SourceUrl = BoundedVec<u8, ConstU32<MAX_SOURCE_URL_LENGTH>>;
+
+/// Endpoint to fetch the candles of an asset from. The response is parsed as the one of `exchange`.
+#[derive(Debug, Clone, Eq, PartialEq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct PriceSource {
+	pub exchange: Exchange,
+	pub url: SourceUrl,
+}
+
+/// Prices fed in an unsigned transaction, signed with the key of the feeder instead.
+#[derive(Encode, Decode, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct PricePayload<T: Config> {
+	pub public: T::Public,
+	/// Block the prices were fetched at.
+	pub block_number: BlockNumberFor<T>,
+	pub values: BoundedVec<(T::OracleKey, T::OracleValue), T::MaxFeedValues>,
+}
+
+impl<T: Config> SignedPayload<T> for PricePayload<T> {
+	fn public(&self) -> T::Public {
+		self.public.clone()
+	}
+}
+
+/// An asset the OCW fetches the price of, and where from.
+#[derive(Debug, Clone, Eq, PartialEq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct AssetPriceSources {
+	pub asset: AssetName,
+	pub sources: BoundedVec<PriceSource, ConstU32<MAX_SOURCES_PER_ASSET>>,
+}
+
+impl AssetPriceSources {
+	/// The built-in sources of `asset`, on every exchange that has a market for it.
+	pub fn defaults(asset: AssetName) -> Self {
+		let sources = Exchange::ALL
+			.into_iter()
+			.filter_map(|exchange| {
+				let url = exchange.default_url(asset);
+				if url.is_empty() {
+					return None;
+				}
+				Some(PriceSource { exchange, url: SourceUrl::truncate_from(url.as_bytes().to_vec()) })
+			})
+			.collect::<Vec<_>>();
+		Self { asset, sources: BoundedVec::truncate_from(sources) }
+	}
+}
+
+/// Rolling time-weighted average of an oracle value. Only the average up to the last update is stored, the time
+/// since then is weighted in when reading it.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct Twap<Price, Moment> {
+	/// Average over the window ending at `last_update`.
+	pub average: Price,
+	/// Value in effect since `last_update`.
+	pub last_price: Price,
+	pub last_update: Moment,
+}
+
+impl<Price: FixedPointNumber, Moment: AtLeast32Bit + Copy> Twap<Price, Moment> {
+	pub fn new(price: Price, now: Moment) -> Self {
+		Self { average: price, last_price: price, last_update: now }
+	}
+
+	/// Average over the `window` ending at `now`. `last_price` is weighted by how long it has been in effect, and the
+	/// rest of the window by the previous average.
+	pub fn average_at(&self, now: Moment, window: Moment) -> Price {
+		if window.is_zero() {
+			return self.last_price;
+		}
+		let elapsed = now.saturating_sub(self.last_update).min(window);
+		let weight = Price::saturating_from_rational(elapsed.saturated_into::<u128>(), window.saturated_into::<u128>());
+		self.average
+			.saturating_mul(Price::one().saturating_sub(weight))
+			.saturating_add(self.last_price.saturating_mul(weight))
+	}
+
+	pub fn update(&mut self, price: Price, now: Moment, window: Moment) {
+		self.average = self.average_at(now, window);
+		self.last_price = price;
+		self.last_update = now;
+	}
+}
+
+/// How a member has been feeding the oracle.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct FeederStats<BlockNumber> {
+	/// Values fed
+	pub feeds: u32,
+	/// Values fed further than `FeederThresholds::max_deviation` from the combined value
+	pub deviating_feeds: u32,
+	/// Fetch intervals without a single value fed
+	pub missed_windows: u32,
+	/// Deviating values fed since the last one close to the combined value
+	pub consecutive_deviating_feeds: u32,
+	/// Fetch intervals missed since the last one with a value fed
+	pub consecutive_missed_windows: u32,
+	pub last_feed: BlockNumber,
+	/// Block the feeder was last resumed at after being suspended
+	pub last_resume: BlockNumber,
+}
+
+/// When feeders get a warning, and when they are suspended.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct FeederThresholds {
+	/// A value further than this from the combined value is a deviating feed
+	pub max_deviation: Percent,
+	/// Deviating feeds in a row tolerated before going over the threshold
+	pub max_consecutive_deviating_feeds: u32,
+	/// Missed fetch intervals in a row tolerated before going over the threshold
+	pub max_consecutive_missed_windows: u32,
+	/// Suspend feeders going over a threshold. Otherwise they only get a warning.
+	pub auto_suspend: bool,
+}
+
+impl Default for FeederThresholds {
+	fn default() -> Self {
+		Self {
+			max_deviation: Percent::from_percent(5),
+			max_consecutive_deviating_feeds: 3,
+			max_consecutive_missed_windows: 3,
+			auto_suspend: false,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub enum FeederIssue {
+	/// A value fed was too far from the combined value
+	DeviatingFeed,
+	/// Nothing was fed during a fetch interval
+	MissedWindow,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -115,6 +345,8 @@ struct KrakenResponse {
 }
 pub(crate) struct KrakenFetcher;
 impl FetchPrice for KrakenFetcher {
+	const EXCHANGE: Exchange = Exchange::Kraken;
+
 	fn parse_body(body: &str) -> Option<Vec<OpenCloseVolume>> {
 		let maybe_response = serde_json_core::from_str::<KrakenResponse>(body);
 		if let Err(e) = maybe_response {
@@ -137,6 +369,8 @@ impl FetchPrice for KrakenFetcher {
 
 pub(crate) struct BitFinexFetcher;
 impl FetchPrice for BitFinexFetcher {
+	const EXCHANGE: Exchange = Exchange::BitFinex;
+
 	fn parse_body(body: &str) -> Option<Vec<OpenCloseVolume>> {
 		let maybe_response = serde_json_core::from_str::<HVec<(u64, f64, f64, f64, f64, f64), NUMBER_OF_CANDLES>>(body);
 		if let Err(e) = maybe_response {
@@ -204,6 +438,8 @@ struct BitStampResponse {
 
 pub(crate) struct BitStampFetcher;
 impl FetchPrice for BitStampFetcher {
+	const EXCHANGE: Exchange = Exchange::BitStamp;
+
 	fn parse_body(body: &str) -> Option<Vec<OpenCloseVolume>> {
 		let maybe_response = serde_json_core::from_str::<BitStampResponse>(body);
 		if let Err(e) = maybe_response {
@@ -230,6 +466,8 @@ impl FetchPrice for BitStampFetcher {
 
 pub(crate) struct CoinbaseFetcher;
 impl FetchPrice for CoinbaseFetcher {
+	const EXCHANGE: Exchange = Exchange::Coinbase;
+
 	fn parse_body(body: &str) -> Option<Vec<OpenCloseVolume>> {
 		let maybe_response = serde_json_core::from_str::<HVec<(u64, f64, f64, f64, f64, f64), 1000>>(body);
 		if let Err(e) = maybe_response {
@@ -298,6 +536,8 @@ struct XTResponse {
 }
 pub(crate) struct XTFetcher;
 impl FetchPrice for XTFetcher {
+	const EXCHANGE: Exchange = Exchange::XT;
+
 	fn parse_body(body: &str) -> Option<Vec<OpenCloseVolume>> {
 		let maybe_response = serde_json_core::from_str::<XTResponse>(body);
 		if let Err(e) = maybe_response {
@@ -319,6 +559,8 @@ impl FetchPrice for XTFetcher {
 
 pub(crate) struct MexcFetcher;
 impl FetchPrice for MexcFetcher {
+	const EXCHANGE: Exchange = Exchange::Mexc;
+
 	fn parse_body(body: &str) -> Option<Vec<OpenCloseVolume>> {
 		let maybe_response =
 			serde_json_core::from_str::<HVec<(u64, &str, &str, &str, &str, &str, u64, &str), 10>>(body);
@@ -342,3 +584,78 @@ impl FetchPrice for MexcFetcher {
 		}
 	}
 }
+
+#[derive(Deserialize)]
+struct StateCallResponse<'a> {
+	result: Option<&'a str>,
+}
+
+/// Price of an asset in its Asset Hub asset-conversion pool against DOT, turned into USD with the DOT/USDT pool. The
+/// volume is the liquidity of the asset in its pool, so the `OnChain` source weight should account for it being
+/// much larger than the exchanges' candle volumes. The reserves are read over HTTP, so only the OCW finds prices.
+pub struct AssetHubPools<C>(PhantomData<C>);
+
+impl<C: AssetHubPoolsConfig> OnChainPriceSource for AssetHubPools<C> {
+	fn price(asset: AssetName) -> Option<(FixedU128, FixedU128)> {
+		// DOT and USDT are the route to USD, not priced by their own pool
+		if matches!(asset, AssetName::DOT | AssetName::USDT) {
+			return None;
+		}
+		let (asset_location, asset_decimals) = C::pool_asset(asset)?;
+		let (dot_location, dot_decimals) = C::pool_asset(AssetName::DOT)?;
+		let (usdt_location, usdt_decimals) = C::pool_asset(AssetName::USDT)?;
+
+		let (dot_reserve, usdt_reserve) = Self::reserves(&dot_location, &usdt_location)?;
+		let dot_price =
+			Self::units(usdt_reserve, usdt_decimals)?.checked_div(&Self::units(dot_reserve, dot_decimals)?)?;
+
+		let (asset_reserve, dot_reserve) = Self::reserves(&asset_location, &dot_location)?;
+		let asset_reserve = Self::units(asset_reserve, asset_decimals)?;
+		let price = Self::units(dot_reserve, dot_decimals)?.checked_div(&asset_reserve)?.saturating_mul(dot_price);
+		Some((price, asset_reserve))
+	}
+}
+
+impl<C: AssetHubPoolsConfig> AssetHubPools<C> {
+	const TIMEOUT: u64 = 5000;
+
+	/// Reserves of the pool of `asset1` and `asset2`, in that order.
+	fn reserves(asset1: &[u8], asset2: &[u8]) -> Option<(u128, u128)> {
+		let mut body = b"{\"id\":1,\"jsonrpc\":\"2.0\",\"method\":\"state_call\",\"params\":".to_vec();
+		body.extend_from_slice(b"[\"AssetConversionApi_get_reserves\",\"0x");
+		body.extend(asset1.iter().chain(asset2).flat_map(|byte| hex_digits(*byte)));
+		body.extend_from_slice(b"\"]}");
+
+		let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(Self::TIMEOUT));
+		let request = http::Request::post(C::RPC_URL, vec![body])
+			.add_header("Content-Type", "application/json")
+			.deadline(deadline)
+			.send()
+			.ok()?;
+		let response = request.try_wait(deadline).ok()?.ok()?;
+		if response.code != 200 {
+			return None;
+		}
+		let body = response.body().collect::<Vec<u8>>();
+		let maybe_response = serde_json_core::from_slice::<StateCallResponse>(&body);
+		if let Err(e) = maybe_response {
+			log::error!(target: LOG_TARGET, "Error parsing response for Asset Hub pool: {:?}", e);
+			return None;
+		}
+		let result = maybe_response.ok()?.0.result?.strip_prefix("0x")?;
+		let encoded = (0..result.len())
+			.step_by(2)
+			.map(|i| u8::from_str_radix(result.get(i..i + 2)?, 16).ok())
+			.collect::<Option<Vec<u8>>>()?;
+		Option::<(u128, u128)>::decode(&mut &encoded[..]).ok()?
+	}
+
+	fn units(amount: u128, decimals: u32) -> Option<FixedU128> {
+		FixedU128::checked_from_rational(amount, 10u128.checked_pow(decimals)?)
+	}
+}
+
+fn hex_digits(byte: u8) -> [u8; 2] {
+	const DIGITS: &[u8; 16] = b"0123456789abcdef";
+	[DIGITS[(byte >> 4) as usize], DIGITS[(byte & 0xf) as usize]]
+}
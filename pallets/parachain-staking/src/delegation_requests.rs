@@ -94,6 +94,7 @@ impl<T: Config> Pallet<T> {
 			when_executable: when,
 		});
 		state.less_total = state.less_total.saturating_add(bonded_amount);
+		Self::ensure_stake_in_use_kept(&delegator, &state)?;
 		<DelegationScheduledRequests<T>>::insert(collator.clone(), scheduled_requests);
 		<DelegatorState<T>>::insert(delegator.clone(), state);
 
@@ -139,6 +140,7 @@ impl<T: Config> Pallet<T> {
 			when_executable: when,
 		});
 		state.less_total = state.less_total.saturating_add(decrease_amount);
+		Self::ensure_stake_in_use_kept(&delegator, &state)?;
 		<DelegationScheduledRequests<T>>::insert(collator.clone(), scheduled_requests);
 		<DelegatorState<T>>::insert(delegator.clone(), state);
 
@@ -340,6 +342,7 @@ impl<T: Config> Pallet<T> {
 		if existing_revoke_count == state.delegations.0.len() {
 			return Err(<Error<T>>::DelegatorAlreadyLeaving.into());
 		}
+		Self::ensure_stake_in_use_kept(&delegator, &state)?;
 
 		updated_scheduled_requests.into_iter().for_each(|(collator, scheduled_requests)| {
 			<DelegationScheduledRequests<T>>::insert(collator, scheduled_requests);
@@ -194,6 +194,9 @@ pub mod pallet {
 		/// Handler to notify the runtime when a new round begin.
 		/// If you don't need it, you can specify the type `()`.
 		type OnNewRound: OnNewRound;
+		/// Handler to notify the runtime when stake in use by another pallet stops being delegated.
+		/// If you don't need it, you can specify the type `()`.
+		type OnStakeInUseFreed: OnStakeInUseFreed<Self::AccountId, BalanceOf<Self>>;
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -245,6 +248,7 @@ pub mod pallet {
 		TooLowDelegationCountToAutoCompound,
 		TooLowCandidateAutoCompoundingDelegationCountToAutoCompound,
 		TooLowCandidateAutoCompoundingDelegationCountToDelegate,
+		StakeInUse,
 	}
 
 	#[pallet::event]
@@ -460,6 +464,16 @@ pub mod pallet {
 			delegator: T::AccountId,
 			amount: BalanceOf<T>,
 		},
+		/// Part of the stake of a delegator is now used by another pallet.
+		DelegatorStakeUsed {
+			delegator: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// Part of the stake of a delegator is no longer used by another pallet.
+		DelegatorStakeReleased {
+			delegator: T::AccountId,
+			amount: BalanceOf<T>,
+		},
 	}
 
 	#[pallet::hooks]
@@ -536,6 +550,13 @@ pub mod pallet {
 	pub(crate) type CandidateInfo<T: Config> =
 		StorageMap<_, Twox64Concat, T::AccountId, CandidateMetadata<BalanceOf<T>>, OptionQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn delegator_stake_in_use)]
+	/// Stake of a delegator used by another pallet, e.g. as a participation bond in `pallet-funding`.
+	/// It stays delegated and held, and cannot be scheduled for unbonding until it is released.
+	pub(crate) type DelegatorStakeInUse<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
 	/// Stores outstanding delegation requests per collator.
 	#[pallet::storage]
 	#[pallet::getter(fn delegation_scheduled_requests)]
@@ -997,6 +1018,7 @@ pub mod pallet {
 					} else {
 						<DelegatorState<T>>::insert(&bond.owner, delegator);
 					}
+					Self::free_undelegated_stake_in_use(&bond.owner);
 				} else {
 					// TODO: review. we assume here that this delegator has no remaining staked
 					// balance, so we ensure the lock is cleared
@@ -1450,6 +1472,71 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Stake of a delegator that is not in use yet, and is not being unbonded by a pending request.
+		pub fn usable_delegator_stake(delegator: &T::AccountId) -> BalanceOf<T> {
+			match <DelegatorState<T>>::get(delegator) {
+				Some(state) if state.is_active() => state
+					.total()
+					.saturating_sub(state.less_total)
+					.saturating_sub(<DelegatorStakeInUse<T>>::get(delegator)),
+				_ => Zero::zero(),
+			}
+		}
+
+		/// Let another pallet use `amount` of the stake of a delegator, e.g. as a participation bond in
+		/// `pallet-funding`. The stake stays delegated and held under `HoldReason::StakingDelegator`, so it
+		/// keeps counting for the collator selection, but it cannot be unbonded until it is released with
+		/// [`Self::release_delegator_stake`].
+		pub fn use_delegator_stake(delegator: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+			ensure!(Self::usable_delegator_stake(delegator) >= amount, Error::<T>::InsufficientBalance);
+			<DelegatorStakeInUse<T>>::mutate(delegator, |in_use| *in_use = in_use.saturating_add(amount));
+			Self::deposit_event(Event::DelegatorStakeUsed { delegator: delegator.clone(), amount });
+			Ok(())
+		}
+
+		/// Release `amount` of the stake of a delegator used by another pallet.
+		pub fn release_delegator_stake(delegator: &T::AccountId, amount: BalanceOf<T>) {
+			let in_use = <DelegatorStakeInUse<T>>::get(delegator);
+			let amount = amount.min(in_use);
+			if amount.is_zero() {
+				return;
+			}
+			if in_use == amount {
+				<DelegatorStakeInUse<T>>::remove(delegator);
+			} else {
+				<DelegatorStakeInUse<T>>::insert(delegator, in_use.saturating_sub(amount));
+			}
+			Self::deposit_event(Event::DelegatorStakeReleased { delegator: delegator.clone(), amount });
+		}
+
+		/// Ensure the stake in use of a delegator stays delegated once all its pending requests are executed.
+		pub(crate) fn ensure_stake_in_use_kept(
+			delegator: &T::AccountId,
+			state: &Delegator<T::AccountId, BalanceOf<T>>,
+		) -> DispatchResult {
+			let net_total = state.total().saturating_sub(state.less_total);
+			ensure!(net_total >= <DelegatorStakeInUse<T>>::get(delegator), Error::<T>::StakeInUse);
+			Ok(())
+		}
+
+		/// Release the stake in use of a delegator that is no longer delegated because a delegation was
+		/// removed without the delegator asking for it (their collator left, or they were kicked from the
+		/// bottom delegations), and let [`Config::OnStakeInUseFreed`] know about it.
+		pub(crate) fn free_undelegated_stake_in_use(delegator: &T::AccountId) {
+			let in_use = <DelegatorStakeInUse<T>>::get(delegator);
+			if in_use.is_zero() {
+				return;
+			}
+			let net_total = <DelegatorState<T>>::get(delegator)
+				.map(|state| state.total().saturating_sub(state.less_total))
+				.unwrap_or_else(Zero::zero);
+			let freed = in_use.saturating_sub(net_total);
+			if !freed.is_zero() {
+				Self::release_delegator_stake(delegator, freed);
+				T::OnStakeInUseFreed::on_stake_in_use_freed(delegator, freed);
+			}
+		}
+
 		pub(crate) fn prepare_staking_payouts(now: RoundIndex) -> Weight {
 			// payout is now - delay rounds ago => now - delay > 0 else return early
 			let delay = T::RewardPaymentDelay::get();
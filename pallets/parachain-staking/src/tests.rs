@@ -5448,3 +5448,96 @@ fn test_compute_top_candidates_is_stable() {
 			assert_eq!(ParachainStaking::compute_top_candidates(), vec![2, 3, 4, 5, 6]);
 		});
 }
+
+#[test]
+fn use_delegator_stake_keeps_stake_delegated_and_held() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 40), (3, 30)])
+		.with_candidates(vec![(1, 30), (3, 30)])
+		.with_delegations(vec![(2, 1, 10), (2, 3, 20)])
+		.build()
+		.execute_with(|| {
+			assert_eq!(ParachainStaking::usable_delegator_stake(&2), 30);
+			assert_ok!(ParachainStaking::use_delegator_stake(&2, 15));
+			assert_events_emitted!(Event::DelegatorStakeUsed { delegator: 2, amount: 15 });
+			assert_eq!(ParachainStaking::delegator_stake_in_use(2), 15);
+			assert_eq!(ParachainStaking::usable_delegator_stake(&2), 15);
+
+			// Nothing changes for the collators, the stake is still delegated and held
+			assert_eq!(ParachainStaking::delegator_state(2).unwrap().total(), 30);
+			assert_eq!(Balances::balance_on_hold(&HoldReason::StakingDelegator.into(), &2), 30);
+			assert_eq!(ParachainStaking::candidate_info(1).unwrap().total_counted, 40);
+			assert_eq!(ParachainStaking::candidate_info(3).unwrap().total_counted, 50);
+
+			assert_noop!(ParachainStaking::use_delegator_stake(&2, 16), Error::<Test>::InsufficientBalance);
+			ParachainStaking::release_delegator_stake(&2, 15);
+			assert_events_emitted!(Event::DelegatorStakeReleased { delegator: 2, amount: 15 });
+			assert_eq!(ParachainStaking::usable_delegator_stake(&2), 30);
+		});
+}
+
+#[test]
+fn usable_delegator_stake_excludes_pending_requests() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 40), (3, 30)])
+		.with_candidates(vec![(1, 30), (3, 30)])
+		.with_delegations(vec![(2, 1, 10), (2, 3, 20)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::schedule_revoke_delegation(RuntimeOrigin::signed(2), 3));
+			assert_eq!(ParachainStaking::usable_delegator_stake(&2), 10);
+			assert_noop!(ParachainStaking::use_delegator_stake(&2, 11), Error::<Test>::InsufficientBalance);
+		});
+}
+
+#[test]
+fn stake_in_use_cannot_be_scheduled_for_unbonding() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 40), (3, 30)])
+		.with_candidates(vec![(1, 30), (3, 30)])
+		.with_delegations(vec![(2, 1, 10), (2, 3, 20)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::use_delegator_stake(&2, 15));
+
+			assert_noop!(
+				ParachainStaking::schedule_revoke_delegation(RuntimeOrigin::signed(2), 3),
+				Error::<Test>::StakeInUse
+			);
+			assert_noop!(
+				ParachainStaking::schedule_leave_delegators(RuntimeOrigin::signed(2)),
+				Error::<Test>::StakeInUse
+			);
+			assert_noop!(
+				ParachainStaking::schedule_delegator_bond_less(RuntimeOrigin::signed(2), 3, 16),
+				Error::<Test>::StakeInUse
+			);
+
+			// The stake that is not in use can still be unbonded
+			assert_ok!(ParachainStaking::schedule_delegator_bond_less(RuntimeOrigin::signed(2), 3, 5));
+			assert_eq!(ParachainStaking::usable_delegator_stake(&2), 10);
+
+			ParachainStaking::release_delegator_stake(&2, 15);
+			assert_ok!(ParachainStaking::schedule_leave_delegators(RuntimeOrigin::signed(2)));
+		});
+}
+
+#[test]
+fn stake_in_use_is_freed_when_collator_leaves() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 40), (3, 30)])
+		.with_candidates(vec![(1, 30), (3, 30)])
+		.with_delegations(vec![(2, 1, 20), (2, 3, 10)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::use_delegator_stake(&2, 15));
+			assert_ok!(ParachainStaking::schedule_leave_candidates(RuntimeOrigin::signed(1), 2));
+			roll_to(10);
+			assert_ok!(ParachainStaking::execute_leave_candidates(RuntimeOrigin::signed(1), 1, 1));
+
+			// Only the delegation to candidate 3 is left to back the stake in use
+			assert_events_emitted!(Event::DelegatorStakeReleased { delegator: 2, amount: 5 });
+			assert_eq!(ParachainStaking::delegator_stake_in_use(2), 10);
+			assert_eq!(Balances::balance_on_hold(&HoldReason::StakingDelegator.into(), &2), 10);
+		});
+}
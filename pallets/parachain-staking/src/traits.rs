@@ -29,6 +29,16 @@ impl<AccountId, Balance> OnCollatorPayout<AccountId, Balance> for () {
 	}
 }
 
+/// Handler for stake in use by other pallets (see [`crate::Pallet::use_delegator_stake`]) that stopped being
+/// delegated without the delegator asking for it, e.g. because their collator left.
+pub trait OnStakeInUseFreed<AccountId, Balance> {
+	/// `amount` of the stake in use of `delegator` was released to their free balance.
+	fn on_stake_in_use_freed(delegator: &AccountId, amount: Balance);
+}
+impl<AccountId, Balance> OnStakeInUseFreed<AccountId, Balance> for () {
+	fn on_stake_in_use_freed(_delegator: &AccountId, _amount: Balance) {}
+}
+
 pub trait OnNewRound {
 	fn on_new_round(round_index: crate::RoundIndex) -> Weight;
 }
@@ -650,12 +650,13 @@ impl<
 				if leaving {
 					<DelegatorState<T>>::remove(&lowest_bottom_to_be_kicked.owner);
 					Pallet::<T>::deposit_event(Event::DelegatorLeft {
-						delegator: lowest_bottom_to_be_kicked.owner,
+						delegator: lowest_bottom_to_be_kicked.owner.clone(),
 						unstaked_amount: lowest_bottom_to_be_kicked.amount,
 					});
 				} else {
 					<DelegatorState<T>>::insert(&lowest_bottom_to_be_kicked.owner, delegator_state);
 				}
+				Pallet::<T>::free_undelegated_stake_in_use(&lowest_bottom_to_be_kicked.owner);
 				false
 			} else {
 				!bumped_from_top
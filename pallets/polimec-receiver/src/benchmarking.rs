@@ -0,0 +1,79 @@
+// Polimec Blockchain – https://www.polimec.org/
+// Copyright (C) Polimec 2022. All rights reserved.
+
+// The Polimec Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Polimec Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+// If you feel like getting in touch with us, you can do so at info@polimec.org
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+use frame_benchmarking::v2::*;
+use frame_support::traits::{Currency, Get};
+use frame_system::pallet_prelude::OriginFor;
+use polimec_common::migration_types::{Migration, MigrationInfo, MigrationOrigin, Migrations, ParticipationType};
+use polkadot_parachain_primitives::primitives::Sibling;
+use polkadot_runtime_parachains::origin::Origin as ParachainOrigin;
+use sp_runtime::traits::AccountIdConversion;
+use sp_std::prelude::*;
+
+const CT_AMOUNT: u128 = 1_000_000_000_000_000;
+const VESTING_TIME: u64 = 100;
+
+#[benchmarks(where T::AccountId: From<[u8; 32]>)]
+mod benchmarks {
+	use super::*;
+
+	// Every migration goes to a different user, so each one creates a new account. With a cliff, each one also
+	// creates two vesting schedules. Both `execute_migrations` and `execute_scheduled_migrations` use this weight.
+	#[benchmark]
+	fn execute_migrations(x: Linear<1, 1000>) {
+		let polimec_sovereign_account: T::AccountId = Sibling(T::PolimecParaId::get()).into_account_truncating();
+		T::Balances::make_free_balance_be(&polimec_sovereign_account, (CT_AMOUNT * (x as u128 + 1)).into());
+
+		let migrations = (0..x)
+			.map(|i| {
+				let mut user = [0u8; 32];
+				user[..4].copy_from_slice(&i.to_le_bytes());
+				Migration::new(
+					MigrationOrigin { user, id: i, participation_type: ParticipationType::Contribution },
+					MigrationInfo {
+						contribution_token_amount: CT_AMOUNT,
+						vesting_time: VESTING_TIME,
+						start_block: None,
+						cliff: Some(VESTING_TIME / 2),
+					},
+				)
+			})
+			.collect::<Migrations>();
+		let origin: OriginFor<T> =
+			<T as Config>::RuntimeOrigin::from(ParachainOrigin::Parachain(T::PolimecParaId::get())).into();
+
+		#[extrinsic_call]
+		execute_scheduled_migrations(origin as OriginFor<T>, migrations.clone());
+
+		for migration in migrations.inner() {
+			let MigrationOrigin { user, id, participation_type } = migration.origin;
+			assert!(ExecutedMigrations::<T>::get((user, participation_type, id)));
+		}
+	}
+
+	#[benchmark]
+	fn check_token_metadata() {
+		let origin: OriginFor<T> =
+			<T as Config>::RuntimeOrigin::from(ParachainOrigin::Parachain(T::PolimecParaId::get())).into();
+
+		#[extrinsic_call]
+		check_token_metadata(origin as OriginFor<T>, T::TokenDecimals::get(), T::TokenSymbol::get());
+	}
+}
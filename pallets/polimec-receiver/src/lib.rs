@@ -15,22 +15,60 @@
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 #![cfg_attr(not(feature = "std"), no_std)]
+//! # Breaking changes
+//!
+//! ## 0.2.0
+//! Polimec sends migrations with a vesting start and cliff to the new `execute_scheduled_migrations` call, and
+//! checks the receiver version and token metadata before migrating. Runtimes must configure these new `Config`
+//! items, for which [`config_preludes`] has defaults:
+//! * `PolimecReportCallIndices`: [`config_preludes::PolimecReportCallIndices`].
+//! * `TokenDecimals` and `TokenSymbol`: the decimals and symbol of the migrated token, matching the CT metadata of
+//!   the project on Polimec.
+//! * `WeightInfo`: [`weights::SubstrateWeight`], or `()` for tests.
+//! * `XcmSender`: the runtime's `XcmRouter`. If the report of failed migrations cannot be sent, the whole call
+//!   fails, so Polimec retries every migration of the batch.
+//!
+//! A migration with a cliff adds two vesting schedules, so the `MaxVestingSchedules` of the runtime's vesting pallet
+//! must leave room for both.
+
 /// Edit this file to define custom logic or remove it if it is not needed.
 /// Learn more about FRAME and the core library of Substrate FRAME pallets:
 /// <https://docs.substrate.io/v3/runtime/frame>
 pub use pallet::*;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+
 #[frame_support::pallet]
 pub mod pallet {
+	use crate::weights::WeightInfo;
 	use frame_support::{
 		pallet_prelude::*,
+		storage::with_storage_layer,
 		traits::{tokens::Balance, Currency, ExistenceRequirement::KeepAlive, VestingSchedule},
 	};
 	use frame_system::pallet_prelude::*;
-	use polimec_common::migration_types::{Migration, MigrationInfo, MigrationOrigin, Migrations, ParticipationType};
+	use polimec_common::migration_types::{
+		LegacyMigration, Migration, MigrationInfo, MigrationOrigin, Migrations, ParticipationType,
+	};
 	use polkadot_parachain_primitives::primitives::{Id as ParaId, Sibling};
 	use polkadot_runtime_parachains::origin::{ensure_parachain, Origin as ParachainOrigin};
-	use sp_runtime::traits::{AccountIdConversion, Convert};
-	use sp_std::prelude::*;
+	use sp_runtime::{
+		traits::{AccountIdConversion, CheckedAdd, Convert, One, Zero},
+		Saturating,
+	};
+	use sp_std::{prelude::*, vec};
+	use xcm::v3::{
+		send_xcm,
+		Instruction::{Transact, UnpaidExecution},
+		Junction::Parachain,
+		Junctions::X1,
+		MultiLocation, OriginKind, SendXcm, WeightLimit, Xcm,
+	};
+
+	/// Weight allowed on Polimec for processing a report of failed migrations.
+	const REPORT_WEIGHT: Weight = Weight::from_parts(1_000_000_000, 100_000);
 
 	type MomentOf<T> = <<T as Config>::Vesting as VestingSchedule<<T as frame_system::Config>::AccountId>>::Moment;
 
@@ -43,13 +81,24 @@ pub mod pallet {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		type PolimecParaId: Get<ParaId>;
 		type RuntimeOrigin: IsType<<Self as frame_system::Config>::RuntimeOrigin>
-			+ Into<Result<ParachainOrigin, <Self as Config>::RuntimeOrigin>>;
-		type Vesting: VestingSchedule<Self::AccountId, Currency = Self::Balances>;
+			+ Into<Result<ParachainOrigin, <Self as Config>::RuntimeOrigin>>
+			+ From<ParachainOrigin>;
+		type Vesting: VestingSchedule<Self::AccountId, Currency = Self::Balances, Moment = BlockNumberFor<Self>>;
 		type Balances: Currency<Self::AccountId, Balance = Self::Balance>;
 		type Balance: Balance + From<u128> + MaybeSerializeDeserialize;
 		type GenesisMoment: Get<MomentOf<Self>>;
 		type MigrationInfoToPerBlockBalance: Convert<MigrationInfo, Self::Balance>;
 		type MaxMigrations: Get<u128>;
+		/// Pallet and call indices of `report_failed_migrations` in Polimec's funding pallet.
+		type PolimecReportCallIndices: Get<(u8, u8)>;
+		/// Decimals of the migrated token on this chain. Polimec checks they match the project's CT metadata.
+		type TokenDecimals: Get<u8>;
+		/// Symbol of the migrated token on this chain. Polimec checks it matches the project's CT metadata.
+		type TokenSymbol: Get<Vec<u8>>;
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+		/// Sends the report of failed migrations back to Polimec.
+		type XcmSender: SendXcm;
 	}
 
 	#[pallet::pallet]
@@ -69,6 +118,25 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// CT amount of each migration that could not be executed yet, e.g. because Polimec's sovereign account lacked
+	/// the funds. Polimec sends them again, and they are removed once executed.
+	#[pallet::storage]
+	pub type PendingMigrations<T> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, [u8; 32]>,
+			NMapKey<Blake2_128Concat, ParticipationType>,
+			NMapKey<Blake2_128Concat, u32>,
+		),
+		u128,
+		OptionQuery,
+	>;
+
+	/// Sum of all [`PendingMigrations`]. The project must pre-fund Polimec's sovereign account with at least this
+	/// much CT for them to succeed.
+	#[pallet::storage]
+	pub type PendingMigrationAmount<T> = StorageValue<_, u128, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config>
@@ -79,12 +147,20 @@ pub mod pallet {
 		MigrationExecuted { migration: Migration },
 		/// A Migration was found which wa already executed, and was skipped.
 		DuplicatedMigrationSkipped { migration: Migration },
+		/// A Migration could not be executed. It is kept as pending and reported to Polimec.
+		MigrationFailed { migration: Migration, error: DispatchError },
 	}
 
 	#[pallet::error]
 	pub enum Error<T> {
 		NoneValue,
 		StorageOverflow,
+		/// The vesting start or cliff of a migration does not fit in a block number of this chain.
+		InvalidVestingStart,
+		/// The report of failed migrations could not be sent to Polimec.
+		XcmFailed,
+		/// The decimals or symbol Polimec expects do not match the ones of the token on this chain.
+		TokenMetadataMismatch,
 	}
 
 	#[pallet::hooks]
@@ -95,42 +171,156 @@ pub mod pallet {
 	where
 		T::AccountId: From<[u8; 32]>,
 	{
+		/// Execute migrations sent by Polimec in the original format, without vesting start nor cliff.
 		#[pallet::call_index(0)]
-		#[pallet::weight(Weight::from_parts(10_000, 0))]
-		pub fn execute_migrations(origin: OriginFor<T>, migrations: Migrations) -> DispatchResult {
+		#[pallet::weight(T::WeightInfo::execute_migrations(migrations.len() as u32))]
+		pub fn execute_migrations(origin: OriginFor<T>, migrations: Vec<LegacyMigration>) -> DispatchResult {
+			let migrations = migrations.into_iter().map(Migration::from).collect::<Migrations>();
+			Self::do_execute_migrations(origin, migrations)
+		}
+
+		/// Part of Polimec's migration readiness check. Fails if the token of this chain does not have the given
+		/// decimals and symbol, and Polimec gets the result through `ReportTransactStatus`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::check_token_metadata())]
+		pub fn check_token_metadata(origin: OriginFor<T>, decimals: u8, symbol: Vec<u8>) -> DispatchResult {
+			let para_id: ParaId = ensure_parachain(<T as Config>::RuntimeOrigin::from(origin))?;
+			ensure!(para_id == T::PolimecParaId::get(), "Only Polimec Parachain can check the token metadata");
+			ensure!(
+				decimals == T::TokenDecimals::get() && symbol == T::TokenSymbol::get(),
+				Error::<T>::TokenMetadataMismatch
+			);
+
+			Ok(())
+		}
+
+		/// Execute migrations sent by Polimec, vesting from the start block of each one and released after its cliff.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::execute_migrations(migrations.len() as u32))]
+		pub fn execute_scheduled_migrations(origin: OriginFor<T>, migrations: Migrations) -> DispatchResult {
+			Self::do_execute_migrations(origin, migrations)
+		}
+	}
+
+	impl<T: Config> Pallet<T>
+	where
+		T::AccountId: From<[u8; 32]>,
+	{
+		fn do_execute_migrations(origin: OriginFor<T>, migrations: Migrations) -> DispatchResult {
 			let para_id: ParaId = ensure_parachain(<T as Config>::RuntimeOrigin::from(origin))?;
 			let polimec_id = T::PolimecParaId::get();
 			let polimec_soverign_account = Sibling(polimec_id).into_account_truncating();
 
 			ensure!(para_id == T::PolimecParaId::get(), "Only Polimec Parachain can call migrations");
-			for migration @ Migration {
-				origin: MigrationOrigin { user, id, participation_type },
-				info: MigrationInfo { contribution_token_amount, .. },
-				..
-			} in migrations.clone().inner()
+			let mut failed_migrations = Vec::new();
+			for migration @ Migration { origin: MigrationOrigin { user, id, participation_type }, .. } in
+				migrations.clone().inner()
 			{
 				let already_executed = ExecutedMigrations::<T>::get((user, participation_type, id));
 				if already_executed {
 					Self::deposit_event(Event::DuplicatedMigrationSkipped { migration });
 					continue;
 				}
-				T::Balances::transfer(
-					&polimec_soverign_account,
-					&user.into(),
-					contribution_token_amount.into(),
-					KeepAlive,
-				)?;
-				T::Vesting::add_vesting_schedule(
-					&user.into(),
-					contribution_token_amount.into(),
-					T::MigrationInfoToPerBlockBalance::convert(migration.info.clone()),
-					T::GenesisMoment::get(),
-				)?;
-				ExecutedMigrations::<T>::insert((user, participation_type, id), true);
-				Self::deposit_event(Event::MigrationExecuted { migration });
+
+				// Each migration is executed on its own, so a failing one does not revert the others
+				let result =
+					with_storage_layer(|| Self::execute_migration(&polimec_soverign_account, &migration));
+				let key = (user, participation_type, id);
+				match result {
+					Ok(()) => {
+						if let Some(amount) = PendingMigrations::<T>::take(key) {
+							PendingMigrationAmount::<T>::mutate(|total| *total = total.saturating_sub(amount));
+						}
+						Self::deposit_event(Event::MigrationExecuted { migration });
+					},
+					Err(error) => {
+						if !PendingMigrations::<T>::contains_key(key) {
+							let amount = migration.info.contribution_token_amount;
+							PendingMigrations::<T>::insert(key, amount);
+							PendingMigrationAmount::<T>::mutate(|total| *total = total.saturating_add(amount));
+						}
+						failed_migrations.push(migration.origin.clone());
+						Self::deposit_event(Event::MigrationFailed { migration, error });
+					},
+				}
 			}
 
+			// Polimec would otherwise consider the failed migrations executed, and burn their CTs. Failing the call
+			// reverts the whole batch instead, and Polimec retries it.
+			if !failed_migrations.is_empty() {
+				Self::report_failed_migrations(failed_migrations.clone()).map_err(|error| {
+					log::error!(
+						target: "polimec_receiver",
+						"Failed migrations could not be reported: {:?}",
+						failed_migrations
+					);
+					error
+				})?;
+			}
+
+			Ok(())
+		}
+
+		fn execute_migration(polimec_soverign_account: &T::AccountId, migration: &Migration) -> DispatchResult {
+			let MigrationOrigin { user, id, participation_type } = migration.origin;
+			let contribution_token_amount = migration.info.contribution_token_amount;
+
+			T::Balances::transfer(polimec_soverign_account, &user.into(), contribution_token_amount.into(), KeepAlive)?;
+			Self::add_vesting_schedules(&user.into(), &migration.info)?;
+			ExecutedMigrations::<T>::insert((user, participation_type, id), true);
+
 			Ok(())
 		}
+
+		/// Tell Polimec which migrations failed, so it only marks those participants as `Failed`. The report is sent
+		/// before Polimec gets the status of the whole `Transact`, through the same channel.
+		fn report_failed_migrations(failed_migrations: Vec<MigrationOrigin>) -> DispatchResult {
+			let (pallet_index, call_index) = T::PolimecReportCallIndices::get();
+			let mut encoded_call = vec![pallet_index, call_index];
+			encoded_call.extend_from_slice(failed_migrations.encode().as_slice());
+
+			let polimec_location = MultiLocation::new(1, X1(Parachain(T::PolimecParaId::get().into())));
+			let xcm = Xcm(vec![
+				UnpaidExecution { weight_limit: WeightLimit::Unlimited, check_origin: None },
+				Transact {
+					origin_kind: OriginKind::Xcm,
+					require_weight_at_most: REPORT_WEIGHT,
+					call: encoded_call.into(),
+				},
+			]);
+			send_xcm::<T::XcmSender>(polimec_location, xcm).map_err(|_| Error::<T>::XcmFailed)?;
+
+			Ok(())
+		}
+
+		/// Lock the migrated tokens of `who`, vesting linearly from the start block. Nothing is released during the
+		/// cliff: the part accrued by its end is locked by a separate schedule that releases it all at once, and the
+		/// rest keeps vesting linearly.
+		fn add_vesting_schedules(who: &T::AccountId, info: &MigrationInfo) -> DispatchResult {
+			let start: MomentOf<T> = match info.start_block {
+				Some(start_block) => start_block.try_into().map_err(|_| Error::<T>::InvalidVestingStart)?,
+				None => T::GenesisMoment::get(),
+			};
+			let cliff = info.cliff.unwrap_or_default();
+			let cliff_blocks: MomentOf<T> = cliff.try_into().map_err(|_| Error::<T>::InvalidVestingStart)?;
+			let cliff_end = start.checked_add(&cliff_blocks).ok_or(Error::<T>::InvalidVestingStart)?;
+
+			let total: T::Balance = info.contribution_token_amount.into();
+			let per_block = T::MigrationInfoToPerBlockBalance::convert(info.clone());
+			let accrued = per_block.saturating_mul((cliff as u128).into()).min(total);
+			if !accrued.is_zero() {
+				// Fully unlocked in the block the cliff ends.
+				T::Vesting::add_vesting_schedule(who, accrued, accrued, cliff_end.saturating_sub(One::one()))?;
+			}
+			T::Vesting::add_vesting_schedule(who, total.saturating_sub(accrued), per_block, cliff_end)
+		}
+	}
+}
+
+/// Defaults for the `Config` items that do not depend on the project's chain.
+pub mod config_preludes {
+	frame_support::parameter_types! {
+		/// `report_failed_migrations` of the `Funding` pallet on Polimec.
+		pub const PolimecReportCallIndices: (u8, u8) = (80, 43);
 	}
 }
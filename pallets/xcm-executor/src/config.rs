@@ -114,6 +114,7 @@ pub trait Config {
 	/// temporary measure until we properly account for proof size weights for XCM instructions.
 	type SafeCallFilter: Contains<Self::RuntimeCall>;
 
-	/// Polimec's custom type for handling the `HrmpNewChannelOpenRequest` and `HrmpChannelAccepted` instructions
+	/// Polimec's custom type for handling the `HrmpNewChannelOpenRequest`, `HrmpChannelAccepted` and
+	/// `HrmpChannelClosing` instructions
 	type HrmpHandler: HrmpHandler;
 }
@@ -1037,7 +1037,7 @@ impl<Config: config::Config> XcmExecutor<Config> {
 			},
 			msg @ HrmpNewChannelOpenRequest { .. } => Config::HrmpHandler::handle_channel_open_request(msg.into()),
 			msg @ HrmpChannelAccepted { .. } => Config::HrmpHandler::handle_channel_accepted(msg.into()),
-			HrmpChannelClosing { .. } => Err(XcmError::Unimplemented),
+			msg @ HrmpChannelClosing { .. } => Config::HrmpHandler::handle_channel_closing(msg.into()),
 		}
 	}
 }
@@ -24,6 +24,7 @@ pub use xcm_executor::traits::*;
 pub trait HrmpHandler {
 	fn handle_channel_open_request(message: Instruction) -> XcmResult;
 	fn handle_channel_accepted(message: Instruction) -> XcmResult;
+	fn handle_channel_closing(message: Instruction) -> XcmResult;
 }
 
 impl HrmpHandler for () {
@@ -34,4 +35,8 @@ impl HrmpHandler for () {
 	fn handle_channel_accepted(_message: Instruction) -> XcmResult {
 		Err(XcmError::NoDeal)
 	}
+
+	fn handle_channel_closing(_message: Instruction) -> XcmResult {
+		Err(XcmError::NoDeal)
+	}
 }
@@ -134,10 +134,32 @@ pub mod migration_types {
 	pub struct MigrationInfo {
 		pub contribution_token_amount: u128,
 		pub vesting_time: u64,
+		/// Block of the destination chain from which the tokens start vesting. The receiver's genesis moment if `None`.
+		pub start_block: Option<u64>,
+		/// Blocks after the start during which no tokens are released. What vested by then is released when it ends.
+		pub cliff: Option<u64>,
 	}
 	impl From<(u128, u64)> for MigrationInfo {
 		fn from((contribution_token_amount, vesting_time): (u128, u64)) -> Self {
-			Self { contribution_token_amount, vesting_time }
+			Self { contribution_token_amount, vesting_time, start_block: None, cliff: None }
+		}
+	}
+
+	/// [`MigrationInfo`] as encoded before `start_block` and `cliff` existed. Receivers keep accepting it on the
+	/// original `execute_migrations` call, so the payload of a call index never changes.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct LegacyMigrationInfo {
+		pub contribution_token_amount: u128,
+		pub vesting_time: u64,
+	}
+	impl From<LegacyMigrationInfo> for MigrationInfo {
+		fn from(info: LegacyMigrationInfo) -> Self {
+			(info.contribution_token_amount, info.vesting_time).into()
+		}
+	}
+	impl From<MigrationInfo> for LegacyMigrationInfo {
+		fn from(info: MigrationInfo) -> Self {
+			Self { contribution_token_amount: info.contribution_token_amount, vesting_time: info.vesting_time }
 		}
 	}
 
@@ -161,6 +183,23 @@ pub mod migration_types {
 		}
 	}
 
+	/// [`Migration`] with a [`LegacyMigrationInfo`].
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct LegacyMigration {
+		pub origin: MigrationOrigin,
+		pub info: LegacyMigrationInfo,
+	}
+	impl From<LegacyMigration> for Migration {
+		fn from(migration: LegacyMigration) -> Self {
+			Self::new(migration.origin, migration.info.into())
+		}
+	}
+	impl From<Migration> for LegacyMigration {
+		fn from(migration: Migration) -> Self {
+			Self { origin: migration.origin, info: migration.info.into() }
+		}
+	}
+
 	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 	pub struct Migrations(Vec<Migration>);
 	impl FromIterator<Migration> for Migrations {
@@ -53,7 +53,7 @@ use sp_runtime::{
 		AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT, Convert, ConvertBack, ConvertInto,
 		IdentifyAccount, IdentityLookup, OpaqueKeys, Verify,
 	},
-	transaction_validity::{TransactionSource, TransactionValidity},
+	transaction_validity::{TransactionPriority, TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, MultiSignature, SaturatedConversion,
 };
 use sp_std::{cmp::Ordering, prelude::*};
@@ -71,7 +71,7 @@ pub use shared_configuration::{
 	assets::*, currency::*, fee::*, funding::*, governance::*, identity::*, proxy::*, staking::*, time::*, weights::*,
 };
 pub use sp_consensus_aura::sr25519::AuthorityId as AuraId;
-pub use sp_runtime::{MultiAddress, Perbill, Permill};
+pub use sp_runtime::{MultiAddress, Perbill, Percent, Permill};
 
 // Make the WASM binary available.
 #[cfg(feature = "std")]
@@ -159,8 +159,12 @@ pub type Migrations = migrations::Unreleased;
 pub mod migrations {
 	use crate::{custom_migrations::init_pallet::InitializePallet, DmpQueue};
 	/// Unreleased migrations. Add new ones here:
-	#[allow(unused_parens)]
-	pub type Unreleased = (InitializePallet<DmpQueue>);
+	pub type Unreleased = (
+		InitializePallet<DmpQueue>,
+		pallet_funding::storage_migrations::v3::MigrationToV3<crate::Runtime>,
+		pallet_funding::storage_migrations::v4::MigrationToV4<crate::Runtime>,
+		pallet_funding::storage_migrations::v5::MigrationToV5<crate::Runtime>,
+	);
 }
 
 /// Executive: handles dispatch to the various modules.
@@ -267,7 +271,8 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 				RuntimeCall::Preimage(..) |
 				RuntimeCall::Scheduler(..) |
 				RuntimeCall::Oracle(..) |
-				RuntimeCall::OracleProvidersMembership(..)
+				RuntimeCall::OracleProvidersMembership(..) |
+				RuntimeCall::OracleOffchainWorker(..)
 			),
 			ProxyType::Governance => matches!(
 				c,
@@ -767,6 +772,7 @@ impl pallet_parachain_staking::Config for Runtime {
 	type MonetaryGovernanceOrigin = frame_system::EnsureRoot<AccountId>;
 	type OnCollatorPayout = ();
 	type OnNewRound = ();
+	type OnStakeInUseFreed = HoldFreedStakeAsParticipationBond<Runtime>;
 	type PayMaster = PayMaster;
 	// We use the default implementation, so we leave () here.
 	type PayoutCollatorReward = ();
@@ -794,17 +800,28 @@ impl pallet_membership::Config<pallet_membership::Instance1> for Runtime {
 parameter_types! {
 	pub const MinimumCount: u32 = 3;
 	pub const ExpiresIn: Moment = 1000 * 60; // 1 mins
+	// Prices older than this are stale, and may move any amount on the next update
+	pub const MaxPriceAge: Moment = 1000 * 60 * 30; // 30 mins
+	pub const MaxPriceDeviation: Percent = Percent::from_percent(10);
+	pub const TwapWindow: Moment = 1000 * 60 * 60; // 1 hour
+	pub const MaxPriceHistory: u32 = 4320; // About a month of snapshots, one per `FetchInterval` of 10 minutes
 	pub const MaxHasDispatchedSize: u32 = 20;
 	pub RootOperatorAccountId: AccountId = AccountId::from([0xffu8; 32]);
 	pub const MaxFeedValues: u32 = 4; // max 4 values allowd to feed in one call (USDT, USDC, DOT, PLMC).
 }
 
 impl orml_oracle::Config for Runtime {
-	type CombineData = orml_oracle::DefaultCombineData<Runtime, MinimumCount, ExpiresIn, ()>;
+	type CombineData = DeviationBoundedCombineData<
+		orml_oracle::DefaultCombineData<Runtime, MinimumCount, ExpiresIn, ()>,
+		MaxPriceDeviation,
+		MaxPriceAge,
+		Timestamp,
+	>;
 	type MaxFeedValues = MaxFeedValues;
 	type MaxHasDispatchedSize = MaxHasDispatchedSize;
-	type Members = OracleProvidersMembership;
-	type OnNewData = ();
+	// Suspended feeders are left out
+	type Members = pallet_oracle_ocw::ActiveMembers<Runtime>;
+	type OnNewData = OracleOffchainWorker;
 	type OracleKey = AssetId;
 	type OracleValue = Price;
 	type RootOperatorAccountId = RootOperatorAccountId;
@@ -817,15 +834,22 @@ impl orml_oracle::Config for Runtime {
 parameter_types! {
 	pub const FetchInterval: u32 = 50;
 	pub const FetchWindow: u32 = 5;
+	pub const OracleUnsignedPriority: TransactionPriority = TransactionPriority::MAX / 2;
 }
 
 impl pallet_oracle_ocw::Config for Runtime {
+	type AdminOrigin = EnsureRoot<AccountId>;
 	type AppCrypto = pallet_oracle_ocw::crypto::PolimecCrypto;
 	type ConvertAssetPricePair = AssetPriceConverter;
 	type FetchInterval = FetchInterval;
 	type FetchWindow = FetchWindow;
+	type MaxPriceHistory = MaxPriceHistory;
 	type Members = OracleProvidersMembership;
+	// The Asset Hub pools are only read on politest until their PLMC liquidity is worth weighing in
+	type OnChainPriceSource = ();
 	type RuntimeEvent = RuntimeEvent;
+	type TwapWindow = TwapWindow;
+	type UnsignedPriority = OracleUnsignedPriority;
 }
 
 impl frame_system::offchain::SigningTypes for Runtime {
@@ -981,8 +1005,11 @@ impl pallet_assets::Config<ContributionTokensInstance> for Runtime {
 parameter_types! {
 	pub ContributionTreasuryAccount: AccountId = FundingPalletId::get().into_account_truncating();
 	pub PolimecReceiverInfo: xcm::v3::PalletInfo = xcm::v3::PalletInfo::new(
-		51, "PolimecReceiver".into(), "polimec_receiver".into(), 0, 1, 0
+		51, "PolimecReceiver".into(), "polimec_receiver".into(), 0, 2, 0
 	).unwrap();
+	pub RelayHrmpCallIndices: pallet_funding::types::HrmpCallIndices = pallet_funding::types::HrmpCallIndices {
+		pallet: 60, init_open_channel: 0, accept_open_channel: 1
+	};
 	pub MaxMessageSizeThresholds: (u32, u32) = (50000, 102_400);
 	pub MaxCapacityThresholds: (u32, u32) = (8, 1000);
 	pub RequiredMaxCapacity: u32 = 1000;
@@ -1032,6 +1059,7 @@ impl pallet_funding::Config for Runtime {
 	type Balance = Balance;
 	type BlockNumber = BlockNumber;
 	type BlockNumberToBalance = ConvertInto;
+	type BondingPriceProvider = TwapPriceProvider<Runtime>;
 	type CommunityFundingDuration = CommunityFundingDuration;
 	type ContributionTokenCurrency = ContributionTokens;
 	type ContributionTreasury = ContributionTreasuryAccount;
@@ -1041,6 +1069,7 @@ impl pallet_funding::Config for Runtime {
 	type EvaluatorSlash = EvaluatorSlash;
 	type FeeBrackets = FeeBrackets;
 	type FundingCurrency = ForeignAssets;
+	type HrmpChannelRequestTimeout = HrmpChannelRequestTimeout;
 	type InvestorOrigin = EnsureInvestor<Runtime>;
 	type ManualAcceptanceDuration = ManualAcceptanceDuration;
 	type MaxBidsPerProject = ConstU32<512>;
@@ -1050,8 +1079,13 @@ impl pallet_funding::Config for Runtime {
 	type MaxEvaluationsPerProject = ConstU32<512>;
 	type MaxEvaluationsPerUser = ConstU32<16>;
 	type MaxMessageSizeThresholds = MaxMessageSizeThresholds;
+	type MaxMigrationRetries = ConstU32<3>;
+	type MaxPhaseDurations = MaxPhaseDurations;
 	type MaxProjectsToUpdateInsertionAttempts = ConstU32<100>;
 	type MaxProjectsToUpdatePerBlock = ConstU32<1>;
+	type MigrationQueryTimeout = MigrationQueryTimeout;
+	type MigrationRetryBackoff = MigrationRetryBackoff;
+	type MinPhaseDurations = MinPhaseDurations;
 	type MinUsdPerEvaluation = MinUsdPerEvaluation;
 	type Multiplier = pallet_funding::types::Multiplier;
 	type NativeCurrency = Balances;
@@ -1059,9 +1093,12 @@ impl pallet_funding::Config for Runtime {
 	type PolimecReceiverInfo = PolimecReceiverInfo;
 	type PreImageLimit = ConstU32<1024>;
 	type Price = Price;
-	type PriceProvider = OraclePriceProvider<AssetId, Price, Oracle>;
+	type PriceCircuitBreakerCooldown = PriceCircuitBreakerCooldown;
+	type PriceCircuitBreakerDeviation = PriceCircuitBreakerDeviation;
+	type PriceProvider = OraclePriceProvider<AssetId, Price, Oracle, Timestamp, MaxPriceAge>;
 	type ProtocolGrowthTreasury = TreasuryAccount;
 	type Randomness = Random;
+	type RelayHrmpCallIndices = RelayHrmpCallIndices;
 	type RemainderFundingDuration = RemainderFundingDuration;
 	type RequiredMaxCapacity = RequiredMaxCapacity;
 	type RequiredMaxMessageSize = RequiredMaxMessageSize;
@@ -1071,6 +1108,7 @@ impl pallet_funding::Config for Runtime {
 	type RuntimeOrigin = RuntimeOrigin;
 	#[cfg(any(feature = "runtime-benchmarks", feature = "std"))]
 	type SetPrices = crate::benchmarks::helpers::SetOraclePrices;
+	type StakedPlmc = StakedPlmcFromDelegations<Runtime>;
 	type StringLimit = ConstU32<64>;
 	type SuccessToSettlementTime = SuccessToSettlementTime;
 	type VerifierPublicKey = VerifierPublicKey;
@@ -1115,10 +1153,12 @@ ord_parameter_types! {
 impl pallet_dispenser::Config for Runtime {
 	type AdminOrigin = EnsureSignedBy<DispenserAdminAccount, AccountId>;
 	type BlockNumberToBalance = ConvertInto;
+	type BlocksPerDay = DispenserBlocksPerDay;
 	type FreeDispenseAmount = FreeDispenseAmount;
 	type InitialDispenseAmount = InitialDispenseAmount;
 	type InvestorOrigin = EnsureInvestor<Runtime>;
 	type LockPeriod = DispenserLockPeriod;
+	type MaxCampaignCleanup = DispenserMaxCampaignCleanup;
 	type PalletId = DispenserId;
 	type RuntimeEvent = RuntimeEvent;
 	type VerifierPublicKey = VerifierPublicKey;
@@ -1178,7 +1218,7 @@ construct_runtime!(
 		// Oracle
 		Oracle: orml_oracle::{Pallet, Call, Storage, Event<T>} = 70,
 		OracleProvidersMembership: pallet_membership::<Instance1> = 71,
-		OracleOffchainWorker: pallet_oracle_ocw::{Pallet, Event<T>} = 72,
+		OracleOffchainWorker: pallet_oracle_ocw::{Pallet, Call, Storage, Event<T>, ValidateUnsigned} = 72,
 
 		Funding: pallet_funding = 80,
 		LinearRelease: pallet_linear_release = 81,
@@ -1318,6 +1358,21 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_oracle_ocw::runtime_api::OraclePriceHistoryApi<Block, AssetId, Price, BlockNumber> for Runtime {
+		fn price_history(key: AssetId, from: BlockNumber, to: BlockNumber) -> Vec<(BlockNumber, Price)> {
+			OracleOffchainWorker::price_history(&key, from, to)
+		}
+	}
+
+	impl pallet_funding::runtime_api::OffchainMigrationApi<Block, AccountId> for Runtime {
+		fn migration_merkle_proofs(
+			project_id: pallet_funding::ProjectId,
+			participant: AccountId,
+		) -> Vec<(polimec_common::migration_types::Migration, Vec<sp_core::H256>)> {
+			Funding::offchain_migration_proofs(project_id, participant)
+		}
+	}
+
 	impl sp_session::SessionKeys<Block> for Runtime {
 		fn generate_session_keys(seed: Option<Vec<u8>>) -> Vec<u8> {
 			SessionKeys::generate(seed)
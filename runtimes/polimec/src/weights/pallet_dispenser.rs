@@ -55,6 +55,15 @@ impl<T: frame_system::Config> pallet_dispenser::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(7))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
+	/// Placeholder until the weights are regenerated with the benchmark CLI. Reads the timestamp to check the
+	/// credential, the mode, the DID's last refill, the block and day refills and both accounts, and writes all but
+	/// the timestamp and the mode.
+	fn dispense_faucet() -> Weight {
+		Weight::from_parts(100_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
 	/// Storage: `Dispenser::DispenseAmount` (r:0 w:1)
 	/// Proof: `Dispenser::DispenseAmount` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	fn set_dispense_amount() -> Weight {
@@ -66,4 +75,56 @@ impl<T: frame_system::Config> pallet_dispenser::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Placeholder until the weights are regenerated with the benchmark CLI. Writes the mode.
+	fn set_dispense_mode() -> Weight {
+		Weight::from_parts(4_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Placeholder until the weights are regenerated with the benchmark CLI. Reads and bumps the next campaign id,
+	/// and writes the campaign.
+	fn create_campaign() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Placeholder until the weights are regenerated with the benchmark CLI. Reads the campaign, the time and the
+	/// receipt, transfers from the campaign account and adds a vesting schedule.
+	fn dispense_campaign() -> Weight {
+		Weight::from_parts(200_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Placeholder until the weights are regenerated with the benchmark CLI. Removes the campaign, returns its funds
+	/// and removes `x` receipts.
+	/// The range of component `x` is `[0, 500]`.
+	fn end_campaign(x: u32, ) -> Weight {
+		Weight::from_parts(70_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2569).saturating_mul(x.into()))
+	}
+	/// Placeholder until the weights are regenerated with the benchmark CLI. Reads the campaign and transfers from
+	/// the dispenser to the campaign account.
+	fn fund_campaign() -> Weight {
+		Weight::from_parts(70_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Placeholder until the weights are regenerated with the benchmark CLI. Reads the next campaign id and the
+	/// campaign, and removes `x` receipts.
+	/// The range of component `x` is `[1, 500]`.
+	fn clean_up_campaign(x: u32, ) -> Weight {
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3559))
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 2569).saturating_mul(x.into()))
+	}
 }
@@ -761,4 +761,93 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:0)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(397), added: 2872, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::MigrationReadinessReports` (r:1 w:0)
+	/// Proof: `Funding::MigrationReadinessReports` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::PendingMigrations` (r:100 w:100)
+	/// Proof: `Funding::PendingMigrations` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::UserMigrations` (r:100 w:100)
+	/// Proof: `Funding::UserMigrations` (`max_values`: None, `max_size`: Some(3658), added: 6133, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::MigrationExecutionFees` (r:1 w:0)
+	/// Proof: `Funding::MigrationExecutionFees` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `PolkadotXcm::QueryCounter` (r:1 w:1)
+	/// Proof: `PolkadotXcm::QueryCounter` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `XcmpQueue::OutboundXcmpStatus` (r:1 w:1)
+	/// Proof: `XcmpQueue::OutboundXcmpStatus` (`max_values`: Some(1), `max_size`: Some(1282), added: 1777, mode: `MaxEncodedLen`)
+	/// Storage: `XcmpQueue::OutboundXcmpMessages` (r:0 w:1)
+	/// Proof: `XcmpQueue::OutboundXcmpMessages` (`max_values`: None, `max_size`: Some(105506), added: 107981, mode: `MaxEncodedLen`)
+	/// Storage: `PolkadotXcm::Queries` (r:0 w:1)
+	/// Proof: `PolkadotXcm::Queries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::ActiveMigrationQueue` (r:0 w:1)
+	/// Proof: `Funding::ActiveMigrationQueue` (`max_values`: None, `max_size`: Some(3250), added: 5725, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 100]`.
+	fn migrate_participants_batch(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1842 + x * (1170 ±0)`
+		//  Estimated: `108971 + x * (6133 ±0)`
+		// Minimum execution time: 98_410_000 picoseconds.
+		Weight::from_parts(91_372_000, 108971)
+			// Standard Error: 11_482
+			.saturating_add(Weight::from_parts(14_518_436, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 6133).saturating_mul(x.into()))
+	}
+	/// Storage: `Funding::ProjectsDetails` (r:1 w:1)
+	/// Proof: `Funding::ProjectsDetails` (`max_values`: None, `max_size`: Some(397), added: 2872, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Evaluations` (r:1 w:0)
+	/// Proof: `Funding::Evaluations` (`max_values`: None, `max_size`: Some(254), added: 2729, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Bids` (r:1 w:0)
+	/// Proof: `Funding::Bids` (`max_values`: None, `max_size`: Some(306), added: 2781, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::Contributions` (r:1 w:0)
+	/// Proof: `Funding::Contributions` (`max_values`: None, `max_size`: Some(254), added: 2729, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::UserMigrations` (r:1001 w:0)
+	/// Proof: `Funding::UserMigrations` (`max_values`: None, `max_size`: Some(3658), added: 6133, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::MigrationMerkleRoots` (r:0 w:1)
+	/// Proof: `Funding::MigrationMerkleRoots` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 1000]`.
+	fn start_offchain_migration(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1204 + x * (1170 ±0)`
+		//  Estimated: `4697 + x * (6133 ±0)`
+		// Minimum execution time: 61_220_000 picoseconds.
+		Weight::from_parts(48_915_000, 4697)
+			// Standard Error: 7_904
+			.saturating_add(Weight::from_parts(11_284_530, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 6133).saturating_mul(x.into()))
+	}
+	/// Storage: `Funding::MigrationQueriesPerParachain` (r:1 w:0)
+	/// Proof: `Funding::MigrationQueriesPerParachain` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::FailedMigrationReports` (r:0 w:100)
+	/// Proof: `Funding::FailedMigrationReports` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 100]`.
+	fn report_failed_migrations(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `3489`
+		// Minimum execution time: 9_870_000 picoseconds.
+		Weight::from_parts(8_602_114, 3489)
+			// Standard Error: 1_207
+			.saturating_add(Weight::from_parts(1_493_210, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+	}
+	/// Placeholder until the weights are regenerated with the benchmark CLI. Reads and writes the query, its timeout,
+	/// the project and its chain's query count, and for each participant its failure report, migrations, CT asset
+	/// and account, migrated total and retries.
+	/// The range of component `x` is `[1, 100]`.
+	fn confirm_migrations(x: u32, ) -> Weight {
+		Weight::from_parts(50_000_000, 10_000)
+			.saturating_add(Weight::from_parts(60_000_000, 7_000).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((7_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((7_u64).saturating_mul(x.into())))
+	}
 }
\ No newline at end of file
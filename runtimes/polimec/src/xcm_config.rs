@@ -26,12 +26,14 @@ use frame_support::{
 	weights::Weight,
 };
 use pallet_xcm::XcmPassthrough;
+use parity_scale_codec::Encode;
 use polimec_xcm_executor::{
 	polimec_traits::{JustTry, Properties, ShouldExecute},
 	XcmExecutor,
 };
 use polkadot_parachain_primitives::primitives::Sibling;
 use polkadot_runtime_common::impls::ToAuthor;
+use shared_configuration::AllowFailedMigrationReportsFrom;
 use sp_runtime::traits::MaybeEquivalence;
 use xcm::latest::prelude::*;
 use xcm_builder::{
@@ -212,6 +214,12 @@ parameter_types! {
 	pub UnitWeightCost: Weight = Weight::from_parts(1_000_000_000, 64 * 1024);
 	pub const MaxInstructions: u32 = 100;
 	pub const MaxAssetsIntoHolding: u32 = 64;
+	// Pallet and call indices of `report_failed_migrations`, the first two bytes of the encoded call
+	pub FailedMigrationReportCall: (u8, u8) = {
+		let call = RuntimeCall::Funding(pallet_funding::Call::report_failed_migrations { failed: Vec::new() });
+		let encoded_call = call.encode();
+		(encoded_call[0], encoded_call[1])
+	};
 }
 
 match_types! {
@@ -243,6 +251,11 @@ pub type Barrier = DenyThenTry<
 				AllowTopLevelPaidExecutionFrom<Everything>,
 				// Common Good Assets parachain, parent and its exec plurality get free execution
 				AllowExplicitUnpaidExecutionFrom<(CommonGoodAssetsParachain, ParentOrParentsExecutivePlurality)>,
+				// Project chains report the migrations they could not execute for free while migrating
+				AllowFailedMigrationReportsFrom<
+					pallet_funding::MigratingParachains<Runtime>,
+					FailedMigrationReportCall,
+				>,
 				// Subscriptions for version tracking are OK.
 				AllowSubscriptionsFrom<ParentOrSiblings>,
 			),
@@ -19,11 +19,16 @@ use crate::{
 	Balance,
 };
 use core::marker::PhantomData;
-use frame_support::{parameter_types, PalletId};
-use orml_traits::DataProvider;
+use frame_support::{
+	parameter_types,
+	traits::{Get, Time},
+	PalletId,
+};
+use orml_traits::{CombineData, DataProvider, DataProviderExtended, TimestampedValue};
 use pallet_funding::traits::ProvideAssetPrice;
 use parachains_common::DAYS;
-use sp_arithmetic::FixedPointNumber;
+use sp_arithmetic::{traits::Saturating, FixedPointNumber, Percent};
+use sp_std::vec::Vec;
 
 parameter_types! {
 	pub const AssetDeposit: Balance = 10  * PLMC;
@@ -36,12 +41,18 @@ parameter_types! {
 	pub const ZeroDeposit: Balance = 0;
 }
 
-pub struct OraclePriceProvider<AssetId, Price, Oracle>(PhantomData<(AssetId, Price, Oracle)>);
+/// Prices last updated more than `MaxPriceAge` ago are reported as stale.
+pub struct OraclePriceProvider<AssetId, Price, Oracle, Now, MaxPriceAge>(
+	PhantomData<(AssetId, Price, Oracle, Now, MaxPriceAge)>,
+);
 
-impl<AssetId, Price, Oracle> ProvideAssetPrice for OraclePriceProvider<AssetId, Price, Oracle>
+impl<AssetId, Price, Oracle, Now, MaxPriceAge> ProvideAssetPrice
+	for OraclePriceProvider<AssetId, Price, Oracle, Now, MaxPriceAge>
 where
 	Price: FixedPointNumber,
-	Oracle: DataProvider<AssetId, Price>,
+	Oracle: DataProvider<AssetId, Price> + DataProviderExtended<AssetId, TimestampedValue<Price, Now::Moment>>,
+	Now: Time,
+	MaxPriceAge: Get<Now::Moment>,
 {
 	type AssetId = AssetId;
 	type Price = Price;
@@ -49,6 +60,65 @@ where
 	fn get_price(asset_id: AssetId) -> Option<Price> {
 		Oracle::get(&asset_id)
 	}
+
+	fn is_price_fresh(asset_id: AssetId) -> bool {
+		Oracle::get_no_op(&asset_id)
+			.map_or(false, |price| price.timestamp.saturating_add(MaxPriceAge::get()) >= Now::now())
+	}
+}
+
+/// Time-weighted average of the oracle prices over `TwapWindow`. Used where a short-lived price spike should not
+/// move the result much, like participation bonds and refunds.
+pub struct TwapPriceProvider<T>(PhantomData<T>);
+
+impl<T: pallet_oracle_ocw::Config> ProvideAssetPrice for TwapPriceProvider<T>
+where
+	T::OracleValue: FixedPointNumber,
+{
+	type AssetId = T::OracleKey;
+	type Price = T::OracleValue;
+
+	fn get_price(asset_id: T::OracleKey) -> Option<T::OracleValue> {
+		pallet_oracle_ocw::Pallet::<T>::twap(&asset_id)
+	}
+}
+
+/// Keeps the previous price if the one combined by `Inner` moved more than `MaxDeviation` from it, so a single
+/// glitching update cannot move the price used on chain. Once the previous price is older than `MaxPriceAge`, the
+/// new one is accepted no matter how far it moved.
+pub struct DeviationBoundedCombineData<Inner, MaxDeviation, MaxPriceAge, Now>(
+	PhantomData<(Inner, MaxDeviation, MaxPriceAge, Now)>,
+);
+
+impl<Key, Price, Inner, MaxDeviation, MaxPriceAge, Now> CombineData<Key, TimestampedValue<Price, Now::Moment>>
+	for DeviationBoundedCombineData<Inner, MaxDeviation, MaxPriceAge, Now>
+where
+	Price: FixedPointNumber,
+	Inner: CombineData<Key, TimestampedValue<Price, Now::Moment>>,
+	MaxDeviation: Get<Percent>,
+	MaxPriceAge: Get<Now::Moment>,
+	Now: Time,
+{
+	fn combine_data(
+		key: &Key,
+		values: Vec<TimestampedValue<Price, Now::Moment>>,
+		prev_value: Option<TimestampedValue<Price, Now::Moment>>,
+	) -> Option<TimestampedValue<Price, Now::Moment>> {
+		let combined = Inner::combine_data(key, values, prev_value.clone())?;
+		let prev = match prev_value {
+			Some(prev) if prev.timestamp.saturating_add(MaxPriceAge::get()) >= Now::now() => prev,
+			_ => return Some(combined),
+		};
+
+		let max_deviation = Price::saturating_from_rational(MaxDeviation::get().deconstruct(), 100u8);
+		let max_change = prev.value.saturating_mul(max_deviation);
+		let change = combined.value.max(prev.value).saturating_sub(combined.value.min(prev.value));
+		if change > max_change {
+			Some(prev)
+		} else {
+			Some(combined)
+		}
+	}
 }
 
 // Dispenser Related constants
@@ -58,5 +128,7 @@ parameter_types! {
 	pub const DispenserId: PalletId = PalletId(*b"plmc/fct");
 	pub const DispenserLockPeriod: u32 = DAYS * 365 * 2; // 2 years
 	pub const DispenserVestPeriod: u32 = DAYS * 365 * 2; // 2 years
+	pub const DispenserBlocksPerDay: u32 = DAYS;
+	pub const DispenserMaxCampaignCleanup: u32 = 500;
 
 }
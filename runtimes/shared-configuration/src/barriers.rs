@@ -0,0 +1,63 @@
+// Polimec Blockchain – https://www.polimec.org/
+// Copyright (C) Polimec 2022. All rights reserved.
+
+// The Polimec Blockchain is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Polimec Blockchain is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+use core::marker::PhantomData;
+use frame_support::{
+	ensure,
+	traits::{Contains, Get, ProcessMessageError},
+	weights::Weight,
+};
+use polimec_xcm_executor::polimec_traits::{Properties, ShouldExecute};
+use xcm::latest::prelude::*;
+use xcm_builder::{CreateMatcher, MatchXcm};
+
+/// Allows unpaid execution of a single `Transact` of the call in `ReportCall`, dispatched with the `Xcm` origin
+/// kind. Used by project chains to report the migrations they could not execute, without letting them run
+/// anything else for free.
+pub struct AllowFailedMigrationReportsFrom<T, ReportCall>(PhantomData<(T, ReportCall)>);
+impl<T: Contains<MultiLocation>, ReportCall: Get<(u8, u8)>> ShouldExecute
+	for AllowFailedMigrationReportsFrom<T, ReportCall>
+{
+	fn should_execute<Call>(
+		origin: &MultiLocation,
+		instructions: &mut [Instruction<Call>],
+		max_weight: Weight,
+		_weight_credit: &mut Properties,
+	) -> Result<(), ProcessMessageError> {
+		log::trace!(
+			target: "xcm::barriers",
+			"AllowFailedMigrationReportsFrom origin: {:?}, instructions: {:?}, max_weight: {:?}, weight_credit: {:?}",
+			origin, instructions, max_weight, _weight_credit,
+		);
+		ensure!(T::contains(origin), ProcessMessageError::Unsupported);
+		let (pallet_index, call_index) = ReportCall::get();
+		instructions
+			.matcher()
+			.assert_remaining_insts(2)?
+			.match_next_inst(|inst| match inst {
+				UnpaidExecution { weight_limit: Limited(limit), .. } if limit.all_gte(max_weight) => Ok(()),
+				UnpaidExecution { weight_limit: Unlimited, .. } => Ok(()),
+				_ => Err(ProcessMessageError::Overweight(max_weight)),
+			})?
+			.match_next_inst(|inst| match inst {
+				Transact { origin_kind: OriginKind::Xcm, call, .. }
+					if call.clone().into_encoded().starts_with(&[pallet_index, call_index]) =>
+					Ok(()),
+				_ => Err(ProcessMessageError::Unsupported),
+			})?;
+		Ok(())
+	}
+}
@@ -17,9 +17,12 @@
 use crate::Balance;
 use frame_support::parameter_types;
 use pallet_funding::AcceptedFundingAsset;
-use pallet_oracle_ocw::types::AssetName;
+use pallet_oracle_ocw::{traits::AssetHubPoolsConfig, types::AssetName};
 use parachains_common::AssetIdForTrustBackedAssets as AssetId;
+use parity_scale_codec::Encode;
 use sp_runtime::{traits::Convert, FixedU128};
+use sp_std::prelude::*;
+use xcm::v3::{Junction::*, Junctions::*, MultiLocation};
 
 /// One PLMC
 pub const PLMC: Balance = 10u128.pow(10);
@@ -91,6 +94,23 @@ impl Convert<(AssetName, FixedU128), (AssetId, Price)> for AssetPriceConverter {
 			AssetName::USDC => (AcceptedFundingAsset::USDC.to_assethub_id(), price),
 			AssetName::USDT => (AcceptedFundingAsset::USDT.to_assethub_id(), price),
 			AssetName::PLMC => (pallet_funding::PLMC_FOREIGN_ID, price),
+			AssetName::Other(asset_id) => (asset_id, price),
 		}
 	}
 }
+
+/// Asset-conversion pools of Polkadot Asset Hub the PLMC price is read from.
+pub struct AssetHubPolkadotPools;
+impl AssetHubPoolsConfig for AssetHubPolkadotPools {
+	const RPC_URL: &'static str = "https://polkadot-asset-hub-rpc.polkadot.io";
+
+	fn pool_asset(asset: AssetName) -> Option<(Vec<u8>, u32)> {
+		let (location, decimals) = match asset {
+			AssetName::DOT => (MultiLocation::parent(), 10),
+			AssetName::USDT => (MultiLocation::new(0, X2(PalletInstance(50), GeneralIndex(1984))), 6),
+			AssetName::PLMC => (MultiLocation::new(1, X1(Parachain(pallet_funding::PLMC_FOREIGN_ID))), 10),
+			_ => return None,
+		};
+		Some((location.encode(), decimals))
+	}
+}
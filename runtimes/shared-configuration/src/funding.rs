@@ -16,7 +16,7 @@
 
 use crate::{Balance, BlockNumber};
 use frame_support::{parameter_types, PalletId};
-use pallet_funding::types::AcceptedFundingAsset;
+use pallet_funding::types::{AcceptedFundingAsset, PhaseDurations};
 use parachains_common::AssetIdForTrustBackedAssets;
 use polimec_common::USD_UNIT;
 use sp_arithmetic::{FixedU128, Percent};
@@ -99,6 +99,35 @@ parameter_types! {
 	pub const RemainderFundingDuration: BlockNumber = REMAINDER_FUNDING_DURATION;
 	pub const ManualAcceptanceDuration: BlockNumber = MANUAL_ACCEPTANCE_DURATION;
 	pub const SuccessToSettlementTime: BlockNumber = SUCCESS_TO_SETTLEMENT_TIME;
+	// Issuers can choose their own phase durations between half and twice the defaults.
+	pub const MinPhaseDurations: PhaseDurations<BlockNumber> = PhaseDurations {
+		evaluation: EVALUATION_DURATION / 2,
+		auction_opening: AUCTION_OPENING_DURATION / 2,
+		auction_closing: AUCTION_CLOSING_DURATION / 2,
+		community_funding: COMMUNITY_FUNDING_DURATION / 2,
+		remainder_funding: REMAINDER_FUNDING_DURATION / 2,
+		manual_acceptance: MANUAL_ACCEPTANCE_DURATION / 2,
+		success_to_settlement: SUCCESS_TO_SETTLEMENT_TIME / 2,
+	};
+	pub const MaxPhaseDurations: PhaseDurations<BlockNumber> = PhaseDurations {
+		evaluation: EVALUATION_DURATION * 2,
+		auction_opening: AUCTION_OPENING_DURATION * 2,
+		auction_closing: AUCTION_CLOSING_DURATION * 2,
+		community_funding: COMMUNITY_FUNDING_DURATION * 2,
+		remainder_funding: REMAINDER_FUNDING_DURATION * 2,
+		manual_acceptance: MANUAL_ACCEPTANCE_DURATION * 2,
+		success_to_settlement: SUCCESS_TO_SETTLEMENT_TIME * 2,
+	};
+	// Blocks participations stay paused after a price moved too much, about one oracle fetch interval.
+	pub const PriceCircuitBreakerCooldown: BlockNumber = 50;
+	// Largest price move from one block to the next before participations with the asset are paused.
+	pub const PriceCircuitBreakerDeviation: Percent = Percent::from_percent(5);
+	// Blocks to wait for the relay to open a channel to a project before the issuer can request it again.
+	pub const HrmpChannelRequestTimeout: BlockNumber = 300;
+	// Blocks to wait for a project chain to answer a CT migration before marking it as failed.
+	pub const MigrationQueryTimeout: BlockNumber = 20;
+	// Base delay before a failed CT migration is retried. It doubles after each retry.
+	pub const MigrationRetryBackoff: BlockNumber = 10;
 	pub const FundingPalletId: PalletId = PalletId(*b"plmc/fun");
 	pub PriceMap: BTreeMap<AssetIdForTrustBackedAssets, FixedU128> = BTreeMap::from_iter(vec![
 		(AcceptedFundingAsset::DOT.to_assethub_id(), FixedU128::from_rational(69, 1)), // DOT
@@ -17,6 +17,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub mod assets;
+pub mod barriers;
 pub mod currency;
 pub mod fee;
 pub mod funding;
@@ -31,6 +32,7 @@ pub mod weights;
 pub use parachains_common::{Balance, BlockNumber, DAYS};
 
 pub use assets::*;
+pub use barriers::*;
 pub use currency::*;
 pub use fee::*;
 pub use funding::*;
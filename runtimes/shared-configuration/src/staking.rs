@@ -16,6 +16,10 @@
 
 use crate::currency::PLMC;
 use frame_support::{parameter_types, PalletId};
+use pallet_funding::{traits::ProvideStakedPlmc, AccountIdOf};
+use pallet_parachain_staking::{BalanceOf, OnStakeInUseFreed};
+use sp_runtime::DispatchResult;
+use sp_std::marker::PhantomData;
 
 // Since a Round is 6 hours, one week, expresses as `RoundIndex` is 4 * 7
 const WEEK_IN_ROUNDS: u32 = 4 * 7;
@@ -37,3 +41,34 @@ parameter_types! {
 	pub const MinDelegation: u128 = 50 * PLMC;
 	pub const StakingPalletId: PalletId = PalletId(*b"plmc/stk");
 }
+
+/// Lets the funding pallet use PLMC delegated to collators as participation bond, without waiting for the
+/// delegation to be unbonded. The PLMC stays delegated, and the staking pallet does not let it be unbonded until
+/// the funding pallet releases it.
+pub struct StakedPlmcFromDelegations<T>(PhantomData<T>);
+impl<T: pallet_parachain_staking::Config> ProvideStakedPlmc<T::AccountId, BalanceOf<T>>
+	for StakedPlmcFromDelegations<T>
+{
+	fn usable_stake(who: &T::AccountId) -> BalanceOf<T> {
+		pallet_parachain_staking::Pallet::<T>::usable_delegator_stake(who)
+	}
+
+	fn use_stake(who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+		pallet_parachain_staking::Pallet::<T>::use_delegator_stake(who, amount)
+	}
+
+	fn release_stake(who: &T::AccountId, amount: BalanceOf<T>) {
+		pallet_parachain_staking::Pallet::<T>::release_delegator_stake(who, amount)
+	}
+}
+
+/// Holds PLMC that backed participation bonds as stake again under the participation hold, when it stops being
+/// delegated without the delegator asking for it (e.g. their collator left).
+pub struct HoldFreedStakeAsParticipationBond<T>(PhantomData<T>);
+impl<T: pallet_funding::Config> OnStakeInUseFreed<AccountIdOf<T>, pallet_funding::BalanceOf<T>>
+	for HoldFreedStakeAsParticipationBond<T>
+{
+	fn on_stake_in_use_freed(delegator: &AccountIdOf<T>, amount: pallet_funding::BalanceOf<T>) {
+		pallet_funding::Pallet::<T>::hold_freed_staked_bonds(delegator, amount)
+	}
+}